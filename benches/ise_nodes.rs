@@ -0,0 +1,49 @@
+//! Benchmarks ISE node gathering on a recorded xdlrc dump.  Point
+//! `PRJCOMBINE_XDLRC_CACHE` at a raw tool output cache saved by
+//! `dump_ise_parts --cache`.
+use std::env;
+use criterion::{Criterion, Throughput};
+use prjcombine::stringpool::StringPool;
+use prjcombine::toolcache::ToolCache;
+use prjcombine::xilinx::ise::nodes::Nodes;
+use prjcombine::xilinx::ise::xdlrc::{Parser, Tile};
+
+fn load_tiles(cache: &str) -> Vec<Tile> {
+    let mut parser = Parser::new(ToolCache::new(cache).replay("xdlrc").unwrap(), cache).unwrap();
+    let mut res = Vec::new();
+    while let Some(t) = parser.get_tile().unwrap() {
+        res.push(t);
+    }
+    res
+}
+
+fn main() {
+    let cache = match env::var("PRJCOMBINE_XDLRC_CACHE") {
+        Ok(c) => c,
+        Err(_) => {
+            eprintln!("PRJCOMBINE_XDLRC_CACHE not set, skipping");
+            return;
+        }
+    };
+    let tiles = load_tiles(&cache);
+    let nwires: usize = tiles.iter().map(|t| t.wires.len()).sum();
+    let mut c = Criterion::default().configure_from_args().sample_size(10);
+    let mut group = c.benchmark_group("ise_nodes");
+    group.throughput(Throughput::Elements(nwires as u64));
+    group.bench_function("process_wire", |b| b.iter(|| {
+        let mut sp = StringPool::new();
+        let mut nodes = Nodes::new();
+        let mut count = 0;
+        for t in tiles.iter() {
+            for w in t.wires.iter() {
+                if !w.conns.is_empty() {
+                    nodes.process_wire(&mut sp, t, w, |_, _| count += 1);
+                }
+            }
+        }
+        nodes.finish_all(&sp, |_, _| count += 1);
+        count
+    }));
+    group.finish();
+    c.final_summary();
+}
@@ -0,0 +1,20 @@
+fn main() {
+    #[cfg(feature = "interchange")]
+    interchange_schema();
+}
+
+// The generated modules live in xilinx::interchange.
+#[cfg(feature = "interchange")]
+fn interchange_schema() {
+    println!("cargo:rerun-if-env-changed=FPGA_INTERCHANGE_SCHEMA");
+    let dir = std::path::PathBuf::from(std::env::var("FPGA_INTERCHANGE_SCHEMA").expect("FPGA_INTERCHANGE_SCHEMA must point to the interchange schema directory"));
+    let mut cmd = capnpc::CompilerCommand::new();
+    cmd.src_prefix(&dir);
+    cmd.default_parent_module(vec!["xilinx".to_string(), "interchange".to_string()]);
+    for name in ["LogicalNetlist.capnp", "References.capnp", "DeviceResources.capnp"] {
+        let path = dir.join(name);
+        println!("cargo:rerun-if-changed={}", path.display());
+        cmd.file(path);
+    }
+    cmd.run().expect("compiling the interchange schema");
+}
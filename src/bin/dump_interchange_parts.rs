@@ -0,0 +1,28 @@
+use std::io;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use rayon::ThreadPoolBuilder;
+use prjcombine::xilinx::interchange::InterchangeSource;
+use prjcombine::xilinx::source::dump_parts;
+use prjcombine::toolchain::ToolchainSet;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "dump_interchange_parts", about = "Convert a directory of FPGA Interchange device resources into rawdump files.")]
+struct Opt {
+    #[structopt(parse(from_os_str))]
+    source_directory: PathBuf,
+    #[structopt(parse(from_os_str))]
+    target_directory: PathBuf,
+    families: Vec<String>,
+    #[structopt(short="n", long, default_value="0")]
+    num_threads: usize,
+}
+
+fn main() -> Result<(), io::Error> {
+    let opt = Opt::from_args();
+    ThreadPoolBuilder::new().num_threads(opt.num_threads).build_global().unwrap();
+    let families = opt.families.iter().cloned().collect();
+    let source = InterchangeSource { dir: opt.source_directory };
+    dump_parts(&source, &ToolchainSet::plain(), &opt.target_directory, &families, false)?;
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use std::io;
+use structopt::StructOpt;
+use prjcombine::xilinx::interchange::read_device_resources;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "interchange2rd", about = "Make rawdump out of FPGA Interchange device resources.")]
+struct Opt {
+    src: String,
+    dst: String,
+}
+
+fn main() -> Result<(), io::Error> {
+    let opt = Opt::from_args();
+    let rd = read_device_resources(&opt.src)?;
+    rd.to_file(&opt.dst)?;
+    Ok(())
+}
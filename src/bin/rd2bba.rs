@@ -0,0 +1,21 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use structopt::StructOpt;
+use prjcombine::xilinx::rawdump::Part;
+use prjcombine::xilinx::nextpnr::write_bba;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "rd2bba", about = "Write nextpnr chip database from rawdump.")]
+struct Opt {
+    src: String,
+    dst: String,
+}
+
+fn main() -> Result<(), io::Error> {
+    let opt = Opt::from_args();
+    let rd = Part::from_file(&opt.src)?;
+    let mut f = BufWriter::new(File::create(&opt.dst)?);
+    write_bba(&rd, &mut f)?;
+    f.flush()?;
+    Ok(())
+}
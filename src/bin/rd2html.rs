@@ -1,19 +1,13 @@
 use std::collections::HashMap;
 use prjcombine::xilinx::rawdump::{Part, Coord, TkSiteSlot};
+use prjcombine::xilinx::tiletables::SINGLE;
 use structopt::StructOpt;
 use std::io;
 use std::io::Write;
 use std::fs::File;
 use ndarray::Array2;
 
-#[derive(Copy, Clone)]
-struct TileInfo (
-    &'static str,
-    (isize, isize, isize, isize), // expand right, left, up, down
-    &'static [&'static str]
-);
-
-const COLORS: &'static [(&'static str, (u8, u8, u8))] = &[
+const COLORS: &[(&str, (u8, u8, u8))] = &[
     ("int", (204, 204, 204)),
     ("int-buf", (102, 102, 255)),
     ("int-if", (153, 153, 255)),
@@ -56,2724 +50,6 @@ const COLORS: &'static [(&'static str, (u8, u8, u8))] = &[
     ("bterm", (0, 0, 0)),
 ];
 
-const SINGLE: (isize, isize, isize, isize) = (0, 0, 0, 0);
-
-const XC4K_TILES: &'static [TileInfo] = &[
-    TileInfo ("CENTER", SINGLE, &["clb"]),
-    TileInfo ("BOT", SINGLE, &["io"]),
-    TileInfo ("BOTS", SINGLE, &["io"]),
-    TileInfo ("BOTSL", SINGLE, &["io-spec"]),
-    TileInfo ("BOTRR", SINGLE, &["io-spec"]),
-    TileInfo ("TOP", SINGLE, &["io"]),
-    TileInfo ("TOPS", SINGLE, &["io"]),
-    TileInfo ("TOPSL", SINGLE, &["io-spec"]),
-    TileInfo ("TOPRR", SINGLE, &["io-spec"]),
-    TileInfo ("LEFT", SINGLE, &["io"]),
-    TileInfo ("LEFTS", SINGLE, &["io"]),
-    TileInfo ("LEFTT", SINGLE, &["io-spec"]),
-    TileInfo ("LEFTSB", SINGLE, &["io-spec"]),
-    TileInfo ("LEFTF", SINGLE, &["io-spec"]),
-    TileInfo ("LEFTSF", SINGLE, &["io-spec"]),
-    TileInfo ("LEFTF1", SINGLE, &["io-spec"]),
-    TileInfo ("LEFTSF1", SINGLE, &["io-spec"]),
-    TileInfo ("RT", SINGLE, &["io"]),
-    TileInfo ("RTS", SINGLE, &["io"]),
-    TileInfo ("RTSB", SINGLE, &["io-spec"]),
-    TileInfo ("RTT", SINGLE, &["io-spec"]),
-    TileInfo ("RTF", SINGLE, &["io-spec"]),
-    TileInfo ("RTF1", SINGLE, &["io-spec"]),
-    TileInfo ("RTSF", SINGLE, &["io-spec"]),
-    TileInfo ("RTSF1", SINGLE, &["io-spec"]),
-    TileInfo ("UL", SINGLE, &["cfg"]),
-    TileInfo ("UR", SINGLE, &["cfg"]),
-    TileInfo ("LL", SINGLE, &["cfg"]),
-    TileInfo ("LR", SINGLE, &["cfg"]),
-    TileInfo ("CLKB", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKT", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKL", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKR", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKV", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVC", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVB", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKH", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKC", SINGLE, &["clk-spine"]),
-    TileInfo ("VHBRKC", SINGLE, &["clk-spine"]),
-    TileInfo ("TVBRKC", SINGLE, &["clk-spine"]),
-    TileInfo ("HVBRKC", SINGLE, &["clk-spine"]),
-    TileInfo ("HVBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("VHBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("BVHBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("THRBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("LVLBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("RVRBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("RHVBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("LHVBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("RHVBRKS", SINGLE, &["clk-brk"]),
-    TileInfo ("RVRBRKS", SINGLE, &["clk-brk"]),
-    TileInfo ("CCBRK", SINGLE, &[]),
-    TileInfo ("VICONNECT", SINGLE, &["int"]),
-    TileInfo ("HICONNECT", SINGLE, &["int"]),
-    TileInfo ("VHIBRK", SINGLE, &["int"]),
-    TileInfo ("BVIBRK", SINGLE, &["int"]),
-    TileInfo ("TVIBRK", SINGLE, &["int"]),
-    TileInfo ("LHIBRK", SINGLE, &["int"]),
-    TileInfo ("RHIBRK", SINGLE, &["int"]),
-    TileInfo ("HICONNV", SINGLE, &["clk-spine"]),
-    TileInfo ("HICONNVC", SINGLE, &["clk-spine"]),
-    TileInfo ("VIMBRK", SINGLE, &["clk-spine"]),
-    TileInfo ("VHBRKCC", SINGLE, &["clk-spine"]),
-    TileInfo ("HVBRKCC", SINGLE, &["clk-spine"]),
-    TileInfo ("TVBRKCC", SINGLE, &["clk-spine"]),
-    TileInfo ("HIHBRKV", SINGLE, &["clk-brk"]),
-    TileInfo ("VHBRKV", SINGLE, &["clk-brk"]),
-    TileInfo ("HIHBRKVC", SINGLE, &["clk-brk"]),
-    TileInfo ("VHBRKVC", SINGLE, &["clk-brk"]),
-    TileInfo ("VIBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("BCCBRK", SINGLE, &[]),
-    TileInfo ("TCCBRK", SINGLE, &[]),
-];
-
-const VIRTEX_TILES: &'static [TileInfo] = &[
-    TileInfo ("CENTER", SINGLE, &["int", "clb"]),
-    TileInfo ("CLKH", SINGLE, &["clk-spine"]),
-    TileInfo ("BOT", SINGLE, &["int", "io"]),
-    TileInfo ("BL_DLLIOB", SINGLE, &["int", "io-spec"]),
-    TileInfo ("BR_DLLIOB", SINGLE, &["int", "io-spec"]),
-    TileInfo ("TOP", SINGLE, &["int", "io"]),
-    TileInfo ("TL_DLLIOB", SINGLE, &["int", "io-spec"]),
-    TileInfo ("TR_DLLIOB", SINGLE, &["int", "io-spec"]),
-    TileInfo ("LEFT", SINGLE, &["int", "io"]),
-    TileInfo ("LEFT_PCI_TOP", SINGLE, &["int", "io-spec"]),
-    TileInfo ("LEFT_PCI_BOT", SINGLE, &["int", "io-spec"]),
-    TileInfo ("RIGHT", SINGLE, &["int", "io"]),
-    TileInfo ("RIGHT_PCI_TOP", SINGLE, &["int", "io-spec"]),
-    TileInfo ("RIGHT_PCI_BOT", SINGLE, &["int", "io-spec"]),
-    TileInfo ("UL", SINGLE, &["int", "cfg"]),
-    TileInfo ("UR", SINGLE, &["int", "cfg"]),
-    TileInfo ("LL", SINGLE, &["int", "cfg"]),
-    TileInfo ("LR", SINGLE, &["int", "cfg"]),
-    TileInfo ("CLKL", SINGLE, &["hardip"]),
-    TileInfo ("CLKR", SINGLE, &["hardip"]),
-    TileInfo ("LBRAM", (0, 0, 3, 0), &["bram", "clk-last-buf"]),
-    TileInfo ("RBRAM", (0, 0, 3, 0), &["bram", "clk-last-buf"]),
-    TileInfo ("MBRAM", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("MBRAMS2E", (0, 0, 3, 0), &["bram", "crippled"]),
-    TileInfo ("BRAM_CLKH", SINGLE, &["clk-row-buf"]),
-    TileInfo ("BRAMS2E_CLKH", SINGLE, &["clk-row-buf"]),
-    TileInfo ("BRAM_BOT", SINGLE, &["clk-last-buf", "pll"]),
-    TileInfo ("BRAM_TOP", SINGLE, &["clk-last-buf", "pll"]),
-    TileInfo ("BRAM_BOT_GCLK", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM_TOP_GCLK", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM_BOT_NOGCLK", SINGLE, &[]),
-    TileInfo ("BRAM_TOP_NOGCLK", SINGLE, &[]),
-    TileInfo ("BRAMS2E_BOT_NOGCLK", SINGLE, &[]),
-    TileInfo ("BRAMS2E_TOP_NOGCLK", SINGLE, &[]),
-    TileInfo ("LBRAM_BOTP", SINGLE, &["pll"]),
-    TileInfo ("LBRAM_BOTS", SINGLE, &["pll"]),
-    TileInfo ("LBRAM_BOTS_GCLK", SINGLE, &["clk-last-buf", "pll"]),
-    TileInfo ("LBRAM_TOPP", SINGLE, &["pll"]),
-    TileInfo ("LBRAM_TOPS", SINGLE, &["pll"]),
-    TileInfo ("LBRAM_TOPS_GCLK", SINGLE, &["clk-last-buf", "pll"]),
-    TileInfo ("RBRAM_BOTP", SINGLE, &["pll"]),
-    TileInfo ("RBRAM_BOTS", SINGLE, &["pll"]),
-    TileInfo ("RBRAM_BOTS_GCLK", SINGLE, &["clk-last-buf", "pll"]),
-    TileInfo ("RBRAM_TOPP", SINGLE, &["pll"]),
-    TileInfo ("RBRAM_TOPS", SINGLE, &["pll"]),
-    TileInfo ("RBRAM_TOPS_GCLK", SINGLE, &["clk-last-buf", "pll"]),
-    TileInfo ("LBRAMS2E_BOTP", SINGLE, &["pll", "crippled"]),
-    TileInfo ("LBRAMS2E_TOPP", SINGLE, &["pll", "crippled"]),
-    TileInfo ("RBRAMS2E_BOTP", SINGLE, &["pll", "crippled"]),
-    TileInfo ("RBRAMS2E_TOPP", SINGLE, &["pll", "crippled"]),
-    TileInfo ("CLKB", SINGLE, &["clk-global-buf", "clk-last-buf"]),
-    TileInfo ("CLKB_2DLL", SINGLE, &["clk-global-buf", "clk-last-buf"]),
-    TileInfo ("CLKB_4DLL", SINGLE, &["clk-global-buf", "clk-last-buf"]),
-    TileInfo ("CLKT", SINGLE, &["clk-global-buf", "clk-last-buf"]),
-    TileInfo ("CLKT_2DLL", SINGLE, &["clk-global-buf", "clk-last-buf"]),
-    TileInfo ("CLKT_4DLL", SINGLE, &["clk-global-buf", "clk-last-buf"]),
-    TileInfo ("CLKV", SINGLE, &["clk-spine", "clk-last-buf"]),
-    TileInfo ("CLKC", SINGLE, &["clk-spine-buf", "clk-row-buf"]),
-    TileInfo ("GCLKB", SINGLE, &["clk-last-buf"]),
-    TileInfo ("GCLKT", SINGLE, &["clk-last-buf"]),
-    TileInfo ("GCLKV", SINGLE, &["clk-last-buf"]),
-    TileInfo ("GCLKC", SINGLE, &["clk-row-buf"]),
-    TileInfo ("GBRKB", SINGLE, &["clk-brk"]),
-    TileInfo ("GBRKT", SINGLE, &["clk-brk"]),
-    TileInfo ("GBRKV", SINGLE, &["clk-brk"]),
-];
-
-const VIRTEX2_TILES: &'static [TileInfo] = &[
-    TileInfo ("CENTER", SINGLE, &["int", "clb"]),
-
-    TileInfo ("LR_IOIS", SINGLE, &["int", "io"]),
-    TileInfo ("TB_IOIS", SINGLE, &["int", "io"]),
-    TileInfo ("MK_T_IOIS", SINGLE, &["int", "io-spec"]),
-    TileInfo ("MK_B_IOIS", SINGLE, &["int", "io-spec"]),
-    TileInfo ("ML_TB_IOIS", SINGLE, &["int", "io"]),
-    TileInfo ("ML_TBS_IOIS", SINGLE, &["int", "io"]),
-    TileInfo ("GIGABIT_IOI", SINGLE, &["int", "io"]),
-    TileInfo ("GIGABIT10_IOI", SINGLE, &["int", "io"]),
-
-    TileInfo ("BRAM0", SINGLE, &["int"]),
-    TileInfo ("BRAM1", SINGLE, &["int"]),
-    TileInfo ("BRAM2", SINGLE, &["int"]),
-    TileInfo ("BRAM3", SINGLE, &["int"]),
-    TileInfo ("BRAMSITE", (0, 0, 3, 0), &["bram", "dsp"]),
-
-    TileInfo ("BRAM_IOIS", SINGLE, &["int", "pll"]),
-    TileInfo ("BRAMSITE_IOIS", SINGLE, &[]),
-    TileInfo ("ML_BRAM_IOIS", SINGLE, &["int", "pll"]),
-    TileInfo ("ML_BRAMSITE_IOIS", SINGLE, &[]),
-
-    TileInfo ("TGIGABIT_INT0", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT_INT1", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT_INT2", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT_INT3", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT_INT4", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT_INT0", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT_INT1", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT_INT2", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT_INT3", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT_INT4", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT", (0, 0, 4, 0), &["gtp"]),
-    TileInfo ("BGIGABIT", (0, 0, 3, 1), &["gtp"]),
-
-    TileInfo ("TGIGABIT10_INT0", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10_INT1", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10_INT2", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10_INT3", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10_INT4", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10_INT5", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10_INT6", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10_INT7", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10_INT8", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT0", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT1", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT2", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT3", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT4", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT5", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT6", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT7", SINGLE, &["int"]),
-    TileInfo ("BGIGABIT10_INT8", SINGLE, &["int"]),
-    TileInfo ("TGIGABIT10", (0, 0, 9, 0), &["gtp"]),
-    TileInfo ("BGIGABIT10", (0, 0, 8, 1), &["gtp"]),
-
-    TileInfo ("LPPC_X0Y0_INT", SINGLE, &["int", "rterm"]),
-    TileInfo ("LPPC_X1Y0_INT", SINGLE, &["int", "rterm"]),
-    TileInfo ("ULPPC_X0Y0_INT", SINGLE, &["int", "rterm"]),
-    TileInfo ("ULPPC_X1Y0_INT", SINGLE, &["int", "rterm"]),
-    TileInfo ("LLPPC_X0Y0_INT", SINGLE, &["int", "rterm"]),
-    TileInfo ("LLPPC_X1Y0_INT", SINGLE, &["int", "rterm"]),
-    TileInfo ("RPPC_X0Y0_INT", SINGLE, &["int"]),
-    TileInfo ("RPPC_X1Y0_INT", SINGLE, &["int"]),
-    TileInfo ("TPPC_X0Y0_INT", SINGLE, &["int"]),
-    TileInfo ("TPPC_X1Y0_INT", SINGLE, &["int"]),
-    TileInfo ("BPPC_X0Y0_INT", SINGLE, &["int"]),
-    TileInfo ("BPPC_X1Y0_INT", SINGLE, &["int"]),
-    TileInfo ("PTERMT", SINGLE, &["bterm"]),
-    TileInfo ("PTERMB", SINGLE, &["tterm"]),
-    TileInfo ("PTERMR", SINGLE, &["lterm"]),
-    TileInfo ("PTERMBR", SINGLE, &["lterm"]),
-    TileInfo ("PTERMTR", SINGLE, &["lterm"]),
-    TileInfo ("BRAMSITE_PTERMB", SINGLE, &[]),
-    TileInfo ("BRAMSITE_PTERMT", SINGLE, &[]),
-    TileInfo ("BRAMSITE_PPC_X0Y0_INT", SINGLE, &[]),
-    TileInfo ("BRAMSITE_PPC_X1Y0_INT", SINGLE, &[]),
-    TileInfo ("BRAMSITE_RPPC_X0Y0_INT", SINGLE, &[]),
-    TileInfo ("BRAMSITE_RPPC_X1Y0_INT", SINGLE, &[]),
-    TileInfo ("LBPPC", (3, 5, 5, 7), &["hardip"]),
-    TileInfo ("RBPPC", (3, 5, 5, 7), &["hardip"]),
-
-    TileInfo ("UL", SINGLE, &["int", "cfg"]),
-    TileInfo ("UR", SINGLE, &["int", "cfg"]),
-    TileInfo ("LL", SINGLE, &["int", "cfg"]),
-    TileInfo ("LR", SINGLE, &["int", "cfg"]),
-
-    TileInfo ("CLKT", SINGLE, &["clk-global-buf"]),
-    TileInfo ("CLKB", SINGLE, &["clk-global-buf"]),
-    TileInfo ("ML_CLKT", SINGLE, &["clk-global-buf"]),
-    TileInfo ("ML_CLKB", SINGLE, &["clk-global-buf"]),
-    TileInfo ("MK_CLKT", SINGLE, &["clk-global-buf"]),
-    TileInfo ("MK_CLKB", SINGLE, &["clk-global-buf"]),
-    TileInfo ("CLKV", SINGLE, &["clk-spine"]),
-    TileInfo ("GBRKC", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVD1", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVD2", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVU1", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVU2", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVCU1", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVCU2", SINGLE, &["clk-spine"]),
-    TileInfo ("GCLKC", SINGLE, &["clk-row-buf"]),
-    TileInfo ("CLKC", SINGLE, &["clk-spine-buf"]),
-
-    TileInfo ("GCLKH", SINGLE, &["clk-last-buf"]),
-    TileInfo ("LR_GCLKH", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAMSITE_GCLKH", SINGLE, &["clk-row"]),
-    TileInfo ("BRKH", SINGLE, &["clk-brk"]),
-    TileInfo ("LR_BRKH", SINGLE, &["clk-brk"]),
-
-    TileInfo ("CLKH", SINGLE, &["clk-brk"]),
-    TileInfo ("LR_CLKH", SINGLE, &["clk-brk"]),
-    TileInfo ("ML_CLKH", SINGLE, &[]),
-    TileInfo ("ML_LR_CLKH", SINGLE, &[]),
-    TileInfo ("BRAMSITEH", SINGLE, &[]),
-
-    TileInfo ("BTERM010", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM123", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM012", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM323", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BCLKTERM123", SINGLE, &["bterm", "iob-spec"]),
-    TileInfo ("BCLKTERM012", SINGLE, &["bterm", "iob-spec"]),
-    TileInfo ("ML_BCLKTERM123", SINGLE, &["bterm", "iob-spec"]),
-    TileInfo ("ML_BCLKTERM012", SINGLE, &["bterm", "iob-spec"]),
-    TileInfo ("BBTERM", SINGLE, &["bterm"]),
-    TileInfo ("CNR_BTERM", SINGLE, &["bterm"]),
-    TileInfo ("ML_CNR_BTERM", SINGLE, &["bterm"]),
-    TileInfo ("BGIGABIT_IOI_TERM", SINGLE, &["bterm"]),
-    TileInfo ("BGIGABIT_INT_TERM", SINGLE, &["bterm"]),
-    TileInfo ("BGIGABIT10_IOI_TERM", SINGLE, &["bterm"]),
-    TileInfo ("BGIGABIT10_INT_TERM", SINGLE, &["bterm"]),
-
-    TileInfo ("TTERM010", SINGLE, &["tterm", "iob"]),
-    TileInfo ("ML_TTERM010", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM321", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM210", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM323", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TCLKTERM321", SINGLE, &["tterm", "iob-spec"]),
-    TileInfo ("TCLKTERM210", SINGLE, &["tterm", "iob-spec"]),
-    TileInfo ("ML_TCLKTERM210", SINGLE, &["tterm", "iob-spec"]),
-    TileInfo ("BTTERM", SINGLE, &["tterm"]),
-    TileInfo ("CNR_TTERM", SINGLE, &["tterm"]),
-    TileInfo ("TGIGABIT_IOI_TERM", SINGLE, &["tterm"]),
-    TileInfo ("TGIGABIT_INT_TERM", SINGLE, &["tterm"]),
-    TileInfo ("TGIGABIT10_IOI_TERM", SINGLE, &["tterm"]),
-    TileInfo ("TGIGABIT10_INT_TERM", SINGLE, &["tterm"]),
-
-    TileInfo ("LTERM010", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM321", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM210", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM323", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM210_PCI", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM323_PCI", SINGLE, &["lterm", "iob"]),
-    TileInfo ("CNR_LTERM", SINGLE, &["lterm"]),
-
-    TileInfo ("RTERM010", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM321", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM210", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM323", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM210_PCI", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM323_PCI", SINGLE, &["rterm", "iob"]),
-    TileInfo ("CNR_RTERM", SINGLE, &["rterm"]),
-
-    TileInfo ("TBSTERM", SINGLE, &[]),
-    TileInfo ("BBSTERM", SINGLE, &[]),
-    TileInfo ("BGIGABIT_TERM", SINGLE, &[]),
-    TileInfo ("TGIGABIT_TERM", SINGLE, &[]),
-    TileInfo ("BGIGABIT10_TERM", SINGLE, &[]),
-    TileInfo ("TGIGABIT10_TERM", SINGLE, &[]),
-
-    TileInfo ("REG_L", SINGLE, &["hardip"]),
-    TileInfo ("REG_R", SINGLE, &["hardip"]),
-    TileInfo ("REG_LB", SINGLE, &[]),
-    TileInfo ("REG_RB", SINGLE, &[]),
-
-    TileInfo ("EMPTY16X4", SINGLE, &[]),
-    TileInfo ("LEMPTY16X4", SINGLE, &[]),
-    TileInfo ("REMPTY16X4", SINGLE, &[]),
-    TileInfo ("EMPTY0X2", SINGLE, &[]),
-    TileInfo ("LEMPTY0X2", SINGLE, &[]),
-    TileInfo ("REMPTY0X2", SINGLE, &[]),
-    TileInfo ("EMPTY0X4", SINGLE, &[]),
-    TileInfo ("EMPTY0X64", SINGLE, &[]),
-];
-
-const SPARTAN3_TILES: &'static [TileInfo] = &[
-    TileInfo ("CENTER", SINGLE, &["int", "clb"]),
-    TileInfo ("CENTER_SMALL", SINGLE, &["int", "clb"]),
-
-    TileInfo ("TIOIS", SINGLE, &["int", "io"]),
-    TileInfo ("BIOIS", SINGLE, &["int", "io"]),
-    TileInfo ("LIOIS", SINGLE, &["int", "io"]),
-    TileInfo ("RIOIS", SINGLE, &["int", "io"]),
-
-    TileInfo ("BRAM0", SINGLE, &["int"]),
-    TileInfo ("BRAM1", SINGLE, &["int"]),
-    TileInfo ("BRAM2", SINGLE, &["int"]),
-    TileInfo ("BRAM3", SINGLE, &["int"]),
-    TileInfo ("BRAM0_SMALL", SINGLE, &["int"]),
-    TileInfo ("BRAM1_SMALL", SINGLE, &["int"]),
-    TileInfo ("BRAM2_SMALL", SINGLE, &["int"]),
-    TileInfo ("BRAM3_SMALL", SINGLE, &["int"]),
-    TileInfo ("BRAMSITE", (0, 0, 3, 0), &["bram", "dsp"]),
-
-    TileInfo ("BRAM_IOIS", SINGLE, &["int", "pll"]),
-    TileInfo ("BRAM_IOIS_NODCM", SINGLE, &["int"]),
-    TileInfo ("BRAMSITE_IOIS", SINGLE, &[]),
-
-    TileInfo ("UL", SINGLE, &["int", "cfg"]),
-    TileInfo ("UR", SINGLE, &["int", "cfg"]),
-    TileInfo ("LL", SINGLE, &["int", "cfg"]),
-    TileInfo ("LR", SINGLE, &["int", "cfg"]),
-
-    TileInfo ("CLKT", SINGLE, &["clk-global-buf"]),
-    TileInfo ("CLKB", SINGLE, &["clk-global-buf"]),
-    TileInfo ("CLKV", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVC", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKC", SINGLE, &["clk-spine-buf"]),
-
-    TileInfo ("GCLKV", SINGLE, &["clk-spine"]),
-    TileInfo ("GCLKV_IOIS", SINGLE, &["clk-spine"]),
-    TileInfo ("GBRKC", SINGLE, &["clk-spine"]),
-    TileInfo ("GCLKVC", SINGLE, &["clk-row-buf"]),
-    TileInfo ("GCLKVM", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("BGCLKVTERM", SINGLE, &[]),
-    TileInfo ("TGCLKVTERM", SINGLE, &[]),
-
-    TileInfo ("GCLKH", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAMSITE_GCLKH", SINGLE, &["clk-row"]),
-    TileInfo ("BRKH", SINGLE, &["clk-brk"]),
-
-    TileInfo ("CLKH", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("BRAMSITEH", SINGLE, &["clk-spine"]),
-
-    TileInfo ("BTERM2", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM3", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BCLKTERM2", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BCLKTERM3", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BBTERM", SINGLE, &["bterm"]),
-    TileInfo ("CNR_BTERM", SINGLE, &["bterm"]),
-
-    TileInfo ("TTERM2", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM3", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TCLKTERM2", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TCLKTERM3", SINGLE, &["tterm", "iob"]),
-    TileInfo ("BTTERM", SINGLE, &["tterm"]),
-    TileInfo ("CNR_TTERM", SINGLE, &["tterm"]),
-
-    TileInfo ("LTERM", SINGLE, &["lterm", "iob"]),
-    TileInfo ("CNR_LBTERM", SINGLE, &["lterm"]),
-    TileInfo ("CNR_LTTERM", SINGLE, &["lterm"]),
-
-    TileInfo ("RTERM", SINGLE, &["rterm", "iob"]),
-    TileInfo ("CNR_RBTERM", SINGLE, &["rterm"]),
-    TileInfo ("CNR_RTTERM", SINGLE, &["rterm"]),
-
-    TileInfo ("TBSTERM", SINGLE, &[]),
-    TileInfo ("BBSTERM", SINGLE, &[]),
-
-    TileInfo ("EMPTY16X2", SINGLE, &[]),
-    TileInfo ("EMPTY0X3", SINGLE, &[]),
-    TileInfo ("EMPTY0X2", SINGLE, &[]),
-];
-
-const SPARTAN3E_TILES: &'static [TileInfo] = &[
-    TileInfo ("CENTER_SMALL", SINGLE, &["int", "clb"]),
-    TileInfo ("CENTER_SMALL_BRK", SINGLE, &["int", "clb", "clk-brk"]),
-
-    TileInfo ("TIOIS", SINGLE, &["int", "io"]),
-    TileInfo ("TIBUFS", SINGLE, &["int", "io"]),
-    TileInfo ("TIOIB", SINGLE, &["int", "io"]),
-
-    TileInfo ("BIOIS", SINGLE, &["int", "io"]),
-    TileInfo ("BIBUFS", SINGLE, &["int", "io"]),
-    TileInfo ("BIOIB", SINGLE, &["int", "io"]),
-
-    TileInfo ("LIOIS", SINGLE, &["int", "io"]),
-    TileInfo ("LIOIS_PCI", SINGLE, &["int", "io"]),
-    TileInfo ("LIOIS_CLK_PCI", SINGLE, &["int", "io"]),
-    TileInfo ("LIOIS_BRK", SINGLE, &["int", "io", "clk-brk"]),
-    TileInfo ("LIOIS_CLK_PCI_BRK", SINGLE, &["int", "io", "clk-brk"]),
-    TileInfo ("LIBUFS", SINGLE, &["int", "io"]),
-    TileInfo ("LIBUFS_PCI", SINGLE, &["int", "io"]),
-    TileInfo ("LIBUFS_CLK_PCI", SINGLE, &["int", "io"]),
-
-    TileInfo ("RIOIS", SINGLE, &["int", "io"]),
-    TileInfo ("RIOIS_PCI", SINGLE, &["int", "io"]),
-    TileInfo ("RIOIS_CLK_PCI", SINGLE, &["int", "io"]),
-    TileInfo ("RIBUFS", SINGLE, &["int", "io"]),
-    TileInfo ("RIBUFS_BRK", SINGLE, &["int", "io", "clk-brk"]),
-    TileInfo ("RIBUFS_CLK_PCI", SINGLE, &["int", "io"]),
-    TileInfo ("RIBUFS_PCI", SINGLE, &["int", "io"]),
-    TileInfo ("RIBUFS_CLK_PCI_BRK", SINGLE, &["int", "io", "clk-brk"]),
-
-    TileInfo ("BRAM0_SMALL", SINGLE, &["int"]),
-    TileInfo ("BRAM0_SMALL_BOT", SINGLE, &["int"]),
-    TileInfo ("BRAM1_SMALL", SINGLE, &["int"]),
-    TileInfo ("BRAM2_SMALL", SINGLE, &["int"]),
-    TileInfo ("BRAM3_SMALL", SINGLE, &["int"]),
-    TileInfo ("BRAM3_SMALL_BRK", SINGLE, &["int", "clk-brk"]),
-    TileInfo ("BRAM3_SMALL_TOP", SINGLE, &["int"]),
-    TileInfo ("BRAMSITE2", (0, 0, 3, 0), &["bram", "dsp"]),
-    TileInfo ("BRAMSITE2_TOP", (0, 0, 3, 0), &["bram", "dsp"]),
-    TileInfo ("BRAMSITE2_BOT", (0, 0, 3, 0), &["bram", "dsp"]),
-    TileInfo ("BRAMSITE2_BRK", (0, 0, 3, 0), &["bram", "dsp", "clk-brk"]),
-    TileInfo ("BRAMSITE2_3M", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BRAMSITE2_3M_TOP", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BRAMSITE2_3M_BOT", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BRAMSITE2_3M_BRK", (0, 0, 3, 0), &["bram", "clk-brk"]),
-    TileInfo ("BRAM2_FEEDTHRU", (0, 0, 3, 0), &[]),
-    TileInfo ("BRAM2_FEEDTHRU_BRK", (0, 0, 3, 0), &["clk-brk"]),
-    TileInfo ("COB_NO_TERM", SINGLE, &[]),
-    TileInfo ("COB_TERM_B", SINGLE, &["tterm"]),
-    TileInfo ("COB_TERM_T", SINGLE, &["bterm"]),
-
-    TileInfo ("MACC0_SMALL", SINGLE, &["int"]),
-    TileInfo ("MACC0_SMALL_BOT", SINGLE, &["int"]),
-    TileInfo ("MACC1_SMALL", SINGLE, &["int"]),
-    TileInfo ("MACC2_SMALL", SINGLE, &["int"]),
-    TileInfo ("MACC3_SMALL", SINGLE, &["int"]),
-    TileInfo ("MACC3_SMALL_BRK", SINGLE, &["int", "clk-brk"]),
-    TileInfo ("MACC3_SMALL_TOP", SINGLE, &["int"]),
-    TileInfo ("MACCSITE2", (0, 0, 3, 0), &["dsp"]),
-    TileInfo ("MACCSITE2_BRK", (0, 0, 3, 0), &["dsp"]),
-    TileInfo ("MACCSITE2_BOT", (0, 0, 3, 0), &["dsp"]),
-    TileInfo ("MACCSITE2_TOP", (0, 0, 3, 0), &["dsp"]),
-
-    TileInfo ("UL", SINGLE, &["int", "cfg"]),
-    TileInfo ("UR", SINGLE, &["int", "cfg"]),
-    TileInfo ("LL", SINGLE, &["int", "cfg"]),
-    TileInfo ("LR", SINGLE, &["int", "cfg"]),
-
-    TileInfo ("DCM_BR_CENTER", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_TR_CENTER", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_BL_CENTER", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_TL_CENTER", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_H_BR_CENTER", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_H_TR_CENTER", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_H_BL_CENTER", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_H_TL_CENTER", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_BGAP", SINGLE, &["int", "pll"]),
-    TileInfo ("DCM_SPLY", SINGLE, &["int", "pll"]),
-    TileInfo ("DCMAUX_BL_CENTER", SINGLE, &["int"]),
-    TileInfo ("DCMAUX_TL_CENTER", SINGLE, &["int"]),
-    TileInfo ("DCM_TERM", SINGLE, &[]),
-    TileInfo ("DCM_TL_TERM", SINGLE, &[]),
-    TileInfo ("DCM_BL_TERM", SINGLE, &[]),
-    TileInfo ("DCM_H_TR_TERM", SINGLE, &[]),
-    TileInfo ("DCM_H_BR_TERM", SINGLE, &[]),
-    TileInfo ("DCM_TERM_NOMEM", SINGLE, &[]),
-    TileInfo ("DCM_TERM_MULT", SINGLE, &[]),
-    TileInfo ("EMPTY_DCM_TERM", SINGLE, &[]),
-
-    TileInfo ("CLKT", (0, 0, 1, 0), &["clk-global-buf"]),
-    TileInfo ("CLKB", (0, 0, 1, 0), &["clk-global-buf"]),
-    TileInfo ("CLKT_LL", (0, 0, 1, 0), &["clk-global-buf", "int-buf"]),
-    TileInfo ("CLKB_LL", (0, 0, 1, 0), &["clk-global-buf", "int-buf"]),
-    TileInfo ("CLKV", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKV_DCM", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVC", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKVC_LL", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKC", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CLKC_LL", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CLKC_50A", (0, 0, 1, 0), &["clk-spine-buf"]),
-    TileInfo ("CLKL", SINGLE, &["clk-global-buf", "hardip"]),
-    TileInfo ("CLKR", SINGLE, &["clk-global-buf", "hardip"]),
-    TileInfo ("CLKV_LL", SINGLE, &["clk-spine", "int-buf"]),
-    TileInfo ("CLKV_DCM_LL", SINGLE, &["clk-spine", "int-buf"]),
-
-    TileInfo ("GCLKV", SINGLE, &["clk-spine"]),
-    TileInfo ("GCLKV_IOIS", SINGLE, &["clk-spine"]),
-    TileInfo ("GCLKV_IOISL", SINGLE, &["clk-spine"]),
-    TileInfo ("GCLKV_IOISR", SINGLE, &["clk-spine"]),
-    TileInfo ("GBRKC", SINGLE, &["clk-spine"]),
-    TileInfo ("GCLKVC", SINGLE, &["clk-row-buf"]),
-    TileInfo ("GCLKVML", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("GCLKVMR", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("BGCLKVTERM", SINGLE, &[]),
-    TileInfo ("TGCLKVTERM", SINGLE, &[]),
-
-    TileInfo ("GCLKH", SINGLE, &["clk-last-buf"]),
-    TileInfo ("GCLKH_PCI_CE_N", SINGLE, &["clk-last-buf"]),
-    TileInfo ("GCLKH_PCI_CE_S", SINGLE, &["clk-last-buf"]),
-    TileInfo ("GCLKH_PCI_CE_S_50A", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAMSITE2_MID_GCLKH", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAMSITE2_DN_GCLKH", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAMSITE2_UP_GCLKH", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAMSITE2_GCLKH", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM2_MID_GCLKH_FEEDTHRU", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM2_MID_GCLKH_FEEDTHRUA", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM2_DN_GCLKH_FEEDTHRU", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM2_DN_GCLKH_FEEDTHRUA", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM2_UP_GCLKH_FEEDTHRU", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM2_UP_GCLKH_FEEDTHRUA", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM2_GCLKH_FEEDTHRU", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM2_GCLKH_FEEDTHRUA", SINGLE, &["clk-last-buf"]),
-    TileInfo ("MACC2_GCLKH_FEEDTHRUA", SINGLE, &["clk-row"]),
-
-    TileInfo ("CLKH", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("CLKLH_DCM", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("CLKRH_DCM", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("BRAMSITE2H", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("BRAM2_FEEDTHRUH", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("CLKL_IOIS", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("CLKR_IOIS", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("CLKH_DCM_FT", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("CLKH_DCM_CC", SINGLE, &["clk-spine", "clk-brk"]),
-    TileInfo ("MACC2_FEEDTHRUH", SINGLE, &["clk-spine"]),
-
-    TileInfo ("CLKH_LL", SINGLE, &["clk-spine", "int-buf", "clk-brk"]),
-    TileInfo ("CLKH_DCM_LL", SINGLE, &["clk-spine", "int-buf", "clk-brk"]),
-    TileInfo ("CLKLH_DCM_LL", SINGLE, &["clk-spine", "int-buf", "clk-brk"]),
-    TileInfo ("CLKRH_DCM_LL", SINGLE, &["clk-spine", "int-buf", "clk-brk"]),
-    TileInfo ("CLKL_IOIS_LL", SINGLE, &["clk-spine", "int-buf", "clk-brk"]),
-    TileInfo ("CLKR_IOIS_LL", SINGLE, &["clk-spine", "int-buf", "clk-brk"]),
-
-    TileInfo ("CLKH_50A", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKL_IOIS_50A", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKR_IOIS_50A", SINGLE, &["clk-spine"]),
-    TileInfo ("BRAMSITE2H_50A", SINGLE, &["clk-spine"]),
-    TileInfo ("BRAM2_FEEDTHRUH_50A", SINGLE, &["clk-spine"]),
-    TileInfo ("GCLKHL_50A", SINGLE, &[]),
-    TileInfo ("GCLKHR_50A", SINGLE, &[]),
-
-    TileInfo ("BTERM", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM1", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM2", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM3", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM4", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM4_BRAM2", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM4CLK", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERMCLK", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERMCLKA", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERMCLKB", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM2CLK", SINGLE, &["bterm", "iob"]),
-    TileInfo ("BTERM1_MACC", SINGLE, &["bterm", "iob"]),
-    TileInfo ("CNR_BTERM", SINGLE, &["bterm"]),
-
-    TileInfo ("TTERM", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM1", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM2", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM3", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM4", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM4_BRAM2", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERMCLK", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERMCLKA", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM4CLK", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM2CLK", SINGLE, &["tterm", "iob"]),
-    TileInfo ("TTERM1_MACC", SINGLE, &["tterm", "iob"]),
-    TileInfo ("CNR_TTERM", SINGLE, &["tterm"]),
-
-    TileInfo ("LTERM", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM4", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM3", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM2", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM1", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERMCLK", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERMCLKA", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM4B", SINGLE, &["lterm", "iob"]),
-    TileInfo ("LTERM4CLK", SINGLE, &["lterm", "iob"]),
-    TileInfo ("CNR_LBTERM", SINGLE, &["lterm"]),
-    TileInfo ("CNR_LTTERM", SINGLE, &["lterm"]),
-
-    TileInfo ("RTERM", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM4", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM3", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM2", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM1", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM4CLK", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM4CLKB", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERMCLKA", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERMCLKB", SINGLE, &["rterm", "iob"]),
-    TileInfo ("RTERM4B", SINGLE, &["rterm", "iob"]),
-    TileInfo ("CNR_RBTERM", SINGLE, &["rterm"]),
-    TileInfo ("CNR_RTTERM", SINGLE, &["rterm"]),
-
-    TileInfo ("EMPTY_CNR", SINGLE, &[]),
-    TileInfo ("EMPTY_GCLKH", SINGLE, &[]),
-    TileInfo ("EMPTY_CLKH_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_GCLKVML_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_GCLKVMR_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_BRAM_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_CLKL_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_CLKR_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_CLKC_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_CLKL_IOIS_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_CLKR_IOIS_LL", SINGLE, &[]),
-    TileInfo ("EMPTY_BIOI", SINGLE, &[]),
-    TileInfo ("EMPTY_BTERM", SINGLE, &[]),
-    TileInfo ("EMPTY_TIOI", SINGLE, &[]),
-    TileInfo ("EMPTY_TTERM", SINGLE, &[]),
-];
-
-const SPARTAN6_TILES: &'static [TileInfo] = &[
-    TileInfo ("INT", SINGLE, &["int"]),
-    TileInfo ("INT_TERM", SINGLE, &["int"]),
-    TileInfo ("INT_BRAM", SINGLE, &["int"]),
-    TileInfo ("INT_GCLK", SINGLE, &["int"]),
-    TileInfo ("IOI_INT", SINGLE, &["int"]),
-    TileInfo ("LIOI_INT", SINGLE, &["int"]),
-    TileInfo ("INT_BRK", SINGLE, &["int", "clk-brk"]),
-    TileInfo ("INT_TERM_BRK", SINGLE, &["int", "clk-brk"]),
-    TileInfo ("INT_BRAM_BRK", SINGLE, &["int", "clk-brk"]),
-    TileInfo ("LIOI_INT_BRK", SINGLE, &["int", "clk-brk"]),
-
-    TileInfo ("INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_IOI", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_IOI_DCMBOT", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_CARRY", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_REGC", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_RTERM", SINGLE, &["int-if", "rterm"]),
-    TileInfo ("INT_INTERFACE_LTERM", SINGLE, &["int-if", "lterm"]),
-
-    TileInfo ("IOI_LTERM", SINGLE, &["lterm"]),
-    TileInfo ("IOI_LTERM_LOWER_BOT", SINGLE, &["lterm"]),
-    TileInfo ("IOI_LTERM_LOWER_TOP", SINGLE, &["lterm"]),
-    TileInfo ("IOI_LTERM_UPPER_BOT", SINGLE, &["lterm"]),
-    TileInfo ("IOI_LTERM_UPPER_TOP", SINGLE, &["lterm"]),
-    TileInfo ("INT_LTERM", SINGLE, &["lterm"]),
-    TileInfo ("CNR_TL_LTERM", SINGLE, &["lterm"]),
-
-    TileInfo ("IOI_RTERM", SINGLE, &["rterm"]),
-    TileInfo ("IOI_RTERM_LOWER_BOT", SINGLE, &["rterm"]),
-    TileInfo ("IOI_RTERM_LOWER_TOP", SINGLE, &["rterm"]),
-    TileInfo ("IOI_RTERM_UPPER_BOT", SINGLE, &["rterm"]),
-    TileInfo ("IOI_RTERM_UPPER_TOP", SINGLE, &["rterm"]),
-    TileInfo ("INT_RTERM", SINGLE, &["rterm"]),
-    TileInfo ("CNR_TR_RTERM", SINGLE, &["rterm"]),
-
-    TileInfo ("CNR_TR_TTERM", SINGLE, &["tterm"]),
-    TileInfo ("IOI_TTERM", SINGLE, &["tterm"]),
-    TileInfo ("IOI_TTERM_BUFPLL", SINGLE, &["tterm"]),
-    TileInfo ("DSP_INT_TTERM", SINGLE, &["tterm"]),
-    TileInfo ("RAMB_TOP_TTERM", SINGLE, &["tterm"]),
-
-    TileInfo ("CNR_BR_BTERM", SINGLE, &["bterm"]),
-    TileInfo ("CLB_INT_BTERM", SINGLE, &["bterm"]),
-    TileInfo ("IOI_BTERM", SINGLE, &["bterm"]),
-    TileInfo ("IOI_BTERM_BUFPLL", SINGLE, &["bterm"]),
-    TileInfo ("RAMB_BOT_BTERM", SINGLE, &["bterm"]),
-    TileInfo ("DSP_INT_BTERM", SINGLE, &["bterm"]),
-
-    TileInfo ("IOI_TTERM_CLB", SINGLE, &[]),
-    TileInfo ("IOI_TTERM_REGT", SINGLE, &[]),
-    TileInfo ("REG_T_TTERM", SINGLE, &[]),
-    TileInfo ("REG_V_TTERM", SINGLE, &[]),
-    TileInfo ("BRAM_INTER_TTERM", SINGLE, &[]),
-    TileInfo ("BRAM_TOP_TTERM_L", SINGLE, &[]),
-    TileInfo ("BRAM_TOP_TTERM_R", SINGLE, &[]),
-    TileInfo ("RAMB_TOP", SINGLE, &[]),
-    TileInfo ("BRAM_INT_INTERFACE_TOP", SINGLE, &[]),
-    TileInfo ("BRAM_TOP", SINGLE, &[]),
-    TileInfo ("DSP_INTER_TTERM", SINGLE, &[]),
-    TileInfo ("DSP_TOP_TTERM_L", SINGLE, &[]),
-    TileInfo ("DSP_TOP_TTERM_R", SINGLE, &[]),
-    TileInfo ("DSP_INT_EMP_TOP", SINGLE, &[]),
-    TileInfo ("DSP_EMP_TEMP", SINGLE, &[]),
-    TileInfo ("DSP_EMP_TOP", SINGLE, &[]),
-    TileInfo ("IOI_PCI_CE_LEFT", SINGLE, &[]),
-    TileInfo ("IOI_PCI_CE_RIGHT", SINGLE, &[]),
-    TileInfo ("MCB_CNR_TOP", SINGLE, &[]),
-
-    TileInfo ("IOI_BTERM_CLB", SINGLE, &[]),
-    TileInfo ("IOI_BTERM_REGB", SINGLE, &[]),
-    TileInfo ("REG_B_BTERM", SINGLE, &[]),
-    TileInfo ("REG_V_BTERM", SINGLE, &[]),
-    TileInfo ("CLB_EMP_BTERM", SINGLE, &[]),
-    TileInfo ("RAMB_BOT", SINGLE, &[]),
-    TileInfo ("BRAM_INT_INTERFACE_BOT", SINGLE, &[]),
-    TileInfo ("BRAM_BOT", SINGLE, &[]),
-    TileInfo ("BRAM_INTER_BTERM", SINGLE, &[]),
-    TileInfo ("BRAM_BOT_BTERM_L", SINGLE, &[]),
-    TileInfo ("BRAM_BOT_BTERM_R", SINGLE, &[]),
-    TileInfo ("DSP_INT_EMP_BOT", SINGLE, &[]),
-    TileInfo ("DSP_EMP_BOT", SINGLE, &[]),
-    TileInfo ("DSP_BOT_BTERM_L", SINGLE, &[]),
-    TileInfo ("DSP_BOT_BTERM_R", SINGLE, &[]),
-
-    TileInfo ("CLEXL", SINGLE, &["clb"]),
-    TileInfo ("CLEXM", SINGLE, &["clbm"]),
-    TileInfo ("CLEXL_DUMMY", SINGLE, &["clb", "crippled"]),
-    TileInfo ("CLEXM_DUMMY", SINGLE, &["clbm", "crippled"]),
-
-    TileInfo ("BRAMSITE2", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("MACCSITE2", (0, 0, 3, 0), &["dsp"]),
-    TileInfo ("BRAMSITE2_DUMMY", (0, 0, 3, 0), &["bram", "crippled"]),
-    TileInfo ("MACCSITE2_DUMMY", (0, 0, 3, 0), &["dsp", "crippled"]),
-
-    TileInfo ("LIOI", SINGLE, &["io"]),
-    TileInfo ("LIOI_BRK", SINGLE, &["io"]),
-    TileInfo ("RIOI", SINGLE, &["io"]),
-    TileInfo ("RIOI_BRK", SINGLE, &["io"]),
-    TileInfo ("TIOI_INNER", SINGLE, &["io"]),
-    TileInfo ("TIOI_INNER_UNUSED", SINGLE, &["io", "crippled"]),
-    TileInfo ("TIOI_OUTER", SINGLE, &["io"]),
-    TileInfo ("BIOI_INNER", SINGLE, &["io"]),
-    TileInfo ("BIOI_INNER_UNUSED", SINGLE, &["io", "crippled"]),
-    TileInfo ("BIOI_OUTER_UNUSED", SINGLE, &["io", "crippled"]),
-    TileInfo ("BIOI_OUTER", SINGLE, &["io"]),
-
-    TileInfo ("UL", SINGLE, &["cfg"]),
-    TileInfo ("UR_LOWER", SINGLE, &["cfg"]),
-    TileInfo ("UR_UPPER", SINGLE, &["cfg"]),
-    TileInfo ("LL", SINGLE, &["cfg"]),
-    TileInfo ("LR_LOWER", SINGLE, &["cfg"]),
-    TileInfo ("LR_UPPER", SINGLE, &["cfg"]),
-
-    TileInfo ("CMT_CAP", (0, 0, 7, 0), &[]),
-    TileInfo ("CMT_PLL_BOT", (0, 0, 7, 9), &["pll-alt"]),
-    TileInfo ("CMT_PLL1_BOT", (0, 0, 7, 9), &["pll-alt"]),
-    TileInfo ("CMT_PLL2_BOT", (0, 0, 7, 9), &["pll-alt"]),
-    TileInfo ("CMT_PLL3_BOT", (0, 0, 7, 9), &["pll-alt"]),
-    TileInfo ("CMT_PLL_TOP", (0, 0, 7, 9), &["pll-alt"]),
-    TileInfo ("CMT_PLL2_TOP", (0, 0, 7, 9), &["pll-alt"]),
-    TileInfo ("CMT_PLL3_TOP", (0, 0, 7, 9), &["pll-alt"]),
-    TileInfo ("CMT_DCM_BOT", (0, 0, 7, 9), &["pll"]),
-    TileInfo ("CMT_DCM2_BOT", (0, 0, 7, 9), &["pll"]),
-    TileInfo ("CMT_DCM_TOP", (0, 0, 7, 9), &["pll"]),
-    TileInfo ("CMT_DCM2_TOP", (0, 0, 7, 9), &["pll"]),
-
-    TileInfo ("REG_T", SINGLE, &["clk-global-buf"]),
-    TileInfo ("REG_B", SINGLE, &["clk-global-buf"]),
-    TileInfo ("REG_L", SINGLE, &["clk-global-buf"]),
-    TileInfo ("REG_R", SINGLE, &["clk-global-buf"]),
-
-    TileInfo ("MCB_L", (0, 0, 5, 6), &["hardip"]),
-    TileInfo ("MCB_DUMMY", (0, 0, 5, 6), &["hardip", "crippled"]),
-    TileInfo ("MCB_L_BOT", (0, 0, 5, 6), &["hardip"]),
-    TileInfo ("MCB_MUI0R", (0, 0, 1, 0), &["hardip"]),
-    TileInfo ("MCB_MUI0W", (0, 0, 1, 0), &["hardip"]),
-    TileInfo ("MCB_MUI1R", (0, 0, 1, 0), &["hardip"]),
-    TileInfo ("MCB_MUI1W", (0, 0, 1, 0), &["hardip"]),
-    TileInfo ("MCB_MUI2", (0, 0, 1, 0), &["hardip"]),
-    TileInfo ("MCB_MUI3", (0, 0, 1, 0), &["hardip"]),
-    TileInfo ("MCB_MUI4", (0, 0, 1, 0), &["hardip"]),
-    TileInfo ("MCB_MUI5", (0, 0, 1, 0), &["hardip"]),
-    TileInfo ("MCB_MUI_DUMMY", (0, 0, 1, 0), &["hardip", "crippled"]),
-    TileInfo ("MCB_INT", SINGLE, &[]),
-    TileInfo ("MCB_INT_DUMMY", SINGLE, &[]),
-    TileInfo ("MCB_INT_ULDM", SINGLE, &[]),
-    TileInfo ("MCB_INT_DQI", SINGLE, &[]),
-    TileInfo ("MCB_INT_BOT", SINGLE, &[]),
-    TileInfo ("MCB_INT_BOT25", SINGLE, &[]),
-    TileInfo ("MCB_CAP_INT", SINGLE, &[]),
-    TileInfo ("MCB_CAP_INT_BRK", SINGLE, &[]),
-    TileInfo ("MCB_CAP_CLKPN", SINGLE, &[]),
-
-    TileInfo ("GTPDUAL_TOP", (0, 0, 7, 9), &["gtp"]),
-    TileInfo ("GTPDUAL_BOT", (0, 0, 7, 9), &["gtp"]),
-    TileInfo ("GTPDUAL_TOP_UNUSED", (0, 0, 7, 9), &["gtp", "crippled"]),
-    TileInfo ("GTPDUAL_BOT_UNUSED", (0, 0, 7, 9), &["gtp", "crippled"]),
-    TileInfo ("GTPDUAL_LEFT_INT_FEEDTHRU", (0, 0, 7, 0), &[]),
-    TileInfo ("GTPDUAL_LEFT_CLB_FEEDTHRU", (0, 0, 7, 0), &[]),
-    TileInfo ("GTPDUAL_LEFT_DSP_FEEDTHRU", (0, 0, 7, 0), &[]),
-    TileInfo ("GTPDUAL_INT_FEEDTHRU", (0, 0, 7, 9), &[]),
-    TileInfo ("GTPDUAL_CLB_FEEDTHRU", (0, 0, 7, 9), &[]),
-    TileInfo ("GTPDUAL_DSP_FEEDTHRU", (0, 0, 7, 9), &[]),
-    TileInfo ("PCIE_TOP", (0, 0, 7, 9), &["hardip"]),
-    TileInfo ("PCIE_TOP_UNUSED", (0, 0, 7, 9), &["hardip", "crippled"]),
-    TileInfo ("PCIE_TOP_INT_FEEDTHRU", (0, 0, 7, 9), &[]),
-    TileInfo ("PCIE_TOP_CLB_FEEDTHRU", (0, 0, 7, 9), &[]),
-
-    TileInfo ("HCLK_CLB_XM_INT", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_CLB_XL_INT", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_CLB_XM_CLE", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CLB_XL_CLE", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CLB_XM_INT_FOLD", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_CLB_XL_INT_FOLD", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_CLB_XM_CLE_FOLD", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CLB_XL_CLE_FOLD", SINGLE, &["clk-row"]),
-    TileInfo ("BRAM_HCLK_FEEDTHRU", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM_HCLK_FEEDTHRU_INTER", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM_FEEDTHRU", SINGLE, &["clk-row"]),
-    TileInfo ("BRAM_HCLK_FEEDTHRU_FOLD", SINGLE, &["clk-last-buf"]),
-    TileInfo ("BRAM_HCLK_FEEDTHRU_INTER_FOLD", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM_FEEDTHRU_FOLD", SINGLE, &["clk-row"]),
-    TileInfo ("DSP_INT_HCLK_FEEDTHRU", SINGLE, &["clk-last-buf"]),
-    TileInfo ("DSP_CLB_HCLK_FEEDTHRU", SINGLE, &["clk-row"]),
-    TileInfo ("DSP_HCLK_FEEDTHRU_TOP", SINGLE, &["clk-row"]),
-    TileInfo ("DSP_INT_HCLK_FEEDTHRU_FOLD", SINGLE, &["clk-last-buf"]),
-    TileInfo ("DSP_CLB_HCLK_FEEDTHRU_FOLD", SINGLE, &["clk-row"]),
-    TileInfo ("DSP_HCLK_GCLK_NOFOLD", SINGLE, &["clk-row"]),
-    TileInfo ("DSP_HCLK_GCLK_FOLD", SINGLE, &["clk-row-buf"]),
-    TileInfo ("HCLK_IOIL_INT_FOLD", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_IOIR_INT_FOLD", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_IOIL_INT", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_IOIR_INT", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_IOIL_TOP_DN", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIL_TOP_UP", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIL_TOP_SPLIT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIL_BOT_DN", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIL_BOT_UP", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIL_BOT_SPLIT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIR_TOP_DN", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIR_TOP_UP", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIR_TOP_SPLIT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIR_BOT_DN", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIR_BOT_UP", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIR_BOT_SPLIT", SINGLE, &["clk-row"]),
-    TileInfo ("MCB_HCLK", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIL_EMP", SINGLE, &[]),
-    TileInfo ("HCLK_IOI_LTERM", SINGLE, &[]),
-    TileInfo ("HCLK_IOI_LTERM_BOT25", SINGLE, &[]),
-    TileInfo ("HCLK_IOIR_EMP", SINGLE, &[]),
-    TileInfo ("HCLK_IOI_RTERM", SINGLE, &[]),
-    TileInfo ("HCLK_IOI_RTERM_BOT25", SINGLE, &[]),
-    TileInfo ("CMT_HCLK_BOT25", SINGLE, &["clk-row"]),
-
-    TileInfo ("REG_V", SINGLE, &["clk-spine"]),
-    TileInfo ("REG_V_BRK", SINGLE, &["clk-spine"]),
-    TileInfo ("REG_V_HCLK", SINGLE, &["clk-row-buf"]),
-    TileInfo ("REG_V_HCLK_BOT25", SINGLE, &["clk-row-buf"]),
-    TileInfo ("REG_V_MEMB_TOP", SINGLE, &["clk-spine"]),
-    TileInfo ("REG_V_HCLKBUF_TOP", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("REG_V_MIDBUF_TOP", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("REG_V_MEMB_BOT", SINGLE, &["clk-spine"]),
-    TileInfo ("REG_V_HCLKBUF_BOT", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("REG_V_MIDBUF_BOT", SINGLE, &["clk-spine-buf"]),
-
-    TileInfo ("REGH_CLEXM_INT", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_CLEXL_INT", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_CLEXM_CLE", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_CLEXL_CLE", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_CLEXM_INT_GCLKL", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("REGH_CLEXL_INT_CLK", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("BRAM_REGH_FEEDTHRU", SINGLE, &["clk-spine"]),
-    TileInfo ("BRAM_REGH_FEEDTHRU_INTER", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_BRAM_FEEDTHRU", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_BRAM_FEEDTHRU_L_GCLK", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("REGH_BRAM_FEEDTHRU_R_GCLK", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("REGH_DSP_INT", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_DSP_CLB", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_DSP_L_NOCLK", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_DSP_L", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("REGH_DSP_R", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("MCB_REGH", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_IOI_LTERM", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_IOI_RTERM", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_IOI", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_IOI_BOT25", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_RIOI_INT", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_RIOI_INT_BOT25", SINGLE, &["clk-spine"]),
-    TileInfo ("REGH_LIOI_INT", SINGLE, &["clk-spine", "hardip"]),
-    TileInfo ("REGH_LIOI_INT_BOT25", SINGLE, &["clk-spine", "hardip"]),
-    TileInfo ("REGH_RIOI", SINGLE, &["clk-spine", "hardip"]),
-    TileInfo ("REGH_RIOI_BOT25", SINGLE, &["clk-spine", "hardip"]),
-    TileInfo ("REGC_INT", SINGLE, &["clk-spine"]),
-    TileInfo ("REGC_CLE", SINGLE, &["clk-spine"]),
-    TileInfo ("REG_C_CMT", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKC", SINGLE, &["clk-global-buf"]),
-
-    TileInfo ("LIOB", SINGLE, &["iob"]),
-    TileInfo ("RIOB", SINGLE, &["iob"]),
-    TileInfo ("LIOB_PCI", SINGLE, &["iob-spec"]),
-    TileInfo ("LIOB_RDY", SINGLE, &["iob-spec"]),
-    TileInfo ("RIOB_PCI", SINGLE, &["iob-spec"]),
-    TileInfo ("RIOB_RDY", SINGLE, &["iob-spec"]),
-    TileInfo ("TIOB", SINGLE, &["iob"]),
-    TileInfo ("TIOB_SINGLE", SINGLE, &["iob"]),
-    TileInfo ("BIOB", SINGLE, &["iob"]),
-    TileInfo ("BIOB_SINGLE", SINGLE, &["iob"]),
-    TileInfo ("BIOB_SINGLE_ALT", SINGLE, &["iob"]),
-
-    TileInfo ("NULL", SINGLE, &[]),
-    TileInfo ("EMP_LIOB", SINGLE, &[]),
-    TileInfo ("EMP_RIOB", SINGLE, &[]),
-];
-
-const VIRTEX4_TILES: &'static [TileInfo] = &[
-    TileInfo ("INT", SINGLE, &["int"]),
-    TileInfo ("INT_SO", SINGLE, &["int"]),
-    TileInfo ("INT_SO_DCM0", SINGLE, &["int"]),
-    TileInfo ("CLB", SINGLE, &["clb"]),
-    TileInfo ("BRAM", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("DSP", (0, 0, 3, 0), &["dsp"]),
-    TileInfo ("DCM", (0, 0, 3, 0), &["pll"]),
-    TileInfo ("DCM_BOT", (0, 0, 3, 0), &["pll"]),
-    TileInfo ("CCM", (0, 0, 3, 0), &["pll-alt"]),
-    TileInfo ("SYS_MON", (0, 0, 7, 0), &["sysmon"]),
-    TileInfo ("IOIS_NC", SINGLE, &["io"]),
-    TileInfo ("IOIS_NC_L", SINGLE, &["io"]),
-    TileInfo ("IOIS_LC", SINGLE, &["io-spec"]),
-    TileInfo ("IOIS_LC_L", SINGLE, &["io-spec"]),
-    TileInfo ("CFG_CENTER", (0, 0, 8, 8), &["cfg"]),
-    TileInfo ("CLB_BUFFER", SINGLE, &["int-buf"]),
-    TileInfo ("PT", (14, 0, 3, 9), &["hardip"]),
-    TileInfo ("PB", (14, 0, 8, 3), &["hardip"]),
-
-    TileInfo ("MGT_AL", (0, 0, 7, 9), &["gtp", "lterm"]),
-    TileInfo ("MGT_AL_MID", (0, 0, 7, 9), &["gtp", "lterm"]),
-    TileInfo ("MGT_AL_BOT", (0, 0, 7, 9), &["gtp", "lterm"]),
-    TileInfo ("MGT_BL", (0, 0, 7, 9), &["gtp", "lterm"]),
-    TileInfo ("MGT_AR", (0, 0, 7, 9), &["gtp", "rterm"]),
-    TileInfo ("MGT_AR_MID", (0, 0, 7, 9), &["gtp", "rterm"]),
-    TileInfo ("MGT_AR_BOT", (0, 0, 7, 9), &["gtp", "rterm"]),
-    TileInfo ("MGT_BR", (0, 0, 7, 9), &["gtp", "rterm"]),
-    TileInfo ("MGT_R", SINGLE, &[]),
-    TileInfo ("BRKH_MGT11CLK_L", SINGLE, &["gtclk"]),
-    TileInfo ("BRKH_MGT11CLK_R", SINGLE, &["gtclk"]),
-
-    TileInfo ("PPC_R_PB", SINGLE, &[]),
-    TileInfo ("PPC_R_PT", SINGLE, &[]),
-    TileInfo ("PPC_B_PB", SINGLE, &[]),
-    TileInfo ("PPC_T_PT", SINGLE, &[]),
-    TileInfo ("PPC_R", SINGLE, &[]),
-    TileInfo ("PPC_TR", SINGLE, &[]),
-    TileInfo ("PPC_BR", SINGLE, &[]),
-    TileInfo ("PPC_T", SINGLE, &[]),
-    TileInfo ("PPC_B", SINGLE, &[]),
-    TileInfo ("PPC_BRAM_T", SINGLE, &[]),
-    TileInfo ("PPC_BRAM_B", SINGLE, &[]),
-
-    TileInfo ("L_TERM_INT", SINGLE, &["lterm"]),
-    TileInfo ("R_TERM_INT", SINGLE, &["rterm"]),
-    TileInfo ("B_TERM_INT", SINGLE, &["bterm"]),
-    TileInfo ("T_TERM_INT", SINGLE, &["tterm"]),
-
-    TileInfo ("EMPTY_CLB", SINGLE, &[]),
-    TileInfo ("EMPTY_EDGE_BRAM", SINGLE, &[]),
-    TileInfo ("EMPTY_EDGE_DSP", SINGLE, &[]),
-    TileInfo ("EMPTY_EDGE_CONFIG", SINGLE, &[]),
-    TileInfo ("EMPTY_IOIS", SINGLE, &[]),
-    TileInfo ("EMPTY_CORNER", SINGLE, &[]),
-    TileInfo ("EMPTY_CLB_BUFFER", SINGLE, &[]),
-    TileInfo ("EMPTY_HCLK", SINGLE, &[]),
-    TileInfo ("EMPTY_BRKH", SINGLE, &[]),
-    TileInfo ("EMPTY_VBRK", SINGLE, &[]),
-    TileInfo ("EMPTY_EDGE_MGT", SINGLE, &[]),
-
-    TileInfo ("HCLK", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_CLB", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM_FX", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_DSP", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_FT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_PPC", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_MGT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_VBRK", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_VBRK_R", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CENTER", SINGLE, &["clk-row", "iobank"]),
-    TileInfo ("HCLK_CENTER_ABOVE_CFG", SINGLE, &["clk-row", "iobank"]),
-    TileInfo ("HCLK_DCMIOB", SINGLE, &["clk-row", "iobank"]),
-    TileInfo ("HCLK_IOBDCM", SINGLE, &["clk-row", "iobank"]),
-    TileInfo ("HCLK_DCM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOIS_DCI", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOIS_LVDS", SINGLE, &["clk-row-buf", "iobank"]),
-
-    TileInfo ("BRKH", SINGLE, &["clk-brk"]),
-    TileInfo ("BRKH_CLB", SINGLE, &[]),
-    TileInfo ("BRKH_BRAM", SINGLE, &[]),
-    TileInfo ("BRKH_DSP", SINGLE, &[]),
-    TileInfo ("BRKH_IOIS", SINGLE, &[]),
-    TileInfo ("BRKH_DUMMY", SINGLE, &[]),
-    TileInfo ("BRKH_BUFGCTRL", SINGLE, &[]),
-    TileInfo ("BRKH_PPC", SINGLE, &[]),
-    TileInfo ("BRKH_DCM", SINGLE, &[]),
-    TileInfo ("BRKH_MGT", SINGLE, &[]),
-    TileInfo ("BRKH_MGT_R", SINGLE, &[]),
-    TileInfo ("BRKH_VBRK", SINGLE, &[]),
-
-    TileInfo ("CLK_BUFGCTRL_T", (0, 0, 7, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_BUFGCTRL_B", (0, 0, 7, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_HROW", SINGLE, &["clk-row-buf"]),
-    TileInfo ("CLK_IOB_T", (0, 0, 8, 8), &["clk-spine-buf"]),
-    TileInfo ("CLK_IOB_B", (0, 0, 8, 8), &["clk-spine-buf"]),
-    TileInfo ("CLKV_DCM_B", (0, 0, 7, 0), &["clk-spine-buf"]),
-    TileInfo ("CLKV_DCM_T", (0, 0, 7, 0), &["clk-spine-buf"]),
-    TileInfo ("CLKV", SINGLE, &["clk-spine"]),
-    TileInfo ("GBRKC", SINGLE, &["clk-spine"]),
-    TileInfo ("CLK_TERM_B", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CLK_TERM_T", SINGLE, &["clk-spine-buf"]),
-
-    TileInfo ("CFG_VBRK_FRAME", SINGLE, &["vbrk"]),
-];
-
-const VIRTEX5_TILES: &'static [TileInfo] = &[
-    TileInfo ("INT", SINGLE, &["int"]),
-    TileInfo ("INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("GTX_LEFT_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("GTP_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("EMAC_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("PCIE_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("PPC_L_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("PPC_R_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("SITE_FEEDTHRU", SINGLE, &[]),
-    TileInfo ("CLBLL", SINGLE, &["clb"]),
-    TileInfo ("CLBLM", SINGLE, &["clbm"]),
-    TileInfo ("IOI", SINGLE, &["io"]),
-    TileInfo ("BRAM", (0, 0, 4, 0), &["bram"]),
-    TileInfo ("PCIE_BRAM", (0, 0, 4, 0), &["bram"]),
-    TileInfo ("DSP", (0, 0, 4, 0), &["dsp"]),
-    TileInfo ("EMAC", (0, 0, 9, 0), &["hardip"]),
-    TileInfo ("PCIE_T", (0, 0, 9, 11), &["hardip"]),
-    TileInfo ("PCIE_BRKH", SINGLE, &["hardip"]),
-    TileInfo ("PCIE_B", (0, 0, 9, 11), &["hardip"]),
-    TileInfo ("CMT_TOP", (1, 0, 9, 0), &["pll"]),
-    TileInfo ("CMT_BOT", (1, 0, 9, 0), &["pll"]),
-    TileInfo ("CFG_CENTER", (0, 0, 10, 10), &["cfg"]),
-    TileInfo ("GT3", (0, 0, 10, 10), &["gtp"]),
-    TileInfo ("GTX", (0, 0, 10, 10), &["gtx"]),
-    TileInfo ("GTX_LEFT", (0, 0, 10, 10), &["gtx"]),
-    TileInfo ("CFG_VBRK", SINGLE, &["vbrk"]),
-    TileInfo ("CFG_PPC_VBRK", SINGLE, &["vbrk"]),
-    TileInfo ("CFG_PPC_R_VBRK", SINGLE, &["vbrk"]),
-    TileInfo ("INT_BUFS_L", SINGLE, &["int-buf"]),
-    TileInfo ("INT_BUFS_R", SINGLE, &["int-buf"]),
-    TileInfo ("INT_BUFS_R_MON", SINGLE, &["int-buf"]),
-    TileInfo ("LIOB", SINGLE, &["iob"]),
-    TileInfo ("LIOB_MON", SINGLE, &["iob"]),
-    TileInfo ("CIOB", SINGLE, &["iob"]),
-    TileInfo ("RIOB", SINGLE, &["iob"]),
-
-    TileInfo ("PPC_B", (16, 6, 11, 10), &["hardip"]),
-    TileInfo ("PPC_T", (16, 6, 10, 10), &["hardip"]),
-
-    TileInfo ("PPC_T_TERM", SINGLE, &["bterm"]),
-    TileInfo ("PPC_B_TERM", SINGLE, &["tterm"]),
-    TileInfo ("GTX_L_TERM_INT", SINGLE, &["lterm"]),
-    TileInfo ("L_TERM_INT", SINGLE, &["lterm"]),
-    TileInfo ("L_TERM_PPC", SINGLE, &["lterm"]),
-    TileInfo ("R_TERM_INT", SINGLE, &["rterm"]),
-    TileInfo ("R_TERM_PPC", SINGLE, &["rterm"]),
-    TileInfo ("L_TERM_PPC_EXT", SINGLE, &[]),
-    TileInfo ("CFG_PPC_DL_BUFS", SINGLE, &[]),
-
-    TileInfo ("B_TERM_INT_D", SINGLE, &[]),
-    TileInfo ("T_TERM_INT_D", SINGLE, &[]),
-    TileInfo ("R_TERM_INT_D", SINGLE, &[]),
-
-    TileInfo ("HCLK", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_CLB", SINGLE, &["clk-row"]),
-    TileInfo ("CFG_HCLK_INTERFACE", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM_MGT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM_MGT_LEFT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_PCIE_BRAM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_DSP", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_VBRK", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_PPC", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_PPC_TERM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_LIOB", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOB", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_TERM_L", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_TERM_R", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_GT3", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_GTX", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_GTX_LEFT", SINGLE, &["clk-row"]),
-    TileInfo ("INT_HCLK_BUFS", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CMT_CMT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CMT_CMT_MGT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOB_CMT_MID", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOB_CMT_TOP", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOB_CMT_BOT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOB_CMT_MID_MGT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOB_CMT_TOP_MGT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOB_CMT_BOT_MGT", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOI", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOI_CENTER", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOI_CMT", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOI_CMT_MGT", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_CMT_IOI", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOI_BOTCEN", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOI_BOTCEN_MGT", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOI_TOPCEN", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOI_TOPCEN_MGT", SINGLE, &["clk-row-buf", "iobank"]),
-
-    TileInfo ("BRKH", SINGLE, &["clk-brk"]),
-    TileInfo ("BRKH_CLB", SINGLE, &[]),
-    TileInfo ("BRKH_BRAM", SINGLE, &[]),
-    TileInfo ("BRKH_DSP", SINGLE, &[]),
-    TileInfo ("BRKH_GT3", SINGLE, &[]),
-    TileInfo ("BRKH_GTX_LEFT", SINGLE, &[]),
-    TileInfo ("BRKH_IOI", SINGLE, &[]),
-
-    TileInfo ("CLK_BUFGMUX", (0, 0, 10, 10), &["clk-global-buf"]),
-    TileInfo ("CLKV", SINGLE, &["clk-spine"]),
-    TileInfo ("CLKV_MC", SINGLE, &["clk-spine"]),
-    TileInfo ("CLK_HROW", SINGLE, &["clk-row-buf"]),
-    TileInfo ("CLK_HROW_MGT", SINGLE, &["clk-row-buf"]),
-    TileInfo ("CLK_MGT_BOT", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_MGT_TOP", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_MGT_BOT_MGT", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_MGT_TOP_MGT", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_CMT_BOT", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_CMT_TOP", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_CMT_BOT_MGT", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_CMT_TOP_MGT", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_IOB_B", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_IOB_T", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_TERM_TOP", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CLK_TERM_BOT", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("GBRKC", SINGLE, &["clk-spine"]),
-
-    TileInfo ("NULL", SINGLE, &[]),
-];
-
-const VIRTEX6_TILES: &'static [TileInfo] = &[
-    TileInfo ("INT", SINGLE, &["int"]),
-    TileInfo ("INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("IOI_L_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("GTX_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("EMAC_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("PCIE_INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("PCIE_INT_INTERFACE_R", SINGLE, &["int-if"]),
-    TileInfo ("GT_L_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("CLBLL", SINGLE, &["clb"]),
-    TileInfo ("CLBLM", SINGLE, &["clbm"]),
-    TileInfo ("VBRK", SINGLE, &["vbrk"]),
-    TileInfo ("LIOI", (0, 0, 1, 0), &["io"]),
-    TileInfo ("LIOB", (0, 0, 1, 0), &["iob"]),
-    TileInfo ("LIOB_FT", (0, 0, 1, 0), &["iob"]),
-    TileInfo ("RIOI", (0, 0, 1, 0), &["io"]),
-    TileInfo ("RIOB", (0, 0, 1, 0), &["iob"]),
-    TileInfo ("BRAM", (0, 0, 4, 0), &["bram"]),
-    TileInfo ("DSP", (0, 0, 4, 0), &["dsp"]),
-    TileInfo ("EMAC", (0, 0, 9, 0), &["hardip"]),
-    TileInfo ("EMAC_DUMMY", (0, 0, 9, 0), &["hardip", "crippled"]),
-    TileInfo ("PCIE", (4, 0, 9, 10), &["hardip"]),
-    TileInfo ("GTX", (0, 0, 9, 0), &["gtx"]),
-    TileInfo ("GTX_DUMMY", (0, 0, 9, 0), &["gtx", "crippled"]),
-    TileInfo ("GTX_LEFT", (0, 0, 9, 0), &["gtx"]),
-    TileInfo ("GTH_L_TOP", (0, 0, 9, 10), &["gth"]),
-    TileInfo ("GTH_L_BOT", (0, 0, 9, 10), &["gth"]),
-    TileInfo ("GTH_TOP", (0, 0, 9, 10), &["gth"]),
-    TileInfo ("GTH_BOT", (0, 0, 9, 10), &["gth"]),
-    TileInfo ("CMT_BOT", (0, 0, 8, 9), &["pll"]),
-    TileInfo ("CMT_TOP", (0, 0, 8, 9), &["pll"]),
-    TileInfo ("CMT_BUFG_BOT", (0, 0, 1, 0), &["clk-global-buf"]),
-    TileInfo ("CMT_BUFG_TOP", (0, 0, 1, 0), &["clk-global-buf"]),
-    TileInfo ("CMT_PMVA", (0, 0, 1, 0), &["clk-spine-buf"]),
-    TileInfo ("CMT_PMVA_BELOW", (0, 0, 1, 0), &["clk-spine-buf"]),
-    TileInfo ("CMT_PMVB", (0, 0, 1, 0), &["clk-spine-buf"]),
-    TileInfo ("CMT_PMVB_BUF_ABOVE", (0, 0, 1, 0), &["clk-spine-buf"]),
-    TileInfo ("CMT_PMVB_BUF_BELOW", (0, 0, 1, 0), &["clk-spine-buf"]),
-
-    TileInfo ("CFG_CENTER_0", (0, 0, 10, 10), &["cfg"]),
-    TileInfo ("CFG_CENTER_1", (0, 0, 10, 10), &["cfg"]),
-    TileInfo ("CFG_CENTER_2", (0, 0, 10, 10), &["cfg"]),
-    TileInfo ("CFG_CENTER_3", (0, 0, 9, 10), &["cfg"]),
-    TileInfo ("CENTER_SPACE1", SINGLE, &["cfg"]),
-    TileInfo ("CENTER_SPACE2", SINGLE, &["cfg"]),
-    TileInfo ("VFRAME", SINGLE, &["cfg"]),
-    TileInfo ("VFRAME_NOMON", SINGLE, &["cfg"]),
-
-    TileInfo ("L_TERM_INT", SINGLE, &["lterm"]),
-    TileInfo ("R_TERM_INT", SINGLE, &["rterm"]),
-    TileInfo ("BRKH_B_TERM_INT", SINGLE, &["tterm"]),
-    TileInfo ("BRKH_T_TERM_INT", SINGLE, &["bterm"]),
-    TileInfo ("B_TERM_INT", SINGLE, &[]),
-    TileInfo ("T_TERM_INT", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_TERM", SINGLE, &[]),
-    TileInfo ("IOI_L_INT_INTERFACE_TERM", SINGLE, &[]),
-    TileInfo ("GT_INT_INTERFACE_TERM", SINGLE, &[]),
-    TileInfo ("B_TERM_DSP", SINGLE, &[]),
-    TileInfo ("T_TERM_DSP", SINGLE, &[]),
-
-    TileInfo ("HCLK", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_QBUF_L", SINGLE, &["clk-last-buf", "clk-row-buf"]),
-    TileInfo ("HCLK_QBUF_R", SINGLE, &["clk-last-buf", "clk-row-buf"]),
-    TileInfo ("HCLK_CLBLL", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CLBLM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CLBLM_MGT", SINGLE, &["clk-row", "clk-row-buf"]),
-    TileInfo ("HCLK_CLBLM_MGT_LEFT", SINGLE, &["clk-row", "clk-row-buf"]),
-    TileInfo ("HCLK_INT_INTERFACE", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_DSP", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_VBRK", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_TERM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_INNER_IOI", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_OUTER_IOI", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOB", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_VFRAME", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_GT_EMP", SINGLE, &["clk-row"]),
-    TileInfo ("CENTER_SPACE_HCLK1", SINGLE, &["clk-row"]),
-    TileInfo ("CENTER_SPACE_HCLK2", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CMT_BOT", SINGLE, &["clk-row-buf"]),
-    TileInfo ("HCLK_CMT_TOP", SINGLE, &["clk-row-buf"]),
-    TileInfo ("HCLK_GTX", SINGLE, &["gtclk"]),
-    TileInfo ("HCLK_GTX_LEFT", SINGLE, &["gtclk"]),
-    TileInfo ("HCLK_GTX_DUMMY", SINGLE, &["gtclk", "crippled"]),
-    TileInfo ("HCLK_GTH", SINGLE, &["gtclk"]),
-    TileInfo ("HCLK_GTH_LEFT", SINGLE, &["gtclk"]),
-
-    TileInfo ("BRKH_INT", SINGLE, &["clk-brk"]),
-    TileInfo ("BRKH_CLB", SINGLE, &[]),
-    TileInfo ("BRKH_DSP", SINGLE, &[]),
-    TileInfo ("BRKH_BRAM", SINGLE, &[]),
-    TileInfo ("BRKH_IOI", SINGLE, &[]),
-    TileInfo ("BRKH_IOB", SINGLE, &[]),
-    TileInfo ("BRKH_CMT", SINGLE, &[]),
-    TileInfo ("BRKH_GTX", SINGLE, &[]),
-    TileInfo ("BRKH_GTX_L", SINGLE, &[]),
-    TileInfo ("BRKH_GTX_EMP", SINGLE, &[]),
-    TileInfo ("BRKH_GTH", SINGLE, &[]),
-    TileInfo ("BRKH_INT_INTERFACE", SINGLE, &[]),
-    TileInfo ("BRKH", SINGLE, &[]),
-
-    TileInfo ("NULL", SINGLE, &[]),
-];
-
-const SERIES7_TILES: &'static [TileInfo] = &[
-    TileInfo ("INT_L", SINGLE, &["int"]),
-    TileInfo ("INT_R", SINGLE, &["int"]),
-    TileInfo ("INT_L_SLV", SINGLE, &["int", "int-buf"]),
-    TileInfo ("INT_R_SLV", SINGLE, &["int", "int-buf"]),
-    TileInfo ("INT_L_SLV_FLY", SINGLE, &["int"]),
-    TileInfo ("INT_R_SLV_FLY", SINGLE, &["int"]),
-
-    TileInfo ("INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_R", SINGLE, &["int-if"]),
-    TileInfo ("BRAM_INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("BRAM_INT_INTERFACE_R", SINGLE, &["int-if"]),
-    TileInfo ("IO_INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("IO_INT_INTERFACE_R", SINGLE, &["int-if"]),
-    TileInfo ("GTP_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("GTP_INT_INTERFACE_L", SINGLE, &["int-if", "lterm"]),
-    TileInfo ("GTP_INT_INTERFACE_R", SINGLE, &["int-if", "rterm"]),
-    TileInfo ("GTX_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("GTX_INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("GTH_INT_INTERFACE", SINGLE, &["int-if"]),
-    TileInfo ("GTH_INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("PCIE_INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("PCIE_INT_INTERFACE_R", SINGLE, &["int-if"]),
-    TileInfo ("PCIE_INT_INTERFACE_LEFT_L", SINGLE, &["int-if"]),
-    TileInfo ("PCIE3_INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("PCIE3_INT_INTERFACE_R", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_PSS_L", SINGLE, &["int-if"]),
-
-    TileInfo ("HCLK_L", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_R", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_L_SLV", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_R_SLV", SINGLE, &["clk-last-buf"]),
-    TileInfo ("HCLK_L_BOT_UTURN", SINGLE, &["clk-last-buf", "bterm"]),
-    TileInfo ("HCLK_R_BOT_UTURN", SINGLE, &["clk-last-buf", "bterm"]),
-    TileInfo ("HCLK_L_TOP_UTURN", SINGLE, &["clk-last-buf", "tterm"]),
-    TileInfo ("HCLK_R_TOP_UTURN", SINGLE, &["clk-last-buf", "tterm"]),
-    TileInfo ("HCLK_INT_INTERFACE", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_TERM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CLB", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_BRAM", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_DSP_L", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_DSP_R", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_IOI", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOI3", SINGLE, &["clk-row-buf", "iobank"]),
-    TileInfo ("HCLK_IOB", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_VFRAME", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_VBRK", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_CMT", SINGLE, &["clk-row-buf"]),
-    TileInfo ("HCLK_CMT_L", SINGLE, &["clk-row-buf"]),
-    TileInfo ("HCLK_FIFO_L", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_GTX", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_TERM_GTX", SINGLE, &["clk-row"]),
-
-    TileInfo ("BRKH_INT", SINGLE, &["clk-brk"]),
-    TileInfo ("BRKH_TERM_INT", SINGLE, &["tterm"]),
-    TileInfo ("BRKH_INT_PSS", SINGLE, &["tterm"]),
-    TileInfo ("BRKH_B_TERM_INT", SINGLE, &["bterm"]),
-    TileInfo ("BRKH_CLB", SINGLE, &[]),
-    TileInfo ("BRKH_BRAM", SINGLE, &[]),
-    TileInfo ("BRKH_DSP_L", SINGLE, &[]),
-    TileInfo ("BRKH_DSP_R", SINGLE, &[]),
-    TileInfo ("BRKH_CMT", SINGLE, &[]),
-    TileInfo ("BRKH_GTX", SINGLE, &[]),
-    TileInfo ("TERM_CMT", SINGLE, &[]),
-
-    TileInfo ("B_TERM_INT", SINGLE, &["bterm"]),
-    TileInfo ("B_TERM_INT_SLV", SINGLE, &["bterm", "int-buf"]),
-    TileInfo ("B_TERM_INT_NOUTURN", SINGLE, &[]),
-    TileInfo ("B_TERM_INT_PSS", SINGLE, &[]),
-    TileInfo ("B_TERM_VBRK", SINGLE, &[]),
-    TileInfo ("T_TERM_INT", SINGLE, &["tterm"]),
-    TileInfo ("T_TERM_INT_SLV", SINGLE, &["tterm", "int-buf"]),
-    TileInfo ("T_TERM_INT_NOUTURN", SINGLE, &[]),
-    TileInfo ("L_TERM_INT", SINGLE, &["lterm"]),
-    TileInfo ("L_TERM_INT_BRAM", SINGLE, &["lterm"]),
-    TileInfo ("GTP_INT_INT_TERM_L", SINGLE, &["lterm"]),
-    TileInfo ("R_TERM_INT", SINGLE, &["rterm"]),
-    TileInfo ("R_TERM_INT_GTX", SINGLE, &["rterm"]),
-    TileInfo ("GTP_INT_INT_TERM_R", SINGLE, &["rterm"]),
-
-    TileInfo ("CLBLL_L", SINGLE, &["clb"]),
-    TileInfo ("CLBLL_R", SINGLE, &["clb"]),
-    TileInfo ("CLBLM_L", SINGLE, &["clbm"]),
-    TileInfo ("CLBLM_R", SINGLE, &["clbm"]),
-    TileInfo ("BRAM_L", (0, 0, 4, 0), &["bram"]),
-    TileInfo ("BRAM_R", (0, 0, 4, 0), &["bram"]),
-    TileInfo ("DSP_L", (0, 0, 4, 0), &["dsp"]),
-    TileInfo ("DSP_R", (0, 0, 4, 0), &["dsp"]),
-    TileInfo ("LIOI", (0, 0, 1, 0), &["io"]),
-    TileInfo ("LIOI_TBYTESRC", (0, 0, 1, 0), &["io-spec"]),
-    TileInfo ("LIOI_TBYTETERM", (0, 0, 1, 0), &["io-spec"]),
-    TileInfo ("LIOI_SING", SINGLE, &["io"]),
-    TileInfo ("LIOI3", (0, 0, 1, 0), &["io"]),
-    TileInfo ("LIOI3_TBYTESRC", (0, 0, 1, 0), &["io-spec"]),
-    TileInfo ("LIOI3_TBYTETERM", (0, 0, 1, 0), &["io-spec"]),
-    TileInfo ("LIOI3_SING", SINGLE, &["io"]),
-    TileInfo ("RIOI", (0, 0, 1, 0), &["io"]),
-    TileInfo ("RIOI_TBYTESRC", (0, 0, 1, 0), &["io-spec"]),
-    TileInfo ("RIOI_TBYTETERM", (0, 0, 1, 0), &["io-spec"]),
-    TileInfo ("RIOI_SING", SINGLE, &["io"]),
-    TileInfo ("RIOI3", (0, 0, 1, 0), &["io"]),
-    TileInfo ("RIOI3_TBYTESRC", (0, 0, 1, 0), &["io-spec"]),
-    TileInfo ("RIOI3_TBYTETERM", (0, 0, 1, 0), &["io-spec"]),
-    TileInfo ("RIOI3_SING", SINGLE, &["io"]),
-    TileInfo ("LIOB33", (0, 0, 1, 0), &["iob"]),
-    TileInfo ("LIOB33_SING", SINGLE, &["iob"]),
-    TileInfo ("LIOB18", (0, 0, 1, 0), &["iob-spec"]),
-    TileInfo ("LIOB18_SING", SINGLE, &["iob-spec"]),
-    TileInfo ("RIOB33", (0, 0, 1, 0), &["iob"]),
-    TileInfo ("RIOB33_SING", SINGLE, &["iob"]),
-    TileInfo ("RIOB18", (0, 0, 1, 0), &["iob-spec"]),
-    TileInfo ("RIOB18_SING", SINGLE, &["iob-spec"]),
-    TileInfo ("CMT_FIFO_L", (0, 0, 5, 6), &["io"]),
-    TileInfo ("CMT_FIFO_R", (0, 0, 5, 6), &["io"]),
-    TileInfo ("CMT_PMV_L", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CMT_PMV", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CMT_TOP_L_LOWER_B", (0, 0, 7, 8), &["pll"]),
-    TileInfo ("CMT_TOP_L_LOWER_T", (0, 0, 7, 1), &["hardip"]),
-    TileInfo ("CMT_TOP_L_UPPER_B", (0, 0, 7, 4), &["hardip"]),
-    TileInfo ("CMT_TOP_L_UPPER_T", (0, 0, 7, 5), &["pll-alt"]),
-    TileInfo ("CMT_TOP_R_LOWER_B", (0, 0, 7, 8), &["pll"]),
-    TileInfo ("CMT_TOP_R_LOWER_T", (0, 0, 7, 1), &["hardip"]),
-    TileInfo ("CMT_TOP_R_UPPER_B", (0, 0, 7, 4), &["hardip"]),
-    TileInfo ("CMT_TOP_R_UPPER_T", (0, 0, 7, 5), &["pll-alt"]),
-
-    TileInfo ("INT_FEEDTHRU_1", SINGLE, &["cfg"]),
-    TileInfo ("INT_FEEDTHRU_2", SINGLE, &["cfg"]),
-    TileInfo ("HCLK_FEEDTHRU_1", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_FEEDTHRU_1_PELE", SINGLE, &["clk-row"]),
-    TileInfo ("HCLK_FEEDTHRU_2", SINGLE, &["clk-row"]),
-    TileInfo ("CFG_CENTER_BOT", (0, 0, 9, 10), &["cfg"]),
-    TileInfo ("CFG_CENTER_MID", (0, 0, 9, 11), &["cfg"]),
-    TileInfo ("CFG_CENTER_MID_SLAVE", (0, 0, 9, 11), &["cfg"]),
-    TileInfo ("CFG_CENTER_TOP", (0, 0, 9, 0), &["cfg"]),
-    TileInfo ("CFG_CENTER_TOP_SLAVE", (0, 0, 9, 0), &["cfg"]),
-    TileInfo ("MONITOR_BOT", (0, 0, 9, 0), &["sysmon"]),
-    TileInfo ("MONITOR_MID", (0, 0, 9, 0), &["sysmon"]),
-    TileInfo ("MONITOR_TOP", (0, 0, 4, 0), &["sysmon"]),
-    TileInfo ("MONITOR_BOT_FUJI2", (0, 0, 9, 0), &["sysmon"]),
-    TileInfo ("MONITOR_MID_FUJI2", (0, 0, 9, 0), &["sysmon"]),
-    TileInfo ("MONITOR_TOP_FUJI2", (0, 0, 4, 0), &["sysmon"]),
-    TileInfo ("MONITOR_BOT_PELE1", (0, 0, 9, 0), &["sysmon"]),
-    TileInfo ("MONITOR_MID_PELE1", (0, 0, 9, 0), &["sysmon"]),
-    TileInfo ("MONITOR_TOP_PELE1", (0, 0, 4, 0), &["sysmon"]),
-    TileInfo ("MONITOR_BOT_SLAVE", (0, 0, 9, 0), &["sysmon"]),
-    TileInfo ("CFG_SECURITY_BOT_PELE1", (0, 0, 9, 0), &["cfg"]),
-    TileInfo ("CFG_SECURITY_MID_PELE1", (0, 0, 9, 0), &["cfg"]),
-    TileInfo ("CFG_SECURITY_TOP_PELE1", (0, 0, 4, 0), &["cfg"]),
-
-    TileInfo ("GTP_CHANNEL_0", (17, 0, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_1", (17, 0, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_2", (17, 0, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_3", (17, 0, 5, 5), &["gtp"]),
-    TileInfo ("GTP_COMMON", (17, 0, 6, 0), &["gtclk"]),
-    TileInfo ("GTP_CHANNEL_0_MID_LEFT", (19, 12, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_1_MID_LEFT", (19, 12, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_2_MID_LEFT", (19, 12, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_3_MID_LEFT", (19, 12, 5, 5), &["gtp"]),
-    TileInfo ("GTP_COMMON_MID_LEFT", (19, 12, 6, 0), &["gtclk"]),
-    TileInfo ("GTP_CHANNEL_0_MID_RIGHT", (17, 14, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_1_MID_RIGHT", (17, 14, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_2_MID_RIGHT", (17, 14, 5, 5), &["gtp"]),
-    TileInfo ("GTP_CHANNEL_3_MID_RIGHT", (17, 14, 5, 5), &["gtp"]),
-    TileInfo ("GTP_COMMON_MID_RIGHT", (17, 14, 6, 0), &["gtclk"]),
-    TileInfo ("GTP_MID_CHANNEL_STUB", (10, 0, 5, 5), &[]),
-    TileInfo ("GTP_MID_COMMON_STUB", (10, 0, 6, 0), &[]),
-    TileInfo ("GTX_CHANNEL_0", (0, 0, 5, 5), &["gtx"]),
-    TileInfo ("GTX_CHANNEL_1", (0, 0, 5, 5), &["gtx"]),
-    TileInfo ("GTX_CHANNEL_2", (0, 0, 5, 5), &["gtx"]),
-    TileInfo ("GTX_CHANNEL_3", (0, 0, 5, 5), &["gtx"]),
-    TileInfo ("GTX_COMMON", (0, 0, 6, 0), &["gtclk"]),
-    TileInfo ("GTH_CHANNEL_0", (0, 0, 5, 5), &["gth"]),
-    TileInfo ("GTH_CHANNEL_1", (0, 0, 5, 5), &["gth"]),
-    TileInfo ("GTH_CHANNEL_2", (0, 0, 5, 5), &["gth"]),
-    TileInfo ("GTH_CHANNEL_3", (0, 0, 5, 5), &["gth"]),
-    TileInfo ("GTH_COMMON", (0, 0, 6, 0), &["gtclk"]),
-
-    TileInfo ("PCIE_BOT", (4, 0, 9, 10), &["hardip"]),
-    TileInfo ("PCIE_TOP", (4, 0, 4, 0), &["hardip"]),
-    TileInfo ("PCIE_BOT_LEFT", (4, 0, 9, 10), &["hardip"]),
-    TileInfo ("PCIE_TOP_LEFT", (4, 0, 4, 0), &["hardip"]),
-    TileInfo ("PCIE3_BOT_RIGHT", (9, 0, 7, 7), &["hardip"]),
-    TileInfo ("PCIE3_RIGHT", (9, 0, 9, 11), &["hardip"]),
-    TileInfo ("PCIE3_TOP_RIGHT", (9, 0, 7, 7), &["hardip"]),
-
-    TileInfo ("PSS0", (17, 13, 9, 10), &["hardip"]),
-    TileInfo ("PSS1", (17, 13, 9, 11), &["hardip"]),
-    TileInfo ("PSS2", (17, 13, 9, 11), &["hardip"]),
-    TileInfo ("PSS3", (17, 13, 10, 10), &["hardip"]),
-    TileInfo ("PSS4", (17, 13, 9, 10), &["hardip"]),
-
-    TileInfo ("GTZ_INT", SINGLE, &[]),
-    TileInfo ("GTZ_BRAM", SINGLE, &[]),
-    TileInfo ("GTZ_CLB_INTF_IOB", SINGLE, &[]),
-    TileInfo ("GTZ_CMT", SINGLE, &[]),
-    TileInfo ("GTZ_IOI", SINGLE, &[]),
-    TileInfo ("GTZ_VBRK_INTF", SINGLE, &[]),
-    TileInfo ("GTZ_DSP", SINGLE, &[]),
-    TileInfo ("GTZ_BRAM", SINGLE, &[]),
-    TileInfo ("GTZ_INT_L", SINGLE, &["int-buf"]),
-    TileInfo ("GTZ_INT_R", SINGLE, &["int-buf"]),
-    TileInfo ("GTZ_INT_LB", SINGLE, &["int-buf"]),
-    TileInfo ("GTZ_INT_RB", SINGLE, &["int-buf"]),
-    TileInfo ("GTZ_CLK", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("GTZ_CLK_B", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("GTZ_BOT", SINGLE, &["gty"]),
-    TileInfo ("GTZ_TOP", SINGLE, &["gty"]),
-
-    TileInfo ("CLK_FEED", SINGLE, &["clk-spine"]),
-    TileInfo ("CLK_BUFG_REBUF", (0, 0, 1, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_BALI_REBUF", (0, 0, 7, 8), &["clk-spine-buf"]),
-    TileInfo ("CLK_BALI_REBUF_GTZ_TOP", (0, 0, 7, 8), &["clk-spine-buf"]),
-    TileInfo ("CLK_BALI_REBUF_GTZ_BOT", (0, 0, 7, 8), &["clk-spine-buf"]),
-    TileInfo ("CLK_BUFG_BOT_R", (0, 0, 3, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_BUFG_TOP_R", (0, 0, 3, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_HROW_BOT_R", (0, 0, 4, 4), &["clk-row-buf"]),
-    TileInfo ("CLK_HROW_TOP_R", (0, 0, 4, 4), &["clk-row-buf"]),
-    TileInfo ("CLK_PMV", (0, 0, 6, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_MTBF2", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CLK_PMV2", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CLK_PMV2_SVT", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CLK_PMVIOB", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("CLK_TERM", SINGLE, &["clk-spine"]),
-    TileInfo ("BRKH_CLK", SINGLE, &["clk-spine"]),
-
-    TileInfo ("VFRAME", SINGLE, &["cfg"]),
-
-    TileInfo ("VBRK", SINGLE, &["vbrk"]),
-    TileInfo ("VBRK_EXT", SINGLE, &["vbrk"]),
-
-    TileInfo ("PCIE_NULL", SINGLE, &["hardip"]),
-    TileInfo ("NULL", SINGLE, &[]),
-];
-
-const ULTRASCALE_TILES: &'static [TileInfo] = &[
-    TileInfo ("INT", SINGLE, &["int"]),
-    TileInfo ("INT_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_TERM_B", SINGLE, &["bterm"]),
-    TileInfo ("INT_TERM_T", SINGLE, &["tterm"]),
-    TileInfo ("RCLK_INT_L", SINGLE, &["clk-last-buf"]),
-    TileInfo ("RCLK_INT_R", SINGLE, &["clk-last-buf"]),
-
-    TileInfo ("INT_INTERFACE_L", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_L_RBRK", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_L_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_L_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_INTF_L_L", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_INTF_L_R", SINGLE, &["clk-row"]),
-
-    TileInfo ("INT_INTERFACE_R", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_R_RBRK", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_R_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_R_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_INTF_R_L", SINGLE, &["clk-row"]),
-
-    TileInfo ("INT_INTERFACE_PCIE_L", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_PCIE_L_RBRK", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_PCIE_L_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_PCIE_L_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_INTF_PCIE_L_R", SINGLE, &["clk-row"]),
-
-    // despite name, used for everything in IP columns
-    TileInfo ("INT_INTERFACE_PCIE_R", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTERFACE_PCIE_R_RBRK", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_PCIE_R_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_PCIE_R_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_INTF_PCIE_R_L", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_INTF_PCIE3_LEFT_L_FT", SINGLE, &["clk-row"]), // this one used in left IP col
-
-    TileInfo ("INT_INTERFACE_GT_R", SINGLE, &["int-if"]),    // TODO: lterm?
-    TileInfo ("INT_INTERFACE_GT_R_RBRK", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_GT_R_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTERFACE_GT_R_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_INTF_GT_R_R", SINGLE, &["clk-row"]),
-
-    TileInfo ("INT_INT_INTERFACE_GT_LEFT_FT", SINGLE, &["int-if"]),    // TODO: lterm?
-    TileInfo ("INT_INT_INTERFACE_GT_LEFT_RBRK_FT", SINGLE, &[]),
-    TileInfo ("INT_INT_INTERFACE_GT_LEFT_TERM_T_FT", SINGLE, &[]),
-    TileInfo ("INT_INT_INTERFACE_GT_LEFT_TERM_B_FT", SINGLE, &[]),
-    TileInfo ("RCLK_RCLK_INTF_GTH_LEFT_L_FT", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_INTF_GT_LEFT_L_FT", SINGLE, &["clk-row"]), // this one used for GTY
-
-    TileInfo ("CLEL_L", SINGLE, &["clb"]),
-    TileInfo ("CLEL_L_RBRK", SINGLE, &[]),
-    TileInfo ("CLEL_L_TERM_B", SINGLE, &[]),
-    TileInfo ("CLEL_L_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_CLEL_L", SINGLE, &["clk-row-buf"]),
-    TileInfo ("RCLK_CLEL_R", SINGLE, &["clk-row-buf"]),
-    TileInfo ("CLEL_R", SINGLE, &["clb"]),
-    TileInfo ("CLEL_R_RBRK", SINGLE, &[]),
-    TileInfo ("CLEL_R_TERM_B", SINGLE, &[]),
-    TileInfo ("CLEL_R_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_CLEL_R_L", SINGLE, &["clk-row-buf"]),
-    TileInfo ("RCLK_CLEL_R_R", SINGLE, &["clk-row-buf"]),
-
-    // left [_R is to the right of cfg col]
-    TileInfo ("CLE_M", SINGLE, &["clbm"]),
-    TileInfo ("CLE_M_R", SINGLE, &["clbm"]),
-    TileInfo ("CLE_M_RBRK", SINGLE, &[]),
-    TileInfo ("CLE_M_TERM_B", SINGLE, &[]),
-    TileInfo ("CLE_M_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_CLEM_CLKBUF_L", SINGLE, &["clk-spine-buf"]), // HROUTE line breaker
-    TileInfo ("RCLK_CLE_M_L", SINGLE, &["clk-row-buf"]),
-    TileInfo ("RCLK_CLE_M_R", SINGLE, &["clk-row-buf"]),
-    // shared column
-    TileInfo ("LAGUNA_TILE", SINGLE, &["int-buf"]),
-    TileInfo ("LAGUNA_TERM_B", SINGLE, &[]),
-    TileInfo ("LAGUNA_TERM_T", SINGLE, &[]),
-    TileInfo ("LAG_LAGUNA2CLEM_RBRK_FT", SINGLE, &[]),
-    TileInfo ("LAG_CLEM2LAGUNA_RBRK_FT", SINGLE, &[]),
-    TileInfo ("RCLK_RCLK_LAGUNA_L_FT", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_LAGUNA_R_FT", SINGLE, &["clk-row"]),
-
-    // left
-    TileInfo ("BRAM", (0, 0, 4, 0), &["bram"]),
-    TileInfo ("BRAM_RBRK", SINGLE, &[]),
-    TileInfo ("BRAM_TERM_B", SINGLE, &[]),
-    TileInfo ("BRAM_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_BRAM_L", SINGLE, &["clk-row-buf"]), // two bufs
-    TileInfo ("RCLK_BRAM_R", SINGLE, &["clk-row-buf"]), // two bufs
-    TileInfo ("RCLK_RCLK_BRAM_L_AUXCLMP_FT", SINGLE, &["clk-row-buf"]), // two bufs
-    TileInfo ("RCLK_RCLK_BRAM_L_BRAMCLMP_FT", SINGLE, &["clk-row-buf"]), // two bufs
-
-    // right
-    TileInfo ("DSP", (0, 0, 4, 0), &["dsp"]),
-    TileInfo ("DSP_RBRK", SINGLE, &[]),
-    TileInfo ("DSP_TERM_B", SINGLE, &[]),
-    TileInfo ("DSP_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_DSP_CLKBUF_L", SINGLE, &["clk-spine-buf"]), // HROUTE and HDISTR line breaker
-    TileInfo ("RCLK_DSP_L", SINGLE, &["clk-row-buf"]), // two bufs
-
-    // to the left of leftmost HPIO; does nothing
-    TileInfo ("HPIO_TERM_L", SINGLE, &[]),
-    TileInfo ("HPIO_TERM_L_RBRK", SINGLE, &[]),
-    TileInfo ("HPIO_L_TERM_B", SINGLE, &[]),
-    TileInfo ("HPIO_L_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_TERM_L", SINGLE, &["clk-row"]),
-
-    // version when paired with left GTH
-    TileInfo ("HPIO_K3_TERM_L_FT", SINGLE, &[]),
-    TileInfo ("HPIO_K3_TERM_L_RBRK_FT", SINGLE, &[]),
-    TileInfo ("HPIO_K3_TERM_L_TERM_B_FT", SINGLE, &[]),
-    TileInfo ("RCLK_RCLK_K3_TERM_L_FT", SINGLE, &["clk-row"]),
-
-    // version for vu440
-    TileInfo ("HPIO_VH_TERM_L_FT", SINGLE, &[]),
-    TileInfo ("HPIO_VH_TERM_L_RBRK_FT", SINGLE, &[]),
-    TileInfo ("HPIO_VH_TERM_L_TERM_B_FT", SINGLE, &[]),
-    TileInfo ("HPIO_VH_TERM_L_TERM_T_FT", SINGLE, &[]),
-    TileInfo ("RCLK_RCLK_VH_TERM_L_FT", SINGLE, &["clk-row"]),
-
-    // to the left of IOB; does nothing
-    TileInfo ("INT_IBRK_FSR2IO", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_B_OUTER_FT", SINGLE, &[]), // for leftmost IO
-    TileInfo ("INT_IBRK_TERM_T_OUTER_FT", SINGLE, &[]), // for leftmost IO
-    TileInfo ("INT_IBRK_TERM_B_FSR2IO", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_T_FSR2IO", SINGLE, &[]),
-    TileInfo ("INT_IBRK_RBRK_FSR2IO", SINGLE, &[]),
-    TileInfo ("CLK_IBRK_FSR2IO", SINGLE, &["clk-row"]),
-
-    TileInfo ("HPIO_L", (44, 0, 29, 0), &["iob-spec"]),
-    TileInfo ("RCLK_HPIO_L", (44, 0, 0, 0), &["clk-row"]),
-    TileInfo ("HPIO_RBRK_L", (44, 0, 0, 0), &[]),
-    TileInfo ("HPIO_HPIO_TERM_B_LEFT_OUTER_FT", (44, 0, 0, 0), &[]), // for leftmost; does not apply if shared with GT
-    TileInfo ("HPIO_HPIO_TERM_T_LEFT_OUTER_FT", (44, 0, 0, 0), &[]), // for leftmost
-    TileInfo ("HPIO_TERM_T_L", (44, 0, 0, 0), &[]),
-    TileInfo ("HPIO_TERM_B_L", (44, 0, 0, 0), &[]),
-
-    TileInfo ("HRIO_L", (44, 0, 29, 0), &["iob"]),
-    TileInfo ("RCLK_HRIO_L", (44, 0, 0, 0), &["clk-row"]),
-    TileInfo ("HRIO_RBRK_L", (44, 0, 0, 0), &[]),
-    TileInfo ("HRIO_TERM_B_L", (44, 0, 0, 0), &[]),
-
-    TileInfo ("HPHRIO_RBRK_L", (44, 0, 0, 0), &[]),
-
-    // to the right of HPIO; reuses CFRM_L term and RBRK; nothing
-    TileInfo ("HPIO_CBRK_IO", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_CBRK_IO", SINGLE, &["clk-row"]),
-
-    // further right of HPIO; still nothing
-    TileInfo ("INT_IBRK_IO2XIPHY", (0, 0, 29, 0), &[]),
-    TileInfo ("INT_IBRK_TERM_B_IO2XIPHY", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_T_IO2XIPHY", SINGLE, &[]),
-    TileInfo ("INT_IBRK_RBRK_IO2XIPHY", SINGLE, &[]),
-    TileInfo ("RCLK_IBRK_IO2XIPHY", SINGLE, &["clk-row"]),
-
-    TileInfo ("XIPHY_L", (0, 0, 60, 0), &["io", "pll", "clk-global-buf"]), // .. and 24 row buffers!
-    TileInfo ("XIPHY_L_TERM_B", SINGLE, &[]),
-    TileInfo ("XIPHY_L_TERM_T", SINGLE, &[]),
-    TileInfo ("XIPHY_L_RBRK", SINGLE, &[]),
-
-    // right of XIPHY; replaced by INT_TERM_L if leftmost
-    TileInfo ("INT_IBRK_XIPHY2INT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_B_XIPHY2INT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_T_XIPHY2INT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_RBRK_XIPHY2INT", SINGLE, &[]),
-    TileInfo ("RCLK_IBRK_XIPHY2INT", SINGLE, &["clk-row"]),
-
-    // just funny left int interface to XIPHY I think
-    TileInfo ("INT_INT_INTERFACE_XIPHY_FT", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_RCLK_INTF_XIPHY_LEFT_L_FT", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INT_INTERFACE_XIPHY_RBRK_FT", SINGLE, &[]),
-    TileInfo ("INT_INT_INTERFACE_XIPHY_TERM_B_FT", SINGLE, &[]),
-    TileInfo ("INT_INT_INTERFACE_XIPHY_TERM_T_FT", SINGLE, &[]),
-
-    TileInfo ("INT_TERM_L_IO", SINGLE, &["lterm"]),
-    TileInfo ("INT_TERM_L_IO_RBRK", SINGLE, &[]),
-    TileInfo ("INT_TERM_L_IO_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_TERM_IO_TERM_T", SINGLE, &[]),
-    TileInfo ("RCLK_INT_TERM_L", SINGLE, &["clk-row"]),
-
-    // randomly between areas to the left of cfg
-    TileInfo ("CFRM_CBRK_L", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_CBRK_L", SINGLE, &["clk-row"]),
-    TileInfo ("CFRM_L_RBRK", SINGLE, &[]),
-    TileInfo ("CFRM_L_TERM_B", SINGLE, &[]),
-    TileInfo ("CFRM_L_TERM_T", SINGLE, &[]),
-
-    // randomly between areas to the right of cfg
-    TileInfo ("CFRM_CBRK_R", (0, 0, 29, 0), &[]),
-    TileInfo ("RCLK_CBRK_R", SINGLE, &["clk-row"]),
-    TileInfo ("CFRM_R_RBRK", SINGLE, &[]),
-    TileInfo ("CFRM_R_TERM_B", SINGLE, &[]),
-    TileInfo ("CFRM_R_TERM_T", SINGLE, &[]),
-
-    // randomly between areas to the left of cfg, but to right of mid io; when happens,
-    // always with cbrk
-    TileInfo ("FSR_GAP", SINGLE, &[]),
-    TileInfo ("CFG_GAP_CFGBOT", (0, 0, 29, 0), &["vbrk"]), // these two happen in CFG row
-    TileInfo ("CFG_GAP_CFGTOP", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_GAP4", SINGLE, &["clk-row"]),
-    TileInfo ("FSR_GAP_TERM_B", SINGLE, &[]),
-    TileInfo ("FSR_GAP_TERM_T", SINGLE, &[]),
-    TileInfo ("FSR_GAP_RBRK", SINGLE, &[]),
-
-    // the config column: IBRK_R_L, meat, CFRM, IBRK_L_R
-    TileInfo ("INT_IBRK_R_L", SINGLE, &[]),
-    TileInfo ("INT_IBRK_RBRK_R_L", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_B_R_L", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_T_R_L", SINGLE, &[]),
-    TileInfo ("RCLK_IBRK_R_L", SINGLE, &["clk-row"]),
-
-    TileInfo ("FSR_GAP50_MINICBRK_FT", (0, 0, 29, 0), &[]), // padding to left for thin IPs when fat IPs present [aka ILKN]
-    TileInfo ("AMS", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("CFGIO_IOB", (0, 0, 29, 0), &["cfg"]),
-    TileInfo ("CFG_CFG", (0, 0, 60, 0), &["cfg"]),
-    TileInfo ("PCIE", (0, 0, 60, 0), &["hardip"]),
-    TileInfo ("CMAC_CMAC_FT", (0, 0, 60, 0), &["hardip"]),
-    TileInfo ("ILMAC_ILMAC_FT", (1, 0, 60, 0), &["hardip"]),
-    TileInfo ("CFGIO_CFG_RBRK", SINGLE, &[]),
-    TileInfo ("CFG_PCIE_AMS_RBRK", SINGLE, &[]),                    // AMS PCIE
-    TileInfo ("ILMAC_ILMAC_AMS_RBRK_FT", (1, 0, 0, 0), &[]),        // AMS ILMAC
-    TileInfo ("ILMAC_PCIE3_ILMAC_RBRK_FT", (1, 0, 0, 0), &[]),      // ILMAC PCIE
-    TileInfo ("ILMAC_ILMAC_PCIE3_RBRK_FT", (1, 0, 0, 0), &[]),      // PCIE ILMAC
-    TileInfo ("ILMAC_CMAC_ILMAC_LEFT_RBRK_FT", (1, 0, 0, 0), &[]),  // ILMAC CMAC [left]
-    TileInfo ("ILMAC_ILMAC_CMAC_LEFT_RBRK_FT", (1, 0, 0, 0), &[]),  // CMAC ILMAC [left]
-    TileInfo ("CMAC_CMAC_LEFT_RBRK_FT", SINGLE, &[]),               // CMAC CMAC [left]
-    TileInfo ("CFG_CFG_PCIE_RBRK", SINGLE, &[]),                    // PCIE CFG
-    TileInfo ("CFG_PCIE_RBRK", SINGLE, &[]),                        // PCIE PCIE
-    TileInfo ("CMAC_PCIE3_CMAC_RBRK_FT", SINGLE, &[]),              // CMAC PCIE
-    TileInfo ("CMAC_CMAC_AMS_RBRK_FT", SINGLE, &[]),                // AMS CMAC
-    TileInfo ("FSR_GAP50_MINICBRK_RBRK_FT", SINGLE, &[]),
-    TileInfo ("RCLK_AMS_CFGIO", SINGLE, &["clk-row-buf"]),
-    TileInfo ("RCLK_RCLK_GAP50_MINICBRK_FT", SINGLE, &["clk-row-buf"]),
-    TileInfo ("CFG_CTR_TERM_B", SINGLE, &[]),
-    TileInfo ("CFG_CTR_TERM_T", SINGLE, &[]),
-    TileInfo ("CMAC_CMAC_LEFT_TERM_T_FT", SINGLE, &[]),
-    TileInfo ("ILMAC_ILMAC_LEFT_TERM_B_FT", (1, 0, 0, 0), &[]),
-    TileInfo ("FSR_GAP50_MINICBRK_TERM_T_FT", SINGLE, &[]),
-    TileInfo ("FSR_GAP50_MINICBRK_TERM_B_FT", SINGLE, &[]),
-
-    TileInfo ("CFRM_B", (0, 0, 60, 0), &[]), // below cfg
-    TileInfo ("CFRM_T", (0, 0, 60, 0), &[]), // above cfg
-    TileInfo ("CFRM_CFG", (0, 0, 60, 0), &[]),
-    TileInfo ("CFRM_AMS_CFGIO", (0, 0, 60, 0), &[]),
-    TileInfo ("CFRM_RBRK_B", SINGLE, &[]),
-    TileInfo ("CFRM_RBRK_CFGIO", SINGLE, &[]),
-    TileInfo ("CFRM_RBRK_PCIE", SINGLE, &[]),
-    TileInfo ("CFRM_TERM_B", SINGLE, &[]),
-    TileInfo ("CFRM_TERM_T", SINGLE, &[]),
-
-    TileInfo ("INT_IBRK_L_R", SINGLE, &[]),
-    TileInfo ("INT_IBRK_RBRK_L_R", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_B_L_R", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_T_L_R", SINGLE, &[]),
-    TileInfo ("RCLK_IBRK_L_R", SINGLE, &["clk-row"]),
-
-    // left IP column is like right one, except it uses this right IBRK
-    TileInfo ("INT_IBRK_LEFT_L_FT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_RBRK_LEFT_L_FT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_B_LEFT_L_FT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_TERM_T_LEFT_L_FT", SINGLE, &[]),
-    TileInfo ("RCLK_RCLK_IBRK_LEFT_L_FT", SINGLE, &["clk-row"]),
-
-    TileInfo ("GTH_R", (50, 0, 60, 0), &["gth"]),
-    TileInfo ("GTH_R_RBRK", (50, 0, 0, 0), &[]),
-    TileInfo ("GTH_R_TERM_B", (50, 0, 0, 0), &[]),
-    TileInfo ("GTH_R_TERM_T", (50, 0, 0, 0), &[]),
-
-    TileInfo ("GTH_QUAD_LEFT_FT", (50, 0, 60, 0), &["gth"]),
-    TileInfo ("GTH_QUAD_LEFT_RBRK_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTH_QUAD_LEFT_TERM_T_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTH_QUAD_HPIO_RBRK_FT", (51, 0, 0, 0), &[]),
-    // pad with K3 below if mixed with HPIO
-
-    TileInfo ("GTY_QUAD_LEFT_FT", (50, 0, 60, 0), &["gty"]),
-    TileInfo ("GTY_QUAD_LEFT_RBRK_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTY_QUAD_LEFT_TERM_T_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTY_QUAD_LEFT_TERM_B_FT", (50, 0, 0, 0), &[]),
-];
-
-const ULTRASCALEPLUS_TILES: &'static [TileInfo] = &[
-    TileInfo ("INT", SINGLE, &["int"]),
-    TileInfo ("RCLK_INT_L", SINGLE, &["clk-last-buf"]),
-    TileInfo ("RCLK_INT_R", SINGLE, &["clk-last-buf"]),
-    TileInfo ("INT_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_TERM_B", SINGLE, &["bterm"]),
-    TileInfo ("INT_TERM_P", SINGLE, &["bterm"]),
-    TileInfo ("INT_INT_TERM_H_FT", SINGLE, &["bterm"]),
-    TileInfo ("INT_TERM_T", SINGLE, &["tterm"]),
-
-    TileInfo ("INT_INTF_L", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTF_L_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_L_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_L_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_TERM_P", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_R", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTF_R_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_R_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_R_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_INTF_RIGHT_TERM_P", SINGLE, &[]),
-    TileInfo ("INT_INTF_RIGHT_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_L_PCIE4", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_INTF_L_IBRK_PCIE4_L", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_INTF_L_IBRK_PCIE4_R", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_INTF_LEFT_IBRK_FE_L_FT", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INTF_L_PCIE4_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_L_PCIE4_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_L_PCIE4_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_IBRK_PCIE4_TERM_P_FT", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_IBRK_PCIE4_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_R_PCIE4", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_INTF_R_IBRK_L", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INTF_R_PCIE4_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_R_PCIE4_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_R_PCIE4_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_INTF_RIGHT_IBRK_PCIE4_TERM_P_FT", SINGLE, &[]),
-    TileInfo ("INT_INTF_RIGHT_IBRK_PCIE4_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_LEFT_TERM_IO_FT", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_RCLK_INTF_LEFT_TERM_IO_FT", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INTF_LEFT_TERM_IO_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_LEFT_TERM_IO_TERM_B_FT", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_TERM_IO_TERM_T_FT", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_TERM_IO_TERM_P_FT", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_L_CMT", SINGLE, &["int-if"]),
-    TileInfo ("INT_INTF_L_IO", SINGLE, &["int-if"]), // just... mixed in one col
-    TileInfo ("RCLK_INTF_L_IBRK_IO_L", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INTF_L_IO_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_L_IO_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_L_IO_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_IBRK_IO_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_RIGHT_TERM_IO", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_INTF_RIGHT_TERM_IO", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INTF_RIGHT_IO_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_RIGHT_IO_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_RIGHT_TERM_IO_TERM_T", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_LEFT_TERM_PSS", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_INTF_LEFT_TERM_ALTO", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_INTF_LEFT_TERM_DA7", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_INTF_LEFT_TERM_DA6_FT", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_INTF_LEFT_TERM_DA8_FT", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_INTF_LEFT_TERM_DC12_FT", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INTF_LEFT_TERM_PSS_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CLE_M_INTF_TERM_PSS_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_LEFT_TERM_PSS_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_TERM_PSS_TERM_T", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_L_TERM_GT", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_INTF_L_TERM_GT", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INTF_L_TERM_GT_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_L_TERM_G_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_L_TERM_G_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_TERM_GT_TERM_P", SINGLE, &[]),
-    TileInfo ("INT_INTF_LEFT_TERM_GT_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_R_TERM_GT", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_INTF_R_TERM_GT", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_INTF_RIGHT_TERM_GT_IO", SINGLE, &["clk-row"]),
-    TileInfo ("INT_INTF_R_TERM_GT_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_R_TERM_GT_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_INTF_R_TERM_GT_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_INTF_RIGHT_TERM_GT_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("INT_INTF_RIGHT_TERM_GT_IO_RBRK", SINGLE, &["clk-brk"]),
-
-    TileInfo ("CLEL_L", SINGLE, &["clb"]),
-    TileInfo ("RCLK_CLEL_L_L", SINGLE, &["clk-row-buf"]),   // one buf
-    TileInfo ("RCLK_CLEL_L_R", SINGLE, &["clk-row-buf"]),   // one buf
-    TileInfo ("CLEL_L_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CLEL_L_TERM_B", SINGLE, &[]),
-    TileInfo ("CLEL_L_TERM_T", SINGLE, &[]),
-    TileInfo ("CLE_CLE_L_LEFT_TERM_P_FT", SINGLE, &[]),
-    TileInfo ("CLE_CLE_L_LEFT_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("CLEL_R", SINGLE, &["clb"]),
-    TileInfo ("RCLK_CLEL_R_L", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_CLEL_R_R", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_CLEL_R_DCG10_R", SINGLE, &["clk-row"]), // vu23p special
-    TileInfo ("CLE_L_R_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CLEL_R_TERM_B", SINGLE, &[]),
-    TileInfo ("CLEL_R_TERM_T", SINGLE, &[]),
-    TileInfo ("CLE_L_RIGHT_TERM_P", SINGLE, &[]),
-    TileInfo ("CLE_CLE_L_RIGHT_TERM_H_FT", SINGLE, &[]),
-
-    // left
-    TileInfo ("CLEM", SINGLE, &["clbm"]),
-    TileInfo ("CLEM_R", SINGLE, &["clbm"]), // still left, just to right of cfg
-    TileInfo ("RCLK_CLEM_CLKBUF_L", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("RCLK_CLEM_L", SINGLE, &["clk-row-buf"]), // one buf
-    TileInfo ("RCLK_CLEM_DMC_L", SINGLE, &["clk-row-buf"]),
-    TileInfo ("RCLK_CLEM_R", SINGLE, &["clk-row-buf"]), // one buf
-    TileInfo ("CLEM_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CLEM_TERM_B", SINGLE, &[]),
-    TileInfo ("CLEM_TERM_T", SINGLE, &[]),
-    TileInfo ("CLE_M_TERM_P", SINGLE, &[]),
-    TileInfo ("CLE_CLE_M_TERM_H_FT", SINGLE, &[]),
-    // extras for LAGUNA
-    TileInfo ("LAG_LAG", (1, 0, 0, 0), &["int-buf"]),
-    TileInfo ("RCLK_LAG_L", (1, 0, 0, 0), &["clk-row-buf"]),
-    TileInfo ("RCLK_LAG_R", (1, 0, 0, 0), &["clk-row-buf"]),
-    TileInfo ("RCLK_LAG_DMC_L", (1, 0, 0, 0), &["clk-row-buf"]), // vu19p
-    TileInfo ("LAG_LAG2C_RBRK", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("LAG_C2L_RBRK", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("LAG_LAG_TERM_T", (1, 0, 0, 0), &["int-buf"]),
-    TileInfo ("LAG_LAG_TERM_B", (1, 0, 0, 0), &["int-buf"]),
-    TileInfo ("CLE_CLE_M_DECAP_FT", SINGLE, &[]),
-    TileInfo ("CLE_CLE_M_DECAP_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("CLE_CLE_M_DECAP_TERM_H_FT", SINGLE, &[]),
-    TileInfo ("RCLK_RCLK_CLE_M_DECAP_L_FT", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_CLE_M_DECAP_R_FT", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_RCLK_CLE_M_DECAP_DMC_L_FT", SINGLE, &["clk-row"]),
-
-    // wtf
-    TileInfo ("FSR_DMC_TARGET_FT", (0, 0, 310, 0), &[]),
-
-    // left
-    TileInfo ("BRAM", (0, 0, 4, 0), &["bram"]),
-    TileInfo ("RCLK_BRAM_INTF_L", (1, 0, 0, 0), &["clk-row-buf"]), // quad bufs
-    TileInfo ("RCLK_BRAM_INTF_TD_L", (1, 0, 0, 0), &["clk-row-buf"]), // quad bufs
-    TileInfo ("RCLK_BRAM_INTF_TD_R", (1, 0, 0, 0), &["clk-row-buf"]), // quad bufs
-    TileInfo ("BRAM_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("BRAM_TERM_B", SINGLE, &[]),
-    TileInfo ("BRAM_TERM_P", SINGLE, &[]),
-    TileInfo ("BRAM_TERM_T", SINGLE, &[]),
-    TileInfo ("BRAM_BRAM_TERM_H_FT", SINGLE, &[]),
-
-    // right
-    TileInfo ("DSP", (0, 0, 4, 0), &["dsp"]),
-    TileInfo ("RCLK_DSP_INTF_L", (1, 0, 0, 0), &["clk-row-buf"]), // two bufs
-    TileInfo ("RCLK_DSP_INTF_R", (1, 0, 0, 0), &["clk-row-buf"]), // two bufs
-    TileInfo ("RCLK_RCLK_DSP_INTF_DC12_L_FT", (1, 0, 0, 0), &["clk-row-buf"]), // used for xczu*dr
-    TileInfo ("RCLK_RCLK_DSP_INTF_DC12_R_FT", (1, 0, 0, 0), &["clk-row-buf"]), // used for xczu*dr
-    TileInfo ("RCLK_DSP_INTF_CLKBUF_L", (1, 0, 0, 0), &["clk-spine-buf"]),
-    TileInfo ("DSP_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("DSP_TERM_B", SINGLE, &[]),
-    TileInfo ("DSP_TERM_P", SINGLE, &[]),
-    TileInfo ("DSP_TERM_T", SINGLE, &[]),
-    TileInfo ("DSP_JTAG_TERM_P", SINGLE, &[]),
-    // shared col
-    TileInfo ("BLI_BLI_FT", (0, 0, 14, 0), &["int-buf"]),
-    TileInfo ("BLI_BLI_TERM_H_FT", (2, 0, 0, 0), &["int-buf"]),
-
-    TileInfo ("URAM_URAM_FT", (0, 0, 14, 0), &["uram"]),
-    TileInfo ("URAM_URAM_DELAY_FT", (0, 0, 14, 0), &["uram"]),
-    TileInfo ("RCLK_RCLK_URAM_INTF_L_FT", (2, 0, 0, 0), &["clk-row-buf"]), // quad bufs
-    TileInfo ("URAM_URAM_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("URAM_URAM_TERM_B_FT", SINGLE, &[]),
-    TileInfo ("URAM_URAM_TERM_T_FT", SINGLE, &[]),
-    TileInfo ("URAM_URAM_TERM_P_FT", SINGLE, &[]),
-    TileInfo ("URAM_URAM_TERM_H_FT", SINGLE, &[]),
-
-
-    // the spec columns
-    TileInfo ("HDIO_BOT_RIGHT", (1, 0, 29, 0), &["io"]),
-    TileInfo ("HDIO_TOP_RIGHT", (1, 0, 29, 0), &["io"]),
-    TileInfo ("HDIO_HDIO_FILL_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("RCLK_HDIO", (1, 0, 0, 0), &["clk-global-buf"]),
-    TileInfo ("HDIO_RIGHT_RBRK", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HDIO_HDIO_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("HDIO_HDIO_RIGHT_TERM_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HDIO_RIGHT_TERM_B", (1, 0, 0, 0), &[]),
-    TileInfo ("HDIO_RIGHT_TERM_T", (1, 0, 0, 0), &[]),
-    TileInfo ("HDIO_RIGHT_CFG_TERM_T", (1, 0, 0, 0), &[]),
-    TileInfo ("HDIO_HDIO_RIGHT_TERM_P_FT", (1, 0, 0, 0), &[]),
-    TileInfo ("HDIO_HDIO_FILL_TERM_T_FT", SINGLE, &[]),
-
-    TileInfo ("CFG_CONFIG", (1, 0, 60, 0), &["cfg"]),
-    TileInfo ("CFGIO_IOB20", (1, 0, 29, 0), &["cfg"]),
-    TileInfo ("AMS", (1, 0, 29, 0), &["sysmon"]),
-    TileInfo ("RCLK_AMS_CFGIO", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("CFGIO_CONFIG_RBRK", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("CFG_CONFIG_PCIE4", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("AMS_TERM_T", (1, 0, 0, 0), &[]),
-    TileInfo ("CMAC_AMS_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("PCIE4_AMS_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("ILKN_AMS_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("CFG_CONFIG_CMAC_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("CFG_CONFIG_HDIO_RBRK", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HDIO_AMS_RBRK", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HDIO_PCIE4_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HDIO_ILKN_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("ILKN_HDIO_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("CMAC_HDIO_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("PCIE4_HDIO_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-
-    TileInfo ("CFG_CFG_FILL_FT", (0, 0, 60, 0), &[]),
-    TileInfo ("CFG_CFG_FILL_OLY_FT", (0, 0, 60, 0), &[]),
-    TileInfo ("CFG_CFG_FILL_OLY_DK_FT", (0, 0, 60, 0), &[]),
-    TileInfo ("CFGIO_FILL_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("AMS_AMS_FILL_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("RCLK_RCLK_CTR_FILL_FT", SINGLE, &["clk-row"]),
-    TileInfo ("CFGIO_CFG_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("CFG_CFG_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("CFG_CFG_CMAC_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("CMAC_AMS_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("PCIE4_AMS_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("ILKN_AMS_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("HDIO_HDIO_FILL_PCIE4_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("HDIO_HDIO_FILL_AMS_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("ILKN_HDIO_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("CMAC_HDIO_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("PCIE4_HDIO_FILL_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("AMS_AMS_FILL_TERM_T_FT", SINGLE, &[]),
-
-    TileInfo ("PCIE4_PCIE4_FT", (2, 0, 60, 0), &["hardip"]),
-    TileInfo ("PCIE4C_PCIE4C_FT", (2, 0, 60, 0), &["hardip"]),
-    TileInfo ("ILKN_ILKN_FT", (2, 0, 60, 0), &["hardip"]),
-    TileInfo ("CMAC", (2, 0, 60, 0), &["hardip"]),
-    TileInfo ("CMAC_RBRK", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("PCIE4_CMAC_RBRK_FT", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("PCIE4_PCIE4_RBRK_FT", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("CMAC_ILKN_RBRK_FT", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("CMAC_PCIE4_RBRK_FT", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("ILKN_PCIE4_RBRK_FT", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("ILKN_CMAC_RBRK_FT", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("ILKN_ILKN_RBRK_FT", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("PCIE4_TERM_B_FT", (2, 0, 0, 0), &[]),
-    TileInfo ("PCIE4_TERM_T_FT", (2, 0, 0, 0), &[]),
-    TileInfo ("PCIE4_CFG_TERM_T_FT", (2, 0, 0, 0), &[]),
-    TileInfo ("PCIE4_TERM_H_FT", (2, 0, 0, 0), &[]),
-    TileInfo ("CMAC_TERM_B", (2, 0, 0, 0), &[]),
-    TileInfo ("CMAC_CMAC_TERM_T_FT", (2, 0, 0, 0), &[]),
-    TileInfo ("CMAC_CMAC_TERM_P_FT", (2, 0, 0, 0), &[]),
-    TileInfo ("CMAC_CMAC_CFG_TERM_T_FT", (2, 0, 0, 0), &[]),
-    TileInfo ("ILKN_ILKN_CFG_TERM_T_FT", (2, 0, 0, 0), &[]),
-
-
-    // the cframe
-    TileInfo ("CFRM_B", (0, 0, 60, 0), &["cfg"]),
-    TileInfo ("CFRM_CONFIG", (0, 0, 60, 0), &["cfg"]),
-    TileInfo ("CFRM_AMS_CFGIO", (0, 0, 60, 0), &["cfg"]),
-    TileInfo ("CFRM_T", (0, 0, 60, 0), &["cfg"]),
-    TileInfo ("CFRM_T_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CFRM_B_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CFRM_TERM_B", SINGLE, &[]),
-    TileInfo ("CFRM_TERM_T", SINGLE, &[]),
-    TileInfo ("CFRM_CFRAME_DMAH_TERM_T_FT", SINGLE, &[]),
-    TileInfo ("CFRM_CFRAME_TERM_H_FT", SINGLE, &[]),
-
-    // FE column — uses left interconnect only; sequence is IBRK_FSR2FE, FE, INT_INTF_PCIE4_L
-    TileInfo ("FE_FE_FT", (3, 0, 60, 0), &["hardip"]),
-    TileInfo ("FE_FE_RBRK_FT", (3, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("FE_FE_TERM_B_FT", (3, 0, 0, 0), &[]),
-    TileInfo ("FE_FE_TERM_T_FT", (3, 0, 0, 0), &[]),
-
-    TileInfo ("INT_IBRK_FSR2FE_FT", SINGLE, &[]),
-    TileInfo ("RCLK_RCLK_IBRK_FSR2FE_FT", SINGLE, &["clk-row"]),
-    TileInfo ("INT_IBRK_FSR2FE_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_IBRK_FSR2FE_TERM_B_FT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_FSR2FE_TERM_T_FT", SINGLE, &[]),
-
-    // HPIO left [outer]: HPIO_TERM, IBRK_FSR2IO, IOB, special CBRK, IBRK, XIPHY, CMT, INT_LEFT_IO
-    // HPIO left [inner]: ..., IOB, special CBRK, ..., XIPHY, CMT, INT_CMT_L
-    TileInfo ("HPIO_TERM_L_FT", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_AUX_IO_TERM_L_BOT_FT", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_AUX_IO_TERM_L_TOP_FT", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_CFG_TERM_L_BOT_FT", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_CFG_TERM_L_TOP_FT", (1, 0, 29, 0), &[]),
-    TileInfo ("RCLK_RCLK_HPIO_TERM_L_FT", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("HPIO_TERM_L_RBRK_FT", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HPIO_TERM_L_TERM_T_FT", (1, 0, 0, 0), &[]),
-    TileInfo ("HPIO_TERM_L_TERM_B_FT", (1, 0, 0, 0), &[]),
-    // special above PSS
-    TileInfo ("HPIO_TERM_L_DA6_FT", (3, 0, 29, 0), &[]),
-    TileInfo ("HPIO_AUX_IO_TERM_L_BOT_DA6_FT", (3, 0, 29, 0), &[]),
-    TileInfo ("HPIO_AUX_IO_TERM_L_TOP_DA6_FT", (3, 0, 29, 0), &[]),
-    TileInfo ("RCLK_RCLK_HPIO_TERM_L_DA6_FT", (3, 0, 0, 0), &["clk-row"]),
-    TileInfo ("HPIO_TERM_L_DA6_RBRK_FT", (3, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HPIO_TERM_L_DA6_TERM_T_FT", (3, 0, 0, 0), &[]),
-    TileInfo ("HPIO_TERM_L_DA6_TERM_P_FT", (3, 0, 0, 0), &[]),
-
-    TileInfo ("INT_IBRK_FSR2IO", SINGLE, &[]),
-    TileInfo ("RCLK_IBRK_FSR2IO", SINGLE, &["clk-row"]),
-    TileInfo ("INT_IBRK_FSR2IO_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_IBRK_FSR2IO_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_IBRK_FSR2IO_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_IBRK_FSR2IO_TERM_B_L_FT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_FSR2IO_TERM_T_L_FT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_FSR2IO_TERM_P_FT", SINGLE, &[]),
-    TileInfo ("INT_IBRK_FSR2IO_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("HPIO_L", (42, 0, 29, 0), &["iob-spec"]),
-    TileInfo ("RCLK_HPIO_L", (42, 0, 0, 0), &["clk-row"]),
-    TileInfo ("HPIO_L_RBRK", (42, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HPIO_L_TERM_B", (42, 0, 0, 0), &[]),
-    TileInfo ("HPIO_L_TERM_T", (42, 0, 0, 0), &[]),
-    TileInfo ("HPIO_HPIO_LEFT_TERM_B_L_FT", (42, 0, 0, 0), &[]),
-    TileInfo ("HPIO_HPIO_LEFT_TERM_T_L_FT", (42, 0, 0, 0), &[]),
-    TileInfo ("HPIO_HPIO_LEFT_TERM_P_FT", (42, 0, 0, 0), &[]),
-    TileInfo ("HPIO_HPIO_TERM_H_FT", (47, 0, 0, 0), &[]), // extra long
-
-    TileInfo ("INT_IBRK_IO", (0, 0, 29, 0), &[]),
-    TileInfo ("RCLK_IBRK_IO", SINGLE, &["clk-row"]),
-    TileInfo ("INT_IBRK_IO_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_IBRK_IO_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_IBRK_IO_TERM_T", SINGLE, &[]),
-    TileInfo ("INT_IBRK_IO_TERM_P_FT", SINGLE, &[]),
-
-    TileInfo ("XIPHY_BYTE_L", (0, 0, 14, 0), &["io-spec"]),
-    TileInfo ("RCLK_RCLK_XIPHY_INNER_FT", SINGLE, &["clk-row"]),
-    TileInfo ("XIPHY_L_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("XIPHY_L_TERM_B", SINGLE, &[]),
-    TileInfo ("XIPHY_L_TERM_T", SINGLE, &[]),
-    TileInfo ("XIPHY_XIPHY_LEFT_TERM_P_FT", SINGLE, &[]),
-
-    TileInfo ("CMT_L", (0, 0, 60, 0), &["pll"]),
-    TileInfo ("CMT_LEFT_H", (0, 0, 60, 0), &["hardip", "pll"]), // special for HBM
-    TileInfo ("CMT_L_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CMT_L_TERM_B", SINGLE, &[]),
-    TileInfo ("CMT_L_TERM_T", SINGLE, &[]),
-    TileInfo ("CMT_CMT_LEFT_TERM_P_FT", SINGLE, &[]),
-
-    // right HPIO: CMT, XIPHY, IBRK_IO, special CBRK, IOB, IBRK_FSR2IO, HPIO_TERM
-    TileInfo ("CMT_RIGHT", (0, 0, 60, 0), &["pll"]),
-    TileInfo ("CMT_RIGHT_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CMT_RIGHT_TERM_B", SINGLE, &[]),
-    TileInfo ("CMR_RIGHT_TERM_T", SINGLE, &[]),
-
-    TileInfo ("XIPHY_BYTE_RIGHT", (0, 0, 14, 0), &["io-spec"]),
-    TileInfo ("RCLK_XIPHY_OUTER_RIGHT", SINGLE, &["clk-row"]),
-    TileInfo ("XIPHY_RIGHT_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("XIPHY_RIGHT_TERM_R_FT", SINGLE, &[]),
-    TileInfo ("XIPHY_RIGHT_TERM_T_FT", SINGLE, &[]),
-
-    TileInfo ("INT_IBRK_IO_R", (0, 0, 29, 0), &[]),
-    TileInfo ("RCLK_IBRK_IO_R", SINGLE, &["clk-row"]),
-    TileInfo ("INT_IBRK_IO_R_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_IBRK_R_TERM_B", SINGLE, &[]),
-    TileInfo ("INT_IBRK_IO_R_TERM_T", SINGLE, &[]),
-
-    TileInfo ("HPIO_RIGHT", (42, 0, 29, 0), &["iob-spec"]),
-    TileInfo ("RCLK_HPIO_R", (42, 0, 0, 0), &["clk-row"]),
-    TileInfo ("HPIO_RIGHT_RBRK", (42, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HPIO_RIGHT_TERM_B", (42, 0, 0, 0), &[]),
-    TileInfo ("HPIO_RIGHT_TERM_T", (42, 0, 0, 0), &[]),
-
-    TileInfo ("INT_IBRK_FSR2IO_R", SINGLE, &[]),
-    TileInfo ("RCLK_IBRK_FSR2IO_R", SINGLE, &["clk-row"]),
-    TileInfo ("INT_IBRK_FSR2IO_RBRK_R", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_IBRK_FSR2IO_TERM_B_R", SINGLE, &[]),
-    TileInfo ("INT_IBRK_FSRIO_TERM_T_R", SINGLE, &[]),
-
-    TileInfo ("HPIO_AMS_BOT_TERM_R", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_AMS_TOP_TERM_R", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_CFG_BOT_TERM_R", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_CFG_TOP_TERM_R", (1, 0, 29, 0), &[]),
-    TileInfo ("RCLK_HPIO_TERM_R", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("HPIO_TERM_R_RBRK", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HPIO_TERM_R_TERM_B", (1, 0, 0, 0), &[]),
-    TileInfo ("HPIO_TERM_R_TERM_T", (1, 0, 0, 0), &[]),
-    // alt for combo with GTH
-    TileInfo ("HPIO_AMS_BOT_TERM_R_GTH", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_AMS_TOP_TERM_R_GTH", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_CFG_BOT_TERM_R_GTH", (1, 0, 29, 0), &[]),
-    TileInfo ("HPIO_CFG_TOP_TERM_R_GTH", (1, 0, 29, 0), &[]),
-    TileInfo ("RCLK_HPIO_TERM_R_GTH", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("HPIO_TERM_R_GTH_RBRK", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HPIO_TERM_R_GTH_TERM_B", (1, 0, 0, 0), &[]),
-
-    // right
-    TileInfo ("GTH_QUAD_RIGHT", (50, 0, 60, 0), &["gth"]),
-    TileInfo ("GTH_QUAD_RIGHT_RBRK", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("GTH_QUAD_RIGHT_TERM_B_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTH_QUAD_RIGHT_TERM_T", (50, 0, 0, 0), &[]),
-    TileInfo ("GTH_QUAD_HPIO_RIGHT_RBRK", (50, 0, 0, 0), &["clk-brk"]),
-
-    // right
-    TileInfo ("GTY_R", (50, 0, 60, 0), &["gty"]),
-    TileInfo ("GTY_R_RBRK", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("GTY_R_TERM_B", (50, 0, 0, 0), &[]),
-    TileInfo ("GTY_R_TERM_T", (50, 0, 0, 0), &[]),
-    TileInfo ("GTY_QUAD_RIGHT_TERM_H_FT", (50, 0, 0, 0), &[]),
-
-    // right
-    TileInfo ("GTM_DUAL_RIGHT_FT", (50, 0, 60, 0), &["gtm"]),
-    TileInfo ("GTM_DUAL_RIGHT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("GTM_DUAL_RIGHT_TERM_B_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTM_DUAL_RIGHT_TERM_T_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTM_GTY_RIGHT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-
-    // right
-    TileInfo ("HSADC_HSADC_RIGHT_FT", (50, 0, 60, 0), &["hsadc"]),
-    TileInfo ("HSDAC_HSDAC_RIGHT_FT", (50, 0, 60, 0), &["hsdac"]),
-    TileInfo ("HSADC_HSADC_RIGHT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HSDAC_HSDAC_RIGHT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HSDAC_HSDAC_HSADC_RIGHT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("HSADC_HSADC_RIGHT_TERM_B_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("HSDAC_HSDAC_RIGHT_TERM_T_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("HSADC_HSC_FILL_RIGHT_FT", (0, 0, 496, 0), &[]),
-
-    // right
-    TileInfo ("RFADC_RFADC_RIGHT_FT", (50, 0, 60, 0), &["hsadc"]),
-    TileInfo ("RFDAC_RFDAC_RIGHT_FT", (50, 0, 60, 0), &["hsdac"]),
-    TileInfo ("RFADC_RFADC_RIGHT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("RFDAC_RFDAC_RIGHT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("RFDAC_RFDAC_RFADC_RIGHT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("RFADC_RFADC_RIGHT_TERM_B_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("RFDAC_RFDAC_RIGHT_TERM_T_FT", (50, 0, 0, 0), &[]),
-
-    // left
-    TileInfo ("GTH_QUAD_LEFT", (50, 0, 60, 0), &["gth"]),
-    TileInfo ("GTH_QUAD_LEFT_RBRK", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("GTH_QUAD_LEFT_DA7_TERM_P", (50, 0, 0, 0), &[]),
-    TileInfo ("GTH_QUAD_LEFT_TERM_T", (50, 0, 0, 0), &[]),
-
-    TileInfo ("GTH_DA7_TERM_L", (0, 0, 60, 0), &[]),
-    TileInfo ("GTH_DA7_TERM_L_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("GTH_DA7_TERM_L_TERM_P", SINGLE, &[]),
-    TileInfo ("GTH_DA7_TERM_L_TERM_T", SINGLE, &[]),
-
-    // left
-    TileInfo ("GTY_L", (50, 0, 60, 0), &["gty"]),
-    TileInfo ("GTY_L_RBRK", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("GTY_L_TERM_B", (50, 0, 0, 0), &[]),
-    TileInfo ("GTY_L_TERM_T", (50, 0, 0, 0), &[]),
-    TileInfo ("GTY_QUAD_LEFT_TERM_P_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTY_QUAD_LEFT_TERM_H_FT", (50, 0, 0, 0), &[]),
-
-    TileInfo ("GTY_ALTO_TERM_L_FT", (39, 0, 60, 0), &[]),
-    TileInfo ("GTY_ALTO_TERM_L_RBRK_FT", (39, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("GTY_ALTO_TERM_L_TERM_T_FT", (39, 0, 0, 0), &[]),
-    TileInfo ("GTY_ALTO_TERM_L_TERM_P_FT", (39, 0, 0, 0), &[]),
-
-    // left
-    TileInfo ("GTM_DUAL_LEFT_FT", (50, 0, 60, 0), &["gtm"]),
-    TileInfo ("GTM_DUAL_LEFT_RBRK_FT", (50, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("GTM_DUAL_LEFT_TERM_B_FT", (50, 0, 0, 0), &[]),
-    TileInfo ("GTM_DUAL_LEFT_TERM_T_FT", (50, 0, 0, 0), &[]),
-
-    // left
-    TileInfo ("PSS_ALTO", (158, 0, 184, 0), &["hardip"]),
-    TileInfo ("PSS_ALTO_TERM_B", (158, 0, 0, 0), &[]),
-    TileInfo ("PSS_ALTO_TERM_T", (158, 0, 0, 0), &[]),
-    TileInfo ("PSS_GTY_FILL_ALTO_FT", (0, 0, 184, 0), &[]),
-    TileInfo ("PSS_GTY_FILL_ALTO_TERM_B_FT", SINGLE, &[]),
-    TileInfo ("PSS_GTH_FILL_DA7", (0, 0, 184, 0), &[]),
-    TileInfo ("PSS_DA6_FILL_ALTO_FT", (0, 0, 184, 0), &[]),
-    TileInfo ("PSS_GTH_FILL_DA7_TERM_B", SINGLE, &[]),
-
-    TileInfo ("VCU_VCU_FT", (158, 0, 62, 0), &["hardip"]),
-    TileInfo ("PSS_ALTO_VCU_DA6_RBRK_FT", (158, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("VCU_DA6_FILL_FT", (0, 0, 62, 0), &[]),
-    TileInfo ("PSS_VCU_DA6_FILL_ALTO_RBRK_FT", SINGLE, &["clk-brk"]),
-    TileInfo ("INT_INTF_LEFT_TERM_PSS_TERM_V_FT", SINGLE, &[]),
-    TileInfo ("VCU_VCU_TERM_V_FT", (87, 0, 0, 0), &[]),
-    TileInfo ("VCU_VCU_DA4_TERM_V_FT", (200, 0, 0, 0), &[]),
-    TileInfo ("VCU_VCU_DA6_TERM_V_FT", (193, 0, 0, 0), &[]),
-
-    // bottom
-    TileInfo ("HBM_DMAH_FT", (784, 0, 36, 0), &["hardip"]),
-
-
-    // between areas, left of cfg
-    TileInfo ("CFRM_CBRK_L", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_CBRK_M12BUF_L", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("CFRM_CBRK_L_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CFRM_CBRK_L_TERM_B", SINGLE, &[]),
-    TileInfo ("CFRM_CBRK_L_TERM_P", SINGLE, &[]),
-    TileInfo ("CFRM_CBRK_L_TERM_T", SINGLE, &[]),
-    TileInfo ("CFRM_CBRK_L_TERM_H_FT", SINGLE, &[]),
-
-    // paired with those, is on the side towards cfg [!]
-    TileInfo ("AMS_M12BUF_BOT_L", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_TOP_L", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_AMS_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_AMS_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_AMS_ALTO_AUX_IO_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_AMS_ALTO_AUX_IO_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_SYSMON_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_SYSMON_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("CFG_M12BUF", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_TERM_L", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_TOP_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_TERM_BOT_L", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_TERM_TOP_L", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_ALTO_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_ALTO_TOP_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_ALTO_OLY_DK_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_ALTO_OLY_DK_TOP_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_ALTO_TERM_OLY_DK_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_ALTO_TERM_OLY_DK_TOP_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_RBRK_L", SINGLE, &["clk-brk"]),
-    TileInfo ("CFG_M12BUF_TERM_B_L", SINGLE, &[]),
-    TileInfo ("CFG_M12BUF_TERM_P", SINGLE, &[]),
-    TileInfo ("CFG_M12BUF_TERM_T_L", SINGLE, &[]),
-    TileInfo ("CFG_M12BUF_TERM_H_L_FT", SINGLE, &[]),
-
-    // specials for IO
-    TileInfo ("CFRM_CBRK_IO_L", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_RCLK_CBRK_IO_M12BUF_L_FT", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("AMS_M12BUF_IO_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_IO_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_IO_AMS_ALTO_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_IO_AMS_ALTO_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_IO_AMS_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_IO_AMS_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("CFG_M12BUF_IO_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_IO_CFG_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_IO_CFG_TOP_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_IO_CFG_ALTO_OLY_DK_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_IO_CFG_ALTO_OLY_DK_TOP_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_IO_CFG_ALTO_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_IO_CFG_ALTO_TOP_L_FT", (0, 0, 29, 0), &[]),
-
-    // specials for left of cfg col
-    TileInfo ("CFRM_CBRK_CTR_RIGHT_L_FT", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_RCLK_CBRK_CTR_RIGHT_M12BUF_L_FT", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("AMS_M12BUF_CTR_RIGHT_AMS_ALTO_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_CTR_RIGHT_AMS_ALTO_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_CTR_RIGHT_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_CTR_RIGHT_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("CFG_M12BUF_CTR_RIGHT_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CTR_RIGHT_CFG_OLY_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CTR_RIGHT_CFG_OLY_TOP_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CTR_RIGHT_CFG_ALTO_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CTR_RIGHT_CFG_ALTO_TOP_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CTR_RIGHT_CFG_OLY_DK_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CTR_RIGHT_CFG_OLY_DK_TOP_L_FT", (0, 0, 29, 0), &[]),
-
-    // specials for right of hardip col
-    TileInfo ("CFRM_CBRK_CTR_LEFT_L_FT", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_RCLK_CBRK_CTR_LEFT_M12BUF_L_FT", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("AMS_M12BUF_CTR_LEFT_AMS_ALTO_BOT_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_CTR_LEFT_AMS_ALTO_TOP_L_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("CFG_M12BUF_CTR_LEFT_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CTR_LEFT_CFG_ALTO_TERM_BOT_L_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CTR_LEFT_CFG_ALTO_TERM_TOP_L_FT", (0, 0, 29, 0), &[]),
-
-
-    // between areas, right of cfg
-    TileInfo ("CFRM_CBRK_R", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_CBRK_M12BUF_R", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("CFRM_CBRK_R_RBRK", SINGLE, &["clk-brk"]),
-    TileInfo ("CFRM_CBRK_R_TERM_B", SINGLE, &[]),
-    TileInfo ("CFRM_CBRK_R_TERM_T", SINGLE, &[]),
-    TileInfo ("CFRM_CBRK_R_TERM_H_FT", SINGLE, &[]),
-
-    TileInfo ("AMS_M12BUF_BOT_R", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_TOP_R", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_BOT_R_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_TOP_R_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_AMS_BOT_R_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_AMS_TOP_R_FT", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("CFG_M12BUF_CFG_BOT_R", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_TOP_R", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_BOT_R_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_CFG_TOP_R_FT", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_RBRK_R", SINGLE, &["clk-brk"]),
-    TileInfo ("CFG_M12BUF_TERM_B_R", SINGLE, &[]),
-    TileInfo ("CFG_M12BUF_TERM_T_R", SINGLE, &[]),
-    TileInfo ("CFG_M12BUF_TERM_H_R_FT", SINGLE, &[]),
-
-    // specials for IO
-    TileInfo ("CFRM_CBRK_IO_R", (0, 0, 29, 0), &["vbrk"]),
-    TileInfo ("RCLK_CBRK_IO_M12BUF_R", (1, 0, 0, 0), &["clk-row"]),
-    TileInfo ("AMS_M12BUF_IO_BOT_R", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("AMS_M12BUF_IO_TOP_R", (0, 0, 29, 0), &["sysmon"]),
-    TileInfo ("CFG_M12BUF_IO_CFG_BOT_R", (0, 0, 29, 0), &[]),
-    TileInfo ("CFG_M12BUF_IO_CFG_TOP_R", (0, 0, 29, 0), &[]),
-];
-
-const VERSAL_TILES: &'static [TileInfo] = &[
-    TileInfo ("INT", SINGLE, &["int"]),
-    TileInfo ("REBUF_INT_T_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INT_B_CORE", SINGLE, &[]),
-    TileInfo ("RCLK_INT_R_FT", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_INT_L_FT", SINGLE, &["clk-row"]),
-    TileInfo ("RBRK_INT_TILE", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_T_INT_TILE", SINGLE, &["tterm"]),
-    TileInfo ("TERM_B_INT_TILE", SINGLE, &["bterm"]),
-    TileInfo ("TERM_P_INT_TILE", SINGLE, &["bterm"]),
-
-    TileInfo ("INTF_ROCF_TR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_ROCF_TL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_ROCF_BR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_ROCF_BL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_LOCF_TR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_LOCF_TL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_LOCF_BR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_LOCF_BL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("REBUF_INTF_T_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_B_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_T_CORE_MY", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_B_CORE_MY", SINGLE, &[]),
-    TileInfo ("RCLK_INTF_L_CORE", SINGLE, &["clk-last-buf"]),
-    TileInfo ("RCLK_INTF_R_CORE", SINGLE, &["clk-last-buf"]),
-    TileInfo ("RBRK_INTF_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("RBRK_INTF_CORE_MY", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_T_INTF_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_INTF_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_P_INTF_CORE", SINGLE, &[]),
-    TileInfo ("TERM_P_INTF_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_B_INTF_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_INTF_CORE_MY", SINGLE, &[]),
-
-    TileInfo ("INTF_HB_LOCF_TR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HB_LOCF_TL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HB_LOCF_BR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HB_LOCF_BL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HB_ROCF_TR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HB_ROCF_TL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HB_ROCF_BR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HB_ROCF_BL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("REBUF_INTF_HB_T_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_HB_B_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_HB_T_CORE_MY", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_HB_B_CORE_MY", SINGLE, &[]),
-    TileInfo ("RBRK_INTF_HB_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("RBRK_INTF_HB_CORE_MY", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_P_INTF_HB_CORE", SINGLE, &[]),
-    TileInfo ("TERM_P_INTF_HB_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_B_INTF_HB_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_INTF_HB_CORE_MY", SINGLE, &[]),
-
-    TileInfo ("INTF_HDIO_LOCF_TR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HDIO_LOCF_TL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HDIO_ROCF_TR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_HDIO_ROCF_TL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("REBUF_INTF_IBRK_OPTDLY_HDIO_T_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_IBRK_OPTDLY_HDIO_T_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_T_INTF_HDIO_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_INTF_HDIO_CORE_MY", SINGLE, &[]),
-
-    TileInfo ("INTF_CFRM_TL_TILE", SINGLE, &["int-if"]), // XXX wtf
-    TileInfo ("INTF_CFRM_BL_TILE", SINGLE, &["int-if"]), // XXX wtf
-    TileInfo ("INTF_PSS_BL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_PSS_TL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("RCLK_INTF_OPT_CORE", SINGLE, &["clk-last-buf"]),
-    TileInfo ("RBRK_INTF_CFRM_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("REBUF_INTF_PSS_OPTDLY_T_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_PSS_OPTDLY_B_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_CFRM_OPTDLY_T_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_CFRM_OPTDLY_B_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_INTF_CFRM_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_INTF_CFRM_CORE", SINGLE, &[]),
-
-    TileInfo ("RBRK_CFRM_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("CFRM_FSR_TILE", (0, 0, 118, 0), &["cfg"]),
-    TileInfo ("CFRM_CPM_TILE", (0, 0, 118, 0), &["cfg"]),
-    TileInfo ("CFRM_PMC_TILE", (0, 0, 118, 0), &["cfg"]),
-    TileInfo ("CFRM_HFSR_TILE", (0, 0, 59, 0), &["cfg"]),
-    TileInfo ("TERM_T_CFRM_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_CFRM_CORE", SINGLE, &[]),
-
-    TileInfo ("INTF_GT_TL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_GT_BL_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_GT_TR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("INTF_GT_BR_TILE", SINGLE, &["int-if"]),
-    TileInfo ("REBUF_INTF_GT_OPTDLY_T_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_GT_OPTDLY_B_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_GT_OPTDLY_T_CORE_MY", SINGLE, &[]),
-    TileInfo ("REBUF_INTF_GT_OPTDLY_B_CORE_MY", SINGLE, &[]),
-    TileInfo ("RCLK_INTF_TERM_LEFT_CORE", (1, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RCLK_INTF_TERM_RIGHT_CORE", (1, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RBRK_INTF_GT_CORE_MY", SINGLE, &["clk-brk"]),
-    TileInfo ("RBRK_INTF_GT_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_T_INTF_GT_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_INTF_GT_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_P_INTF_GT_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_INTF_GT_CORE", SINGLE, &[]),
-
-    TileInfo ("CLE_W_CORE", SINGLE, &["clbm"]),
-    TileInfo ("CLE_E_CORE", SINGLE, &["clbm"]),
-    TileInfo ("CLE_BC_CORE", SINGLE, &["clb"]),
-    TileInfo ("CLE_BC_CORE_MX", SINGLE, &["clb"]),
-    TileInfo ("BLI_CLE_TOP_CORE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("BLI_CLE_TOP_CORE_MY", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("BLI_CLE_BOT_CORE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("BLI_CLE_BOT_CORE_MY", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("REBUF_CLE_T_CORE", (2, 0, 0, 0), &[]),
-    TileInfo ("REBUF_CLE_B_CORE", (2, 0, 0, 0), &[]),
-    TileInfo ("REBUF_CLE_BLI_T_CORE", (2, 0, 0, 0), &[]),
-    TileInfo ("REBUF_CLE_BLI_B_CORE", (2, 0, 0, 0), &[]),
-    TileInfo ("RCLK_CLE_CORE", (2, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RBRK_CLE_CORE", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("TERM_T_BLI_CLE_TILE", (2, 0, 0, 0), &[]),
-    TileInfo ("TERM_P_CLE_CORE", (2, 0, 0, 0), &[]),
-    TileInfo ("TERM_B_BLI_CLE_TILE", (2, 0, 0, 0), &[]),
-
-    TileInfo ("DSP_ROCF_T_TILE", (1, 0, 1, 0), &["dsp"]),
-    TileInfo ("DSP_ROCF_B_TILE", (1, 0, 1, 0), &["dsp"]),
-    TileInfo ("BLI_DSP_ROCF_TL_TILE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("BLI_DSP_ROCF_TR_TILE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("BLI_DSP_ROCF_BL_TILE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("BLI_DSP_ROCF_BR_TILE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("REBUF_DSP_T_CORE", (1, 0, 0, 0), &[]),
-    TileInfo ("REBUF_DSP_B_CORE", (1, 0, 0, 0), &[]),
-    TileInfo ("REBUF_DSP_BLI_T_CORE", (1, 0, 0, 0), &[]),
-    TileInfo ("REBUF_DSP_BLI_B_CORE", (1, 0, 0, 0), &[]),
-    TileInfo ("RCLK_DSP_CORE", (3, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RBRK_DSP_CORE", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("TERM_T_BLI_DSP_TILE", (1, 0, 0, 0), &[]),
-    TileInfo ("TERM_B_BLI_DSP_TILE", (1, 0, 0, 0), &[]),
-
-    TileInfo ("BRAM_LOCF_TR_TILE", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BRAM_LOCF_BR_TILE", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BRAM_ROCF_TL_TILE", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BRAM_ROCF_BL_TILE", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BRAM_ROCF_TR_TILE", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BRAM_ROCF_BR_TILE", (0, 0, 3, 0), &["bram"]),
-    TileInfo ("BLI_BRAM_LOCF_TR_TILE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("BLI_BRAM_ROCF_TR_TILE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("REBUF_BRAM_T_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_BRAM_B_CORE", SINGLE, &[]),
-    TileInfo ("REBUF_BRAM_T_CORE_MY", SINGLE, &[]),
-    TileInfo ("REBUF_BRAM_B_CORE_MY", SINGLE, &[]),
-    TileInfo ("REBUF_BRAM_BLI_T_CORE", SINGLE, &[]),
-    TileInfo ("RCLK_BRAM_CORE", (1, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RCLK_BRAM_CLKBUF_CORE", (1, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RCLK_BRAM_CORE_MY", (1, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RBRK_BRAM_TILE", SINGLE, &["clk-brk"]),
-    TileInfo ("RBRK_BRAM_TILE_MY", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_T_BLI_BRAM_TILE", SINGLE, &[]),
-    TileInfo ("TERM_T_BRAM_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_BRAM_CORE", SINGLE, &[]),
-    TileInfo ("TERM_P_BRAM_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_BRAM_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_B_BRAM_CORE_MY", SINGLE, &[]),
-
-    TileInfo ("URAM_LOCF_TL_TILE", (0, 0, 3, 0), &["uram"]),
-    TileInfo ("URAM_LOCF_BL_TILE", (0, 0, 3, 0), &["uram"]),
-    TileInfo ("URAM_ROCF_TL_TILE", (0, 0, 3, 0), &["uram"]),
-    TileInfo ("URAM_ROCF_BL_TILE", (0, 0, 3, 0), &["uram"]),
-    TileInfo ("URAM_DELAY_LOCF_TL_TILE", (0, 0, 3, 0), &["uram"]),
-    TileInfo ("URAM_DELAY_ROCF_TL_TILE", (0, 0, 3, 0), &["uram"]),
-    TileInfo ("BLI_URAM_LOCF_TL_TILE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("BLI_URAM_ROCF_TL_TILE", (0, 0, 3, 0), &["int-buf"]),
-    TileInfo ("REBUF_URAM_T_CORE_MY", SINGLE, &[]),
-    TileInfo ("REBUF_URAM_B_CORE_MY", SINGLE, &[]),
-    TileInfo ("REBUF_URAM_BLI_T_CORE_MY", SINGLE, &[]),
-    TileInfo ("RCLK_URAM_CORE_MY", (1, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RBRK_URAM_TILE_MY", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_T_BLI_URAM_TILE", SINGLE, &[]),
-    TileInfo ("TERM_T_URAM_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_P_URAM_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_B_URAM_CORE_MY", SINGLE, &[]),
-
-    TileInfo ("RBRK_VNOC_CORE", (1, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("TERM_T_VNOC_CORE", (1, 0, 0, 0), &[]),
-    TileInfo ("TERM_B_VNOC_CORE", (1, 0, 0, 0), &[]),
-    TileInfo ("TERM_T_VNOC_FIRST_CORE", (1, 0, 0, 0), &[]),
-    TileInfo ("TERM_B_VNOC_FIRST_CORE", (1, 0, 0, 0), &[]),
-
-    TileInfo ("CPIPE_TOP_TILE", (0, 0, 58, 0), &[]),
-    TileInfo ("CPIPE_BOT_TILE", (0, 0, 58, 0), &[]),
-    TileInfo ("CPIPE_TERM_RIGHT_TOP_TILE", (0, 0, 58, 0), &[]),
-    TileInfo ("CPIPE_TERM_RIGHT_BOT_TILE", (0, 0, 58, 0), &[]),
-    TileInfo ("CPIPE_TERM_LEFT_TOP_TILE", (0, 0, 58, 0), &[]),
-    TileInfo ("CPIPE_TERM_LEFT_BOT_TILE", (0, 0, 58, 0), &[]),
-    TileInfo ("RCLK_CPIPE_CORE", SINGLE, &["clk-row"]),
-    TileInfo ("RBRK_CPIPE_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("RBRK_CPIPE_CORE_MY", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_T_CPIPE_L_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_CPIPE_M_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_CPIPE_R_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_CPIPE_CORE", SINGLE, &[]),
-    TileInfo ("TERM_P_CPIPE_CORE", SINGLE, &[]),
-
-    TileInfo ("CBRK_TOP_TILE", (0, 0, 58, 0), &["vbrk"]),
-    TileInfo ("CBRK_BOT_TILE", (0, 0, 58, 0), &["vbrk"]),
-    TileInfo ("CBRK_LOCF_TOP_TILE", (0, 0, 58, 0), &["vbrk"]),
-    TileInfo ("CBRK_LOCF_BOT_TILE", (0, 0, 58, 0), &["vbrk"]),
-    TileInfo ("RCLK_CBRK_L_CORE", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_CBRK_R_CORE", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_CBRK_HB_R_CORE", SINGLE, &["clk-row"]),
-    TileInfo ("RCLK_CBRK_HB_L_CORE", SINGLE, &["clk-row"]),
-    TileInfo ("RBRK_CBRK_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_T_CBRK_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_CBRK_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_CBRK_CORE_MY", SINGLE, &[]),
-    TileInfo ("TERM_P_CBRK_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_CBRK_LOVN_CORE", SINGLE, &[]),
-
-    TileInfo ("HDIO_TILE", (0, 0, 58, 0), &["io"]),
-    TileInfo ("RCLK_HDIO_CORE", (2, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("TERM_T_HDIO_L_CORE", SINGLE, &[]),
-    TileInfo ("TERM_T_HDIO_R_CORE", SINGLE, &[]),
-
-    TileInfo ("PCIEB_TOP_TILE", (0, 0, 58, 0), &["hardip"]),
-    TileInfo ("PCIEB_BOT_TILE", (0, 0, 58, 0), &["hardip"]),
-    TileInfo ("MRMAC_TOP_TILE", (0, 0, 58, 0), &["hardip"]),
-    TileInfo ("MRMAC_BOT_TILE", (0, 0, 58, 0), &["hardip"]),
-    TileInfo ("CPM_EXT_TILE", (0, 0, 58, 0), &["hardip"]),
-    TileInfo ("RCLK_HB_CORE", (2, 0, 0, 0), &["clk-last-buf"]),
-    TileInfo ("RBRK_CPM_EXT_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("RBRK_HB_HFSR_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("RBRK_HB_CORE", SINGLE, &["clk-brk"]),
-    TileInfo ("TERM_P_HB_CORE", SINGLE, &[]),
-    TileInfo ("TERM_B_HB_CORE", SINGLE, &[]),
-
-    TileInfo ("GTY_QUAD_SINGLE_MY", (29, 0, 58, 0), &["gty"]),
-    TileInfo ("RBRK_GTY_CORE_MY", (29, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("TERM_P_GTY_CORE", (29, 0, 0, 0), &[]),
-    TileInfo ("TERM_T_GTY_L_CORE", (29, 0, 0, 0), &[]),
-
-    TileInfo ("GTY_QUAD_SINGLE", (21, 0, 58, 0), &["gty"]),
-    TileInfo ("RBRK_GTY_CORE", (21, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("TERM_T_GTY_R_CORE", (21, 0, 0, 0), &[]),
-    TileInfo ("TERM_B_GTY_CORE", (21, 0, 0, 0), &[]),
-
-    TileInfo ("XPIPE_QUAD_SINGLE", (4, 0, 58, 0), &["hardip"]),
-    TileInfo ("RBRK_XPIPE_CORE", (4, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("TERM_P_XPIPE_CORE", (4, 0, 0, 0), &[]),
-    TileInfo ("TERM_T_XPIPE_CORE", (4, 0, 0, 0), &[]),
-
-    TileInfo ("RBRK_GTCLK_CORE_MY", (4, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("TERM_P_GTCLK_CORE", (4, 0, 0, 0), &[]),
-    TileInfo ("TERM_T_GTCLK_L_CORE", (4, 0, 0, 0), &[]),
-    TileInfo ("FSR_OCTAL_GT_TILE_MY", (0, 0, 118, 0), &[]),
-    TileInfo ("FSR_QUAD_GT_TILE_MY", (0, 0, 59, 0), &[]),
-    TileInfo ("FSR_OCTAL_REMAP_TILE_MY", (0, 0, 118, 0), &[]),
-    TileInfo ("FSR_QUAD_REMAP_TILE_MY", (0, 0, 59, 0), &[]),
-
-    TileInfo ("RBRK_GTCLK_CORE", (2, 0, 0, 0), &["clk-brk"]),
-    TileInfo ("TERM_B_GTCLK_CORE", (2, 0, 0, 0), &[]),
-    TileInfo ("TERM_T_GTCLK_R_CORE", (2, 0, 0, 0), &[]),
-    TileInfo ("FSR_OCTAL_GT_TILE", (0, 0, 118, 0), &[]),
-    TileInfo ("FSR_QUAD_GT_TILE", (0, 0, 59, 0), &[]),
-    TileInfo ("FSR_OCTAL_REMAP_TILE", (0, 0, 118, 0), &[]),
-    TileInfo ("FSR_QUAD_REMAP_TILE", (0, 0, 59, 0), &[]),
-
-    TileInfo ("CPM_CORE", (94, 0, 58, 0), &["hardip"]),
-    TileInfo ("PSS_BASE_CORE", (94, 0, 97, 0), &["hardip"]),
-    TileInfo ("TERM_B_PSS_DECAP_CORE", (94, 0, 20, 0), &[]),
-    TileInfo ("TERM_B_PSS_TILE", (8, 0, 0, 0), &[]),
-    TileInfo ("NOC_PNOC_MONO_CORE", (84, 0, 0, 0), &["noc"]),
-
-    // BLI row bottom
-    TileInfo ("BLI_LS_CORE", SINGLE, &["int-buf"]),
-    TileInfo ("BLI_LS_CORE_MY", SINGLE, &["int-buf"]),
-
-    // BLI row top
-    TileInfo ("BLI_LS_CORE_MX", SINGLE, &["int-buf"]),
-    TileInfo ("BLI_LS_CORE_R180", SINGLE, &["int-buf"]),
-
-    // AI grid
-    TileInfo ("AIE_INTF_A_CORE", (10, 0, 10, 0), &["hardip"]),
-    TileInfo ("AIE_INTF_B0_CORE", (10, 0, 10, 0), &["hardip"]),
-    TileInfo ("AIE_INTF_B1_CORE", (10, 0, 10, 0), &["hardip"]),
-    TileInfo ("AIE_INTF_B2_CORE", (10, 0, 10, 0), &["hardip"]),
-    TileInfo ("AIE_INTF_B3_CORE", (10, 0, 10, 0), &["hardip"]),
-    TileInfo ("AIE_INTF_C_CORE", (10, 0, 10, 0), &["hardip"]),
-    TileInfo ("AIE_TILE", (10, 0, 10, 0), &["hardip"]),
-    TileInfo ("AIE_TILE_MY", (10, 0, 10, 0), &["hardip"]),
-
-    // GT right
-    TileInfo ("CLK_GT_AAA_TILE", (0, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_GT_BBA_TILE", (0, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_GT_CCA_TILE", (0, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("AMS_SAT_GT_TOP_TILE", (0, 0, 18, 0), &["sysmon"]),
-    TileInfo ("AMS_SAT_GT_BOT_TILE", (0, 0, 18, 0), &["sysmon"]),
-    TileInfo ("NPI_VNPI_TOP_TILE", (0, 0, 5, 0), &["noc"]),
-    TileInfo ("NPI_VNPI_BOT_TILE", (0, 0, 5, 0), &["noc"]),
-    TileInfo ("MISC_GT_REBUF_TILE", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_GT_CBA_TILE", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_GT_BAA_TILE", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_GT_ACA_TILE", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_GT_COA_TILE", (0, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_VERT_TO_HSR_GT_OCA_TILE", (0, 0, 8, 0), &["clk-spine-buf"]),
-    TileInfo ("RBRK_GT_DECAP_TILE", (0, 0, 8, 0), &[]),
-
-    // GT left
-    TileInfo ("CLK_GT_AAA_TILE_MY", (2, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_GT_BBA_TILE_MY", (2, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_GT_CCA_TILE_MY", (2, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("AMS_SAT_GT_TOP_TILE_MY", (2, 0, 18, 0), &["sysmon"]),
-    TileInfo ("AMS_SAT_GT_BOT_TILE_MY", (2, 0, 18, 0), &["sysmon"]),
-    TileInfo ("NPI_VNPI_TOP_TILE_MY", (2, 0, 5, 0), &["noc"]),
-    TileInfo ("NPI_VNPI_BOT_TILE_MY", (2, 0, 5, 0), &["noc"]),
-    TileInfo ("MISC_GT_REBUF_TILE_MY", (2, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_GT_BAA_TILE_MY", (2, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_GT_ACA_TILE_MY", (2, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_GT_COA_TILE_MY", (2, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("RBRK_GT_DECAP_TILE_MY", (2, 0, 8, 0), &[]),
-
-    // VNOC
-    TileInfo ("CLK_VNOC_AAA_TILE", (0, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_VNOC_BBA_TILE", (0, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_VNOC_CCA_TILE", (0, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("CLK_VNOC_PSS_CCA_TILE", (0, 0, 9, 0), &["clk-global-buf"]),
-    TileInfo ("MISR_TILE", (0, 0, 9, 0), &["hardip"]),
-    TileInfo ("NOC_NSU512_TOP", (1, 0, 8, 0), &["noc"]),
-    TileInfo ("NOC_NMU512_TOP", (1, 0, 10, 0), &["noc"]),
-    TileInfo ("NOC_NPS_VNOC_TOP", (1, 0, 9, 0), &["noc"]),
-    TileInfo ("CLK_REBUF_VERT_VNOC_TOP_COA_TILE", (1, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_VNOC_ACA_TILE", (1, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_VNOC_BAA_TILE", (1, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("CLK_REBUF_VERT_VNOC_CBA_TILE", (1, 0, 9, 0), &["clk-spine-buf"]),
-    TileInfo ("AMS_SAT_VNOC_TILE", (1, 0, 9, 0), &["sysmon"]),
-    TileInfo ("CLK_VERT_TO_HSR_VNOC_OCA_TILE", (1, 0, 8, 0), &["clk-spine-buf"]),
-    TileInfo ("RBRK_VNOC_DECAP_CORE", (1, 0, 8, 0), &[]),
-
-    // XPIO bottom row
-    TileInfo ("XPIO_NIBBLE_SC", SINGLE, &["io-spec"]),
-    TileInfo ("XPIO_NIBBLE_SC_MY", SINGLE, &["io-spec"]),
-    TileInfo ("CLK_PLL_AND_PHY", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("XPIO_MISC_CORE", SINGLE, &["iobank"]),
-
-    // XPIO second row
-    TileInfo ("CMT_XPLL", SINGLE, &["pll-alt"]),
-
-    // XPIO third row
-    TileInfo ("DDRMC_RIU_CORE", SINGLE, &["hardip"]),
-
-    // XPIO fourth row
-    TileInfo ("DDRMC_DMC_CORE", SINGLE, &["hardip"]),
-    TileInfo ("HSM_AMS_SAT_LC", SINGLE, &["sysmon"]),
-
-    // XPIO fifth row
-    TileInfo ("CLK_REBUF_BUFGS_HSR_CORE", SINGLE, &["clk-global-buf"]),
-    TileInfo ("NOC_HNOC_S3_PS_CORE", SINGLE, &["noc"]),
-    TileInfo ("NOC_HNOC_S3_PL_CORE", SINGLE, &["noc"]),
-    TileInfo ("CMT_MMCM", SINGLE, &["pll"]),
-
-    // AI bot row
-    TileInfo ("CLK_REBUF_HSR_ME_TNOC_CORE_MX", SINGLE, &["clk-spine-buf"]),
-    TileInfo ("HSM_AMS_SAT_ME_MX", SINGLE, &["sysmon"]),
-    TileInfo ("NOC_TNOC_ME_CORE_MX", SINGLE, &["noc"]),
-    TileInfo ("NOC_TNOC_S2_CORE_MX", SINGLE, &["noc"]),
-    TileInfo ("NOC_TNOC_ME_CORE_R180", SINGLE, &["noc"]),
-    TileInfo ("NOC_TNOC_NCRB_CORE_MX", SINGLE, &["noc"]),
-
-    // used in XPIO fifth row and GT
-    TileInfo ("CMT_DPLL", SINGLE, &["pll-alt"]),
-    TileInfo ("CMT_DPLL_MY", SINGLE, &["pll-alt"]),
-
-
-    // TODO kill me
-    TileInfo ("NULL", SINGLE, &[]),
-];
-
 #[derive(Debug, StructOpt)]
 #[structopt(name = "rd2html", about = "Make html out of rawdump.")]
 struct Opt {
@@ -2785,55 +61,42 @@ fn main() -> Result<(), io::Error> {
     let opt = Opt::from_args();
     let rd = Part::from_file(opt.src)?;
     let mut grid: Array2<Option<(Coord, usize, usize)>> = Array2::from_shape_fn((rd.width as usize, rd.height as usize), |p| Some((Coord{x: p.0 as u16, y: p.1 as u16}, 1, 1)));
-    let (tile_info, need_null) = match &rd.family[..] {
-        "xc4000e" | "xc4000ex" | "xc4000xla" | "xc4000xv" | "spartanxl" => (XC4K_TILES, true),
-        "virtex" | "virtexe" => (VIRTEX_TILES, false),
-        "virtex2" | "virtex2p" => (VIRTEX2_TILES, false),
-        "spartan3" => (SPARTAN3_TILES, false),
-        "spartan3e" | "spartan3a" | "spartan3adsp" => (SPARTAN3E_TILES, false),
-        "spartan6" => (SPARTAN6_TILES, true),
-        "virtex4" => (VIRTEX4_TILES, false),
-        "virtex5" => (VIRTEX5_TILES, true),
-        "virtex6" => (VIRTEX6_TILES, true),
-        "7series" => (SERIES7_TILES, true),
-        "ultrascale" => (ULTRASCALE_TILES, true),
-        "ultrascaleplus" => (ULTRASCALEPLUS_TILES, true),
-        "versal" => (VERSAL_TILES, true),
-        _ => panic!("unknown family {}", rd.family),
-    };
+    let tile_info = rd.family.info().tiles;
+    if tile_info.is_empty() {
+        return Err(io::Error::other(format!("no tiles in family {}", rd.family)));
+    }
+    let need_null = rd.family.info().null_tiles;
     let tile_info_d = tile_info.iter().map(|t| (t.0, t)).collect::<HashMap<_, _>>();
     for (crd, tile) in rd.tiles.iter() {
         match tile_info_d.get(&tile.kind[..]) {
-            None => (),
-            Some(t) => {
-                if t.1 != SINGLE {
-                    let (er, el, eu, ed) = t.1;
-                    for dx in -el..er+1 {
-                        for dy in -ed..eu+1 {
-                            let nc = ((crd.x as isize + dx) as usize, (crd.y as isize + dy) as usize);
-                            let ncrd = Coord {x: nc.0 as u16, y: nc.1 as u16};
-                            if dx == -el && dy == eu {
-                                grid[nc] = Some((*crd, (el+er+1) as usize, (ed+eu+1) as usize));
+            Some(t) if t.1 != SINGLE => {
+                let (er, el, eu, ed) = t.1;
+                for dx in -el..er+1 {
+                    for dy in -ed..eu+1 {
+                        let nc = ((crd.x as isize + dx) as usize, (crd.y as isize + dy) as usize);
+                        let ncrd = Coord {x: nc.0 as u16, y: nc.1 as u16};
+                        if dx == -el && dy == eu {
+                            grid[nc] = Some((*crd, (el+er+1) as usize, (ed+eu+1) as usize));
+                        } else {
+                            grid[nc] = None;
+                        }
+                        if ncrd != *crd {
+                            let ntile = &rd.tiles[&ncrd];
+                            let ntk = &rd.tile_kinds[&ntile.kind];
+                            if need_null {
+                                if ntile.kind != "NULL" && ntile.kind != "PCIE_NULL" {
+                                    panic!("Tile {} expanded onto {} which is not NULL", tile.name, ntile.name);
+                                }
                             } else {
-                                grid[nc] = None;
-                            }
-                            if ncrd != *crd {
-                                let ntile = &rd.tiles[&ncrd];
-                                let ntk = &rd.tile_kinds[&ntile.kind];
-                                if need_null {
-                                    if ntile.kind != "NULL" && ntile.kind != "PCIE_NULL" {
-                                        panic!("Tile {} expanded onto {} which is not NULL", tile.name, ntile.name);
-                                    }
-                                } else {
-                                    if ntk.wires.len() != 0 {
-                                        panic!("Tile {} expanded onto {} which is not empty", tile.name, ntile.name);
-                                    }
+                                if !ntk.wires.is_empty() {
+                                    panic!("Tile {} expanded onto {} which is not empty", tile.name, ntile.name);
                                 }
                             }
                         }
                     }
                 }
             },
+            _ => (),
         }
     }
     let mut ofile = File::create(opt.dst)?;
@@ -2863,7 +126,7 @@ fn main() -> Result<(), io::Error> {
                 Some(c) => cs.push(c),
             }
         }
-        if cs.len() == 0 {
+        if cs.is_empty() {
         } else if cs.len() == 1 {
             ofile.write_all(format!("td.{} {{ background: rgb({}, {}, {}); }}\n", tname, cs[0].0, cs[0].1, cs[0].2).as_bytes())?;
         } else if cs.len() == 2 {
@@ -2874,7 +137,7 @@ fn main() -> Result<(), io::Error> {
             panic!("too colorful {}", tname);
         }
     }
-    ofile.write_all(format!("</style></head><body><table><tr>\n").as_bytes())?;
+    ofile.write_all(b"</style></head><body><table><tr>\n")?;
     for y in (0..grid.dim().1).rev() {
         for x in 0..grid.dim().0 {
             if let Some((crd, cs, rs)) = grid[(x, y)] {
@@ -2906,15 +169,15 @@ fn main() -> Result<(), io::Error> {
                         ofile.write_all(format!("<br/>{}&nbsp;{}&nbsp;[MISSING]", tks.kind, slot).as_bytes())?;
                     }
                 }
-                ofile.write_all(format!("</div></td>\n").as_bytes())?;
+                ofile.write_all(b"</div></td>\n")?;
             }
         }
-        ofile.write_all(format!("<td class=\"_rpad\"></td>\n").as_bytes())?;
-        ofile.write_all(format!("</tr><tr>\n").as_bytes())?;
+        ofile.write_all(b"<td class=\"_rpad\"></td>\n")?;
+        ofile.write_all(b"</tr><tr>\n")?;
     }
     for _ in 0..grid.dim().0 {
-        ofile.write_all(format!("<td class=\"_bpad\"></td>\n").as_bytes())?;
+        ofile.write_all(b"<td class=\"_bpad\"></td>\n")?;
     }
-    ofile.write_all(format!("</tr></table></body></html>\n").as_bytes())?;
+    ofile.write_all(b"</tr></table></body></html>\n")?;
     Ok(())
 }
@@ -0,0 +1,18 @@
+use std::io;
+use structopt::StructOpt;
+use prjcombine::xilinx::rawdump::Part;
+use prjcombine::xilinx::interchange::write_device_resources;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "rd2interchange", about = "Write rawdump out as FPGA Interchange device resources.")]
+struct Opt {
+    src: String,
+    dst: String,
+}
+
+fn main() -> Result<(), io::Error> {
+    let opt = Opt::from_args();
+    let rd = Part::from_file(&opt.src)?;
+    write_device_resources(&rd, &opt.dst)?;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::PathBuf;
+use structopt::StructOpt;
+use prjcombine::xilinx::rawdump::Part;
+use prjcombine::xilinx::prjxray::{tilegrid, tileconn};
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "rd2prjxray", about = "Write Project X-Ray style tilegrid.json and tileconn.json from rawdump.")]
+struct Opt {
+    src: String,
+    #[structopt(parse(from_os_str))]
+    dst: PathBuf,
+}
+
+fn main() -> Result<(), io::Error> {
+    let opt = Opt::from_args();
+    let rd = Part::from_file(&opt.src)?;
+    fs::create_dir_all(&opt.dst)?;
+    let f = BufWriter::new(File::create(opt.dst.join("tilegrid.json"))?);
+    serde_json::to_writer_pretty(f, &tilegrid(&rd))?;
+    let f = BufWriter::new(File::create(opt.dst.join("tileconn.json"))?);
+    serde_json::to_writer_pretty(f, &tileconn(&rd))?;
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use std::io;
+use structopt::StructOpt;
+use prjcombine::xilinx::rawdump::Part;
+use prjcombine::xilinx::sqlite::write_sqlite;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "rd2sqlite", about = "Write rawdump into a SQLite database.")]
+struct Opt {
+    src: String,
+    dst: String,
+}
+
+fn main() -> Result<(), io::Error> {
+    let opt = Opt::from_args();
+    let rd = Part::from_file(&opt.src)?;
+    write_sqlite(&rd, &opt.dst)?;
+    Ok(())
+}
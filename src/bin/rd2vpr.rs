@@ -0,0 +1,27 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use structopt::StructOpt;
+use prjcombine::xilinx::rawdump::Part;
+use prjcombine::xilinx::vpr::{write_arch, write_rr_graph};
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "rd2vpr", about = "Write VPR architecture and routing resource graph from rawdump.")]
+struct Opt {
+    src: String,
+    #[structopt(parse(from_os_str))]
+    dst: PathBuf,
+}
+
+fn main() -> Result<(), io::Error> {
+    let opt = Opt::from_args();
+    let rd = Part::from_file(&opt.src)?;
+    fs::create_dir_all(&opt.dst)?;
+    let mut f = BufWriter::new(File::create(opt.dst.join("arch.xml"))?);
+    write_arch(&rd, &mut f)?;
+    f.flush()?;
+    let mut f = BufWriter::new(File::create(opt.dst.join("rr_graph.xml"))?);
+    write_rr_graph(&rd, &mut f)?;
+    f.flush()?;
+    Ok(())
+}
@@ -41,11 +41,36 @@ fn main() -> Result<(), io::Error> {
             }
         }
     }
+    if let Some(versal) = &rd.versal {
+        for site in versal.sites.iter().sorted_by_key(|site| &site.name) {
+            let tile = &rd.tiles[&site.tile];
+            match site.aie_pos {
+                Some((c, r)) => println!("VERSAL {:?} {} {} AIE {},{}", site.kind, site.name, tile.name, c, r),
+                None => println!("VERSAL {:?} {} {}", site.kind, site.name, tile.name),
+            }
+        }
+        if let Some(array) = versal.aie_array() {
+            println!("AIE ARRAY {},{} {} columns", array.origin.0, array.origin.1, array.columns);
+            for (i, row) in array.rows.iter().enumerate() {
+                println!("AIE ROW {} {:?}", i, row);
+            }
+            for (c, r) in array.holes.iter() {
+                println!("AIE HOLE {},{}", c, r);
+            }
+        }
+        for link in versal.noc_links.iter().sorted_by_key(|l| (&versal.sites[l.from_site].name, &l.from_pin, &versal.sites[l.to_site].name, &l.to_pin)) {
+            print!("NOCLINK {}/{} {}/{}", versal.sites[link.from_site].name, link.from_pin, versal.sites[link.to_site].name, link.to_pin);
+            for pip in link.pips.iter() {
+                print!(" {}", pip);
+            }
+            println!();
+        }
+    }
     for (name, tt) in rd.tile_kinds.iter().sorted_by_key(|(name, _)| *name) {
         println!("TT {}", name);
         for site in tt.sites.iter().sorted_by_key(|site| site.slot) {
             let slot = match site.slot {
-                TkSiteSlot::Single(sk) => format!("{}", rd.print_slot_kind(sk)),
+                TkSiteSlot::Single(sk) => rd.print_slot_kind(sk).to_string(),
                 TkSiteSlot::Indexed(sk, idx) => format!("{}[{}]", rd.print_slot_kind(sk), idx),
                 TkSiteSlot::Xy(sk, x, y) => format!("{}[{},{}]", rd.print_slot_kind(sk), x, y),
             };
@@ -91,7 +116,7 @@ fn main() -> Result<(), io::Error> {
         let tt = rd.tile_kinds.get(&tile.kind).unwrap();
         for (ts, tks) in tile.sites.iter().zip(tt.sites.iter()).sorted_by_key(|(_, tks)| tks.slot) {
             let slot = match tks.slot {
-                TkSiteSlot::Single(sk) => format!("{}", rd.print_slot_kind(sk)),
+                TkSiteSlot::Single(sk) => rd.print_slot_kind(sk).to_string(),
                 TkSiteSlot::Indexed(sk, idx) => format!("{}[{}]", rd.print_slot_kind(sk), idx),
                 TkSiteSlot::Xy(sk, x, y) => format!("{}[{},{}]", rd.print_slot_kind(sk), x, y),
             };
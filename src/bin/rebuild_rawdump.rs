@@ -0,0 +1,27 @@
+use std::io;
+use structopt::StructOpt;
+use prjcombine::toolcache::ToolCache;
+use prjcombine::xilinx::ise::source::IseSource;
+use prjcombine::xilinx::vivado::source::VivadoSource;
+use prjcombine::xilinx::source::rebuild;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "rebuild_rawdump", about = "Rebuild rawdump file from saved raw tool output.")]
+struct Opt {
+    cache: String,
+    dst: String,
+}
+
+fn main() -> Result<(), io::Error> {
+    let opt = Opt::from_args();
+    let cache = ToolCache::new(&opt.cache);
+    let rd = match rebuild(&IseSource, &cache)? {
+        Some(rd) => rd,
+        None => match rebuild(&VivadoSource { jobs: 1 }, &cache)? {
+            Some(rd) => rd,
+            None => return Err(io::Error::other(format!("{} is not a raw tool output cache", opt.cache))),
+        },
+    };
+    rd.to_file(&opt.dst)?;
+    Ok(())
+}
@@ -7,6 +7,17 @@ pub enum Error {
     IoError(io::Error),
     NixError(nix::Error),
     ParseError(String),
+    /// A parse error pinned to a line of a named input.
+    ParseErrorAt {
+        source: String,
+        line: usize,
+        text: String,
+        msg: String,
+    },
+    ToolError(String),
+    Timeout(String),
+    #[cfg(feature = "sqlite")]
+    SqliteError(rusqlite::Error),
 }
 
 impl From<io::Error> for Error {
@@ -25,20 +36,62 @@ impl From<Error> for io::Error {
     fn from(x: Error) -> io::Error {
         match x {
             Error::IoError(x) => x,
-            Error::NixError(x) => io::Error::new(io::ErrorKind::Other, format!("{:?}", x)),
-            Error::ParseError(s) => io::Error::new(io::ErrorKind::Other, s),
+            Error::NixError(x) => io::Error::other(format!("{:?}", x)),
+            Error::ParseError(s) => io::Error::other(s),
+            Error::ParseErrorAt { source, line, text, msg } => io::Error::other(format!("{}:{}: {} [{}]", source, line, msg, text)),
+            Error::ToolError(s) => io::Error::other(s),
+            Error::Timeout(s) => io::Error::new(io::ErrorKind::TimedOut, format!("{} timed out", s)),
+            #[cfg(feature = "sqlite")]
+            Error::SqliteError(e) => io::Error::other(format!("sqlite: {}", e)),
         }
     }
 }
 
 impl From<num::ParseIntError> for Error {
-    fn from(_: num::ParseIntError) -> Error {
-        Error::ParseError(format!("failed to parse integer"))
+    fn from(x: num::ParseIntError) -> Error {
+        Error::ParseError(format!("failed to parse integer: {}", x))
     }
 }
 
 impl From<num::ParseFloatError> for Error {
-    fn from(_: num::ParseFloatError) -> Error {
-        Error::ParseError(format!("failed to parse float"))
+    fn from(x: num::ParseFloatError) -> Error {
+        Error::ParseError(format!("failed to parse float: {}", x))
+    }
+}
+
+#[cfg(feature = "interchange")]
+impl From<capnp::Error> for Error {
+    fn from(x: capnp::Error) -> Error {
+        Error::ParseError(format!("capnp: {}", x))
+    }
+}
+
+#[cfg(feature = "interchange")]
+impl From<capnp::NotInSchema> for Error {
+    fn from(x: capnp::NotInSchema) -> Error {
+        Error::ParseError(format!("capnp: {}", x))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(x: rusqlite::Error) -> Error {
+        Error::SqliteError(x)
+    }
+}
+
+impl Error {
+    /// Pins a plain parse error to a location; other errors are returned
+    /// unchanged.
+    pub fn at(self, source: &str, line: usize, text: &str) -> Error {
+        match self {
+            Error::ParseError(msg) => Error::ParseErrorAt {
+                source: source.to_string(),
+                line,
+                text: text.to_string(),
+                msg,
+            },
+            e => e,
+        }
     }
 }
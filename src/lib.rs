@@ -2,8 +2,10 @@ pub mod error;
 pub mod xilinx;
 pub mod stringpool;
 pub mod namevec;
+pub mod linereader;
 pub mod toolreader;
 pub mod toolchain;
+pub mod toolcache;
 
 #[global_allocator]
 static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
@@ -0,0 +1,42 @@
+use std::io::{self, BufRead, Lines};
+use crate::error::Error;
+
+/// Line iterator that remembers where it is, so that parse errors can point
+/// at the offending line.
+pub struct LineReader<B: BufRead> {
+    source: String,
+    lines: Lines<B>,
+    line: usize,
+    text: String,
+}
+
+impl<B: BufRead> LineReader<B> {
+    pub fn new(b: B, source: &str) -> Self {
+        LineReader {
+            source: source.to_string(),
+            lines: b.lines(),
+            line: 0,
+            text: String::new(),
+        }
+    }
+
+    /// Pins a parse error to the last line returned.
+    pub fn locate(&self, e: Error) -> Error {
+        e.at(&self.source, self.line, &self.text)
+    }
+}
+
+impl<B: BufRead> Iterator for LineReader<B> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<io::Result<String>> {
+        let res = self.lines.next();
+        if let Some(Ok(l)) = &res {
+            self.line += 1;
+            // Reuses the allocation; this runs for every line of multi-GB
+            // xdlrc files.
+            self.text.clone_from(l);
+        }
+        res
+    }
+}
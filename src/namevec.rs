@@ -38,20 +38,29 @@ impl<T: Named> NameVec<T> {
     pub fn len(&self) -> usize {
         self.vec.len()
     }
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
     pub fn idx(&self, name: &str) -> usize {
         self.index[name]
     }
     pub fn get_idx(&self, name: &str) -> Option<usize> {
         self.index.get(name).copied()
     }
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         self.into_iter()
     }
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         self.into_iter()
     }
 }
 
+impl<T: Named> Default for NameVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'a, T: Named> IntoIterator for &'a NameVec<T> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
@@ -17,6 +17,14 @@ impl StringPool {
         &self.i2s[idx as usize]
     }
 
+    pub fn len(&self) -> usize {
+        self.i2s.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.i2s.is_empty()
+    }
+
     pub fn put(&mut self, s: &str) -> u32 {
         match self.s2i.get(s) {
             None => {
@@ -31,3 +39,9 @@ impl StringPool {
         }
     }
 }
+
+impl Default for StringPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,266 @@
+use std::fs::{File, create_dir_all, read_dir, remove_file, rename};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use crate::toolchain::Toolchain;
+use crate::toolreader::ToolchainReader;
+use crate::error::Error;
+
+/// A directory of raw tool outputs, stored zstd-compressed under a key per
+/// tool invocation, plus whatever metadata is needed to make sense of them.
+#[derive(Debug, Clone)]
+pub struct ToolCache {
+    dir: PathBuf,
+}
+
+// Bump whenever the format of any cached tool output or metadata changes,
+// so that old caches are refused instead of misparsed.
+const VERSION: u32 = 1;
+const VERSION_KEY: &str = "version";
+
+struct TeeReader<R: Read> {
+    inner: R,
+    out: Option<zstd::stream::write::Encoder<'static, File>>,
+    tmp: PathBuf,
+    path: PathBuf,
+}
+
+impl<R: Read> Read for TeeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let res = match self.inner.read(buf) {
+            Ok(res) => res,
+            Err(e) => {
+                // Most likely the tool failed; whatever it wrote must not
+                // be replayed as if it were complete.
+                if e.kind() != io::ErrorKind::Interrupted {
+                    self.discard();
+                }
+                return Err(e);
+            }
+        };
+        if res == 0 {
+            if let Some(out) = self.out.take() {
+                out.finish()?;
+                rename(&self.tmp, &self.path)?;
+            }
+        } else if let Some(out) = &mut self.out {
+            out.write_all(&buf[..res])?;
+        }
+        Ok(res)
+    }
+}
+
+impl<R: Read> TeeReader<R> {
+    fn discard(&mut self) {
+        if self.out.take().is_some() {
+            let _ = remove_file(&self.tmp);
+        }
+    }
+}
+
+impl<R: Read> Drop for TeeReader<R> {
+    fn drop(&mut self) {
+        // The tool output was not read to the end, so the entry would be
+        // truncated; throw it away.
+        self.discard();
+    }
+}
+
+impl ToolCache {
+    pub fn new<P: AsRef<Path>> (dir: P) -> Self {
+        ToolCache {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(key.to_string() + ".zst")
+    }
+
+    fn tmp_path(&self, key: &str) -> PathBuf {
+        self.dir.join(key.to_string() + ".zst.tmp")
+    }
+
+    // Creates the cache directory, marking it with the format version.  An
+    // existing directory without a version is refused rather than adopted,
+    // since there is no telling what format its entries are in.
+    fn prepare(&self) -> Result<(), Error> {
+        create_dir_all(&self.dir)?;
+        if self.has(VERSION_KEY) {
+            return self.check_version();
+        }
+        for entry in read_dir(&self.dir)? {
+            // Leftovers of interrupted stores are harmless.
+            if !entry?.file_name().to_string_lossy().ends_with(".tmp") {
+                return Err(Error::ParseError(format!("cache {} has no format version", self.dir.display())));
+            }
+        }
+        self.write_meta(VERSION_KEY, &VERSION)
+    }
+
+    fn check_version(&self) -> Result<(), Error> {
+        if !self.has(VERSION_KEY) {
+            return Err(Error::ParseError(format!("cache {} has no format version", self.dir.display())));
+        }
+        let version: u32 = self.read_meta(VERSION_KEY)?;
+        if version != VERSION {
+            return Err(Error::ParseError(format!("cache {} has format version {}, expected {}", self.dir.display(), version, VERSION)));
+        }
+        Ok(())
+    }
+
+    fn write_meta<T: Serialize>(&self, key: &str, val: &T) -> Result<(), Error> {
+        let tmp = self.tmp_path(key);
+        let mut cf = zstd::stream::Encoder::new(File::create(&tmp)?, 9)?;
+        bincode::serialize_into(&mut cf, val).map_err(|e| Error::ParseError(format!("cache metadata {}: {}", key, e)))?;
+        cf.finish()?;
+        rename(tmp, self.path(key))?;
+        Ok(())
+    }
+
+    fn read_meta<T: DeserializeOwned>(&self, key: &str) -> Result<T, Error> {
+        let f = File::open(self.path(key))?;
+        let cf = zstd::stream::Decoder::new(f)?;
+        bincode::deserialize_from(cf).map_err(|e| Error::ParseError(format!("cache metadata {}: {}", key, e)))
+    }
+
+    pub fn has(&self, key: &str) -> bool {
+        self.path(key).exists()
+    }
+
+    /// Wraps a tool output stream so that everything read through it is also
+    /// stored under the given key.  The entry only appears once the stream
+    /// has been read to the end.
+    pub fn record<'a, R: Read + 'a>(&self, key: &str, r: R) -> Result<Box<dyn BufRead + 'a>, Error> {
+        self.prepare()?;
+        let tmp = self.tmp_path(key);
+        let out = zstd::stream::write::Encoder::new(File::create(&tmp)?, 3)?;
+        Ok(Box::new(BufReader::new(TeeReader {
+            inner: r,
+            out: Some(out),
+            tmp,
+            path: self.path(key),
+        })))
+    }
+
+    /// Stores a complete tool output at once.  The entry only appears when
+    /// fully written, so an interrupted store leaves nothing behind.
+    pub fn store(&self, key: &str, data: &[u8]) -> Result<(), Error> {
+        self.prepare()?;
+        let tmp = self.tmp_path(key);
+        let mut out = zstd::stream::write::Encoder::new(File::create(&tmp)?, 3)?;
+        out.write_all(data)?;
+        out.finish()?;
+        rename(tmp, self.path(key))?;
+        Ok(())
+    }
+
+    pub fn replay(&self, key: &str) -> Result<Box<dyn BufRead>, Error> {
+        self.check_version()?;
+        let f = File::open(self.path(key))?;
+        Ok(Box::new(BufReader::new(zstd::stream::Decoder::new(f)?)))
+    }
+
+    pub fn save_meta<T: Serialize>(&self, key: &str, val: &T) -> Result<(), Error> {
+        self.prepare()?;
+        self.write_meta(key, val)
+    }
+
+    pub fn load_meta<T: DeserializeOwned>(&self, key: &str) -> Result<T, Error> {
+        self.check_version()?;
+        self.read_meta(key)
+    }
+}
+
+/// Where tool outputs come from: a live toolchain, optionally recording
+/// everything into a cache, or a previously recorded cache alone.
+pub enum ToolRunner<'a> {
+    Live(&'a Toolchain),
+    Record(&'a Toolchain, ToolCache),
+    Replay(ToolCache),
+}
+
+impl<'a> ToolRunner<'a> {
+    /// Like `ToolchainReader::new`, with `key` naming the invocation in the
+    /// cache.
+    pub fn run(&self, key: &str, cmd: &str, args: &[&str], env: &[(&str, &str)], out_name: &str, input_files: &[(&str, &[u8])]) -> Result<Box<dyn BufRead>, Error> {
+        match self {
+            ToolRunner::Live(tc) => Ok(Box::new(ToolchainReader::new(tc, cmd, args, env, out_name, input_files)?)),
+            ToolRunner::Record(tc, cache) => cache.record(key, ToolchainReader::new(tc, cmd, args, env, out_name, input_files)?.into_inner()),
+            ToolRunner::Replay(cache) => cache.replay(key),
+        }
+    }
+
+    pub fn toolchain(&self) -> Option<&'a Toolchain> {
+        match *self {
+            ToolRunner::Live(tc) => Some(tc),
+            ToolRunner::Record(tc, _) => Some(tc),
+            ToolRunner::Replay(_) => None,
+        }
+    }
+
+    pub fn cache(&self) -> Option<&ToolCache> {
+        match self {
+            ToolRunner::Live(_) => None,
+            ToolRunner::Record(_, cache) => Some(cache),
+            ToolRunner::Replay(cache) => Some(cache),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Cursor, Read};
+    use tempdir::TempDir;
+    use super::ToolCache;
+
+    #[test]
+    fn record_test() {
+        let dir = TempDir::new("toolcache").unwrap();
+        let cache = ToolCache::new(dir.path());
+        let data = vec![b'x'; 1 << 20];
+        {
+            let mut r = cache.record("partial", Cursor::new(&data[..])).unwrap();
+            let mut buf = [0; 16];
+            r.read_exact(&mut buf).unwrap();
+        }
+        assert!(!cache.has("partial"));
+        {
+            let mut r = cache.record("full", Cursor::new(&data[..])).unwrap();
+            let mut buf = Vec::new();
+            r.read_to_end(&mut buf).unwrap();
+        }
+        let mut buf = Vec::new();
+        cache.replay("full").unwrap().read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, data);
+        {
+            let failing = Cursor::new(&data[..]).chain(FailingReader);
+            let mut r = cache.record("failed", failing).unwrap();
+            let mut buf = Vec::new();
+            assert!(r.read_to_end(&mut buf).is_err());
+        }
+        assert!(!cache.has("failed"));
+    }
+
+    struct FailingReader;
+
+    impl Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("tool failed"))
+        }
+    }
+
+    #[test]
+    fn version_test() {
+        let dir = TempDir::new("toolcache").unwrap();
+        std::fs::write(dir.path().join("stray.zst"), b"").unwrap();
+        let cache = ToolCache::new(dir.path());
+        assert!(cache.store("x", b"x").is_err());
+        assert!(!cache.has("version"));
+        let cache = ToolCache::new(dir.path().join("sub"));
+        cache.store("x", b"x").unwrap();
+        cache.store("y", b"y").unwrap();
+        assert!(cache.replay("y").is_ok());
+    }
+}
@@ -2,32 +2,234 @@ use std::collections::HashMap;
 use std::path::Path;
 use std::fs::read;
 use std::process::Command;
+use std::time::Duration;
 use crate::error::Error;
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Deserialize)]
+/// How to run the vendor tools.  The final command line is the wrapper (if
+/// any), then `wine` (if enabled), then the tool itself.  The wrapper can be
+/// anything that runs its arguments as a command: a container runtime,
+/// `firejail`, or a shell sourcing the tool's settings script, eg.
+/// `["sh", "-c", ". /opt/Xilinx/14.7/ISE_DS/settings64.sh; exec \"$0\" \"$@\""]`.
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct Toolchain {
+    #[serde(default)]
     pub use_wine: bool,
+    #[serde(default)]
+    pub wrapper: Vec<String>,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Whether tool output is streamed through a fifo, rather than written
+    /// to a regular file and read once the tool exits.  Defaults to true,
+    /// except under wine, where fifos are unreliable.
+    #[serde(default)]
+    pub use_fifo: Option<bool>,
+    /// Kill any single tool invocation running longer than this many seconds.
+    #[serde(default)]
+    pub timeout: Option<u64>,
+    /// Per-tool settings, keyed by tool name (eg. `xdl`, `vivado`).
+    #[serde(default)]
+    pub tools: HashMap<String, ToolOverride>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ToolOverride {
+    /// The command to run instead of the bare tool name.
+    pub path: Option<String>,
+    /// Replaces the toolchain-wide wrapper for this tool.
+    pub wrapper: Option<Vec<String>>,
+    /// Added on top of the toolchain-wide environment.
+    #[serde(default)]
     pub env: HashMap<String, String>,
+    pub timeout: Option<u64>,
 }
 
 impl Toolchain {
     pub fn from_file<P: AsRef<Path>> (path: P) -> Result<Self, Error> {
         let s = read(path)?;
-        Ok(toml::from_slice(&s).unwrap())
+        toml::from_slice(&s).map_err(|e| Error::ParseError(format!("toolchain config: {}", e)))
     }
 
     pub fn command(&self, cmd: &str) -> Command {
-        let mut res: Command;
+        let ovr = self.tools.get(cmd);
+        let mut argv: Vec<&str> = Vec::new();
+        match ovr.and_then(|o| o.wrapper.as_ref()) {
+            Some(w) => argv.extend(w.iter().map(|s| &s[..])),
+            None => argv.extend(self.wrapper.iter().map(|s| &s[..])),
+        }
         if self.use_wine {
-            res = Command::new("wine");
-            res.arg(cmd);
-        } else {
-            res = Command::new(cmd);
+            argv.push("wine");
         }
+        argv.push(match ovr.and_then(|o| o.path.as_ref()) {
+            Some(p) => p,
+            None => cmd,
+        });
+        let mut res = Command::new(argv[0]);
+        res.args(&argv[1..]);
         for (k, v) in self.env.iter() {
             res.env(k, v);
         }
+        if let Some(ovr) = ovr {
+            for (k, v) in ovr.env.iter() {
+                res.env(k, v);
+            }
+        }
         res
     }
+
+    pub fn use_fifo(&self) -> bool {
+        self.use_fifo.unwrap_or(!self.use_wine)
+    }
+
+    pub fn timeout(&self, cmd: &str) -> Option<Duration> {
+        match self.tools.get(cmd).and_then(|o| o.timeout) {
+            Some(t) => Some(Duration::from_secs(t)),
+            None => self.timeout.map(Duration::from_secs),
+        }
+    }
+}
+
+/// Several named toolchains, with a mapping from family to the toolchain that
+/// should be used for it.  A plain single-toolchain file is also accepted,
+/// and then serves every family.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToolchainSet {
+    pub toolchains: HashMap<String, Toolchain>,
+    /// The toolchain for families not listed in `families`.
+    #[serde(default)]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub families: HashMap<String, String>,
+}
+
+impl ToolchainSet {
+    pub fn from_file<P: AsRef<Path>> (path: P) -> Result<Self, Error> {
+        let s = read(path)?;
+        let v: toml::Value = toml::from_slice(&s).map_err(|e| Error::ParseError(format!("toolchain config: {}", e)))?;
+        if v.get("toolchains").is_some() {
+            let res: ToolchainSet = v.try_into().map_err(|e| Error::ParseError(format!("toolchain config: {}", e)))?;
+            if let Some(name) = &res.default {
+                res.by_name(name)?;
+            }
+            for name in res.families.values() {
+                res.by_name(name)?;
+            }
+            Ok(res)
+        } else {
+            let tc: Toolchain = v.try_into().map_err(|e| Error::ParseError(format!("toolchain config: {}", e)))?;
+            Ok(ToolchainSet {
+                toolchains: vec![("default".to_string(), tc)].into_iter().collect(),
+                default: Some("default".to_string()),
+                families: HashMap::new(),
+            })
+        }
+    }
+
+    /// A set of just one plain toolchain, running tools from `PATH`.  For
+    /// sources that need no vendor tools.
+    pub fn plain() -> Self {
+        ToolchainSet {
+            toolchains: vec![("default".to_string(), Toolchain::default())].into_iter().collect(),
+            default: Some("default".to_string()),
+            families: HashMap::new(),
+        }
+    }
+
+    pub fn by_name(&self, name: &str) -> Result<&Toolchain, Error> {
+        self.toolchains.get(name).ok_or_else(|| Error::ParseError(format!("unknown toolchain {}", name)))
+    }
+
+    pub fn default(&self) -> Result<&Toolchain, Error> {
+        match &self.default {
+            Some(name) => self.by_name(name),
+            None => Err(Error::ParseError("no default toolchain".to_string())),
+        }
+    }
+
+    pub fn for_family(&self, family: &str) -> Result<&Toolchain, Error> {
+        match self.families.get(family) {
+            Some(name) => self.by_name(name),
+            None => self.default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+    use std::process::Command;
+    use tempdir::TempDir;
+    use super::ToolchainSet;
+
+    fn load(config: &str) -> Result<ToolchainSet, crate::error::Error> {
+        let dir = TempDir::new("toolchain").unwrap();
+        let path = dir.path().join("toolchain.toml");
+        std::fs::write(&path, config).unwrap();
+        ToolchainSet::from_file(&path)
+    }
+
+    fn argv(cmd: &Command) -> Vec<&OsStr> {
+        let mut res = vec![cmd.get_program()];
+        res.extend(cmd.get_args());
+        res
+    }
+
+    fn env<'a>(cmd: &'a Command, key: &str) -> Option<&'a OsStr> {
+        cmd.get_envs().find(|(k, _)| *k == key).and_then(|(_, v)| v)
+    }
+
+    #[test]
+    fn command_test() {
+        let tcs = load(r#"
+            default = "ise"
+
+            [families]
+            virtex4 = "old"
+
+            [toolchains.ise]
+            use_wine = true
+            wrapper = ["firejail", "--quiet"]
+            env = { XILINX = "/opt/ise", LANG = "C" }
+
+            [toolchains.ise.tools.xdl]
+            path = "xdl.exe"
+            env = { LANG = "en_US" }
+
+            [toolchains.ise.tools.partgen]
+            wrapper = ["nice"]
+
+            [toolchains.old]
+        "#).unwrap();
+        let tc = tcs.for_family("virtex2").unwrap();
+        let cmd = tc.command("xdl");
+        assert_eq!(argv(&cmd), ["firejail", "--quiet", "wine", "xdl.exe"]);
+        assert_eq!(env(&cmd, "XILINX"), Some(OsStr::new("/opt/ise")));
+        assert_eq!(env(&cmd, "LANG"), Some(OsStr::new("en_US")));
+        let cmd = tc.command("partgen");
+        assert_eq!(argv(&cmd), ["nice", "wine", "partgen"]);
+        assert_eq!(env(&cmd, "LANG"), Some(OsStr::new("C")));
+        assert!(!tc.use_fifo());
+        let cmd = tcs.for_family("virtex4").unwrap().command("xdl");
+        assert_eq!(argv(&cmd), ["xdl"]);
+        assert_eq!(env(&cmd, "XILINX"), None);
+    }
+
+    #[test]
+    fn plain_test() {
+        let tcs = load(r#"
+            wrapper = ["sh", "-c", "exec \"$0\" \"$@\""]
+            timeout = 10
+        "#).unwrap();
+        let tc = tcs.for_family("spartan6").unwrap();
+        assert_eq!(argv(&tc.command("vivado")), ["sh", "-c", "exec \"$0\" \"$@\"", "vivado"]);
+        assert_eq!(tc.timeout("vivado"), Some(std::time::Duration::from_secs(10)));
+        assert!(tc.use_fifo());
+    }
+
+    #[test]
+    fn unknown_toolchain_test() {
+        assert!(load("default = \"missing\"\n[toolchains.ise]\n").is_err());
+        assert!(load("[families]\nvirtex = \"missing\"\n[toolchains.ise]\n").is_err());
+        assert!(load("use_wine = \"yes\"\n").is_err());
+    }
 }
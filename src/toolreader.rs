@@ -1,29 +1,210 @@
 use nix::sys::stat::Mode;
-use nix::unistd::mkfifo;
-use nix::fcntl::{fcntl, FcntlArg};
-use std::fs::{File, write};
+use nix::sys::signal::{killpg, SigSet, Signal};
+use nix::unistd::{mkfifo, Pid};
+use nix::fcntl::{fcntl, FcntlArg, OFlag};
+use std::fs::{File, OpenOptions, write};
 use std::io::{self, BufReader, Read};
-use std::process::{Child, Stdio};
+use std::process::{self, ChildStdin, ChildStdout, Command, ExitStatus, Stdio};
+use std::os::unix::fs::OpenOptionsExt;
 use std::os::unix::io::AsRawFd;
+use std::os::unix::process::CommandExt;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 use tempdir::TempDir;
 use crate::toolchain::Toolchain;
 use crate::error::Error;
 
+// Process groups of all tools currently running, so that they can be killed
+// when we are interrupted.
+static ACTIVE_GROUPS: Mutex<Vec<Pid>> = Mutex::new(Vec::new());
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+fn kill_group(pgid: Pid) {
+    // Nothing much to do if it fails — the group is likely gone already.
+    let _ = killpg(pgid, Signal::SIGKILL);
+}
+
+/// Kills every tool process group that is still running.
+pub fn kill_all_tools() {
+    let groups = ACTIVE_GROUPS.lock().unwrap();
+    for pgid in groups.iter() {
+        kill_group(*pgid);
+    }
+}
+
+/// Arranges for SIGINT, SIGTERM and SIGHUP to kill all running tools and then
+/// exit.  Must be called before any other thread is started, so that they all
+/// inherit the blocked signal mask.
+pub fn handle_signals() -> Result<(), Error> {
+    let mut set = SigSet::empty();
+    set.add(Signal::SIGINT);
+    set.add(Signal::SIGTERM);
+    set.add(Signal::SIGHUP);
+    set.thread_block()?;
+    thread::spawn(move || {
+        if let Ok(sig) = set.wait() {
+            kill_all_tools();
+            eprintln!("caught {:?}, killed running tools", sig);
+            process::exit(128 + sig as i32);
+        }
+    });
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct ToolState {
+    status: Option<ExitStatus>,
+    deadline: Option<Instant>,
+    timed_out: bool,
+}
+
+/// A tool child process running in its own process group, watched by
+/// a thread that enforces the timeout.
+pub struct ToolProcess {
+    name: String,
+    pgid: Pid,
+    state: Arc<Mutex<ToolState>>,
+    watchdog: Option<JoinHandle<()>>,
+    stdin: Option<ChildStdin>,
+    stdout: Option<ChildStdout>,
+}
+
+impl ToolProcess {
+    /// Spawns the command.  `keepalive` is closed once the tool exits — this
+    /// is used to hold a fifo write end open until then, so that the reader
+    /// sees EOF even if the tool dies without ever opening the fifo.
+    pub fn spawn(name: &str, mut cmd: Command, timeout: Option<Duration>, keepalive: Option<File>) -> Result<Self, Error> {
+        cmd.process_group(0);
+        let mut child = {
+            // Hold the lock across spawn, so that the signal thread cannot
+            // miss a freshly started group.
+            let mut groups = ACTIVE_GROUPS.lock().unwrap();
+            let child = cmd.spawn()?;
+            groups.push(Pid::from_raw(child.id() as i32));
+            child
+        };
+        let pgid = Pid::from_raw(child.id() as i32);
+        let stdin = child.stdin.take();
+        let stdout = child.stdout.take();
+        let state = Arc::new(Mutex::new(ToolState {
+            deadline: timeout.map(|t| Instant::now() + t),
+            ..Default::default()
+        }));
+        let wstate = state.clone();
+        let watchdog = thread::spawn(move || {
+            let status = loop {
+                match child.try_wait() {
+                    Ok(Some(status)) => break Some(status),
+                    Ok(None) => (),
+                    Err(_) => break None,
+                }
+                let expired = {
+                    let mut state = wstate.lock().unwrap();
+                    if let Some(deadline) = state.deadline {
+                        if Instant::now() >= deadline {
+                            state.timed_out = true;
+                        }
+                    }
+                    state.timed_out
+                };
+                if expired {
+                    kill_group(pgid);
+                    break child.wait().ok();
+                }
+                thread::sleep(POLL_INTERVAL);
+            };
+            ACTIVE_GROUPS.lock().unwrap().retain(|x| *x != pgid);
+            wstate.lock().unwrap().status = status;
+            drop(keepalive);
+        });
+        Ok(ToolProcess {
+            name: name.to_string(),
+            pgid,
+            state,
+            watchdog: Some(watchdog),
+            stdin,
+            stdout,
+        })
+    }
+
+    /// Takes the stdin and stdout pipes, if the command was set up with them.
+    pub fn take_pipes(&mut self) -> (Option<ChildStdin>, Option<ChildStdout>) {
+        (self.stdin.take(), self.stdout.take())
+    }
+
+    /// Restarts the timeout clock, for long-lived tools that get their work
+    /// piece by piece.
+    pub fn set_timeout(&self, timeout: Option<Duration>) {
+        self.state.lock().unwrap().deadline = timeout.map(|t| Instant::now() + t);
+    }
+
+    /// Waits for the tool to exit.
+    pub fn wait(mut self) -> Result<ExitStatus, Error> {
+        self.join();
+        self.check()?;
+        let state = self.state.lock().unwrap();
+        match state.status {
+            Some(status) => Ok(status),
+            None => Err(Error::ToolError(format!("lost track of {}", self.name))),
+        }
+    }
+
+    /// Waits for the tool to exit, and returns an error unless it exited
+    /// successfully.  Can be called again, with the same result.
+    pub fn finish(&mut self) -> Result<(), Error> {
+        self.join();
+        self.check()?;
+        let state = self.state.lock().unwrap();
+        match state.status {
+            Some(status) if status.success() => Ok(()),
+            Some(status) => Err(Error::ToolError(format!("{} failed: {}", self.name, status))),
+            None => Err(Error::ToolError(format!("lost track of {}", self.name))),
+        }
+    }
+
+    /// Returns an error if the tool has been killed because of a timeout.
+    pub fn check(&self) -> Result<(), Error> {
+        if self.state.lock().unwrap().timed_out {
+            Err(Error::Timeout(self.name.clone()))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn join(&mut self) {
+        if let Some(watchdog) = self.watchdog.take() {
+            // Nothing much to do if it fails.
+            let _ = watchdog.join();
+        }
+    }
+}
+
+impl Drop for ToolProcess {
+    fn drop(&mut self) {
+        // Nobody is waiting for the tool any more, so do not let a hung one
+        // keep us here forever.
+        if self.watchdog.is_some() && self.state.lock().unwrap().status.is_none() {
+            kill_group(self.pgid);
+        }
+        self.join();
+    }
+}
+
 pub struct ToolchainReader {
+    output: Option<File>,
+    process: Option<ToolProcess>,
     _dir: TempDir,
-    fifo: Option<File>,
-    child: Child,
 }
 
 impl ToolchainReader {
-    pub fn new(tc: &Toolchain, cmd: &str, args: &[&str], env: &[(&str, &str)], fifo_name: &str, input_files: &[(&str, &[u8])]) -> Result<BufReader<Self>, Error> {
+    fn prepare(tc: &Toolchain, cmd: &str, args: &[&str], env: &[(&str, &str)], input_files: &[(&str, &[u8])]) -> Result<(TempDir, Command), Error> {
         let dir = TempDir::new(cmd)?;
         for (k, v) in input_files {
             let path = dir.path().join(k);
             write(path, v)?;
         }
-        let path = dir.path().join(fifo_name);
-        mkfifo(&path, Mode::S_IRUSR | Mode::S_IWUSR)?;
         let mut cmd = tc.command(cmd);
         cmd.current_dir(dir.path().as_os_str());
         cmd.stdin(Stdio::null());
@@ -35,24 +216,66 @@ impl ToolchainReader {
         for (k, v) in env {
             cmd.env(k, v);
         }
-        let child = cmd.spawn()?;
-        let fifo = File::open(path)?;
-        match fcntl(fifo.as_raw_fd(), FcntlArg::F_SETPIPE_SZ(1<<20)) {
-            Ok(_) => (),
-            Err(_) => (),
+        Ok((dir, cmd))
+    }
+
+    /// Runs the tool and reads the file it writes to `out_name` in its
+    /// working directory.  Depending on the toolchain, the file is either
+    /// a fifo read while the tool runs, or a regular file read once the tool
+    /// exits.
+    pub fn new(tc: &Toolchain, cmd: &str, args: &[&str], env: &[(&str, &str)], out_name: &str, input_files: &[(&str, &[u8])]) -> Result<BufReader<Self>, Error> {
+        if tc.use_fifo() {
+            ToolchainReader::new_fifo(tc, cmd, args, env, out_name, input_files)
+        } else {
+            ToolchainReader::new_file(tc, cmd, args, env, out_name, input_files)
         }
+    }
+
+    fn new_fifo(tc: &Toolchain, cmd: &str, args: &[&str], env: &[(&str, &str)], fifo_name: &str, input_files: &[(&str, &[u8])]) -> Result<BufReader<Self>, Error> {
+        let name = cmd;
+        let (dir, cmd) = ToolchainReader::prepare(tc, name, args, env, input_files)?;
+        let path = dir.path().join(fifo_name);
+        mkfifo(&path, Mode::S_IRUSR | Mode::S_IWUSR)?;
+        // Open both ends without blocking, then make the read end blocking.
+        let fifo = OpenOptions::new().read(true).custom_flags(OFlag::O_NONBLOCK.bits()).open(&path)?;
+        let keepalive = OpenOptions::new().write(true).custom_flags(OFlag::O_NONBLOCK.bits()).open(&path)?;
+        fcntl(fifo.as_raw_fd(), FcntlArg::F_SETFL(OFlag::empty()))?;
+        let process = ToolProcess::spawn(name, cmd, tc.timeout(name), Some(keepalive))?;
+        let _ = fcntl(fifo.as_raw_fd(), FcntlArg::F_SETPIPE_SZ(1<<20));
         Ok(BufReader::new(ToolchainReader {
-            fifo: Some(fifo),
+            output: Some(fifo),
+            process: Some(process),
+            _dir: dir,
+        }))
+    }
+
+    fn new_file(tc: &Toolchain, cmd: &str, args: &[&str], env: &[(&str, &str)], out_name: &str, input_files: &[(&str, &[u8])]) -> Result<BufReader<Self>, Error> {
+        let name = cmd;
+        let (dir, cmd) = ToolchainReader::prepare(tc, name, args, env, input_files)?;
+        ToolProcess::spawn(name, cmd, tc.timeout(name), None)?.finish()?;
+        let file = File::open(dir.path().join(out_name))?;
+        Ok(BufReader::new(ToolchainReader {
+            output: Some(file),
+            process: None,
             _dir: dir,
-            child,
         }))
     }
 }
 
 impl Read for ToolchainReader {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        match &mut self.fifo {
-            Some(fifo) => fifo.read(buf),
+        match &mut self.output {
+            Some(output) => {
+                let res = output.read(buf)?;
+                if res == 0 {
+                    // A tool that crashed closes the fifo just like one that
+                    // finished, so only its exit status tells them apart.
+                    if let Some(process) = &mut self.process {
+                        process.finish()?;
+                    }
+                }
+                Ok(res)
+            },
             None => Ok(0),
         }
     }
@@ -60,11 +283,46 @@ impl Read for ToolchainReader {
 
 impl Drop for ToolchainReader {
     fn drop(&mut self) {
-        self.fifo = None;
-        // Nothing much to do if it fails.
-        match self.child.wait() {
-            Ok(_) => (),
-            Err(_) => (),
+        // Closing the fifo makes the tool die on its next write, if it is
+        // still running; the ToolProcess drop then kills whatever is left
+        // and reaps it.
+        self.output = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::time::{Duration, Instant};
+    use crate::toolchain::Toolchain;
+    use crate::error::Error;
+    use super::ToolchainReader;
+
+    fn run(use_fifo: bool, script: &str) -> Result<Vec<u8>, Error> {
+        let tc = Toolchain {
+            use_fifo: Some(use_fifo),
+            ..Default::default()
+        };
+        let mut r = ToolchainReader::new(&tc, "sh", &["-c", script], &[], "out", &[])?;
+        let mut res = Vec::new();
+        r.read_to_end(&mut res)?;
+        Ok(res)
+    }
+
+    #[test]
+    fn exit_status_test() {
+        for &use_fifo in &[true, false] {
+            assert_eq!(run(use_fifo, "echo hello > out").unwrap(), b"hello\n");
+            assert!(run(use_fifo, "echo hello > out; exit 3").is_err());
         }
     }
+
+    #[test]
+    fn drop_test() {
+        let tc = Toolchain::default();
+        let start = Instant::now();
+        let r = ToolchainReader::new(&tc, "sh", &["-c", "sleep 60"], &[], "out", &[]).unwrap();
+        drop(r);
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
 }
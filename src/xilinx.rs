@@ -1,5 +1,15 @@
+pub mod family;
+pub mod tiletables;
 pub mod rawdump;
 pub mod rdbuild;
 pub mod ise;
 pub mod vivado;
 pub mod geomdb;
+pub mod source;
+pub mod prjxray;
+pub mod vpr;
+pub mod nextpnr;
+#[cfg(feature = "interchange")]
+pub mod interchange;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
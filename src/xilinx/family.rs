@@ -0,0 +1,422 @@
+use std::fmt;
+use regex::Regex;
+use serde::{Serialize, Deserialize};
+use super::tiletables::*;
+
+/// How PartBuilder assigns slots to the sites of a tile, where the plain
+/// `NAME_XnYm` scheme does not work.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum SlotQuirks {
+    None,
+    Xc4000,
+    Virtex,
+    Virtex2,
+    Spartan3,
+    Spartan3E,
+    Spartan6,
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Serialize, Deserialize)]
+pub enum Family {
+    Xc9500,
+    Xpla3,
+    Xbr,
+    Xc4000e,
+    Xc4000ex,
+    Xc4000xla,
+    Xc4000xv,
+    SpartanXl,
+    Virtex,
+    VirtexE,
+    Virtex2,
+    Virtex2P,
+    Spartan3,
+    Spartan3E,
+    Spartan3A,
+    Spartan3ADsp,
+    Spartan6,
+    Virtex4,
+    Virtex5,
+    Virtex6,
+    Series7,
+    Ultrascale,
+    UltrascalePlus,
+    Versal,
+}
+
+pub struct FamilyInfo {
+    pub family: Family,
+    /// Our name for the family, used on the command line and for directories.
+    pub name: &'static str,
+    /// What partgen calls the (sub-)families we lump together.
+    pub ise_families: &'static [&'static str],
+    /// The Vivado part ARCHITECTUREs of the family.
+    pub vivado_archs: &'static [&'static str],
+    /// Device and package name patterns, tried in order.  ISE part names
+    /// are split with them, and device names of other sources are matched
+    /// against the device pattern alone.
+    pub part_patterns: &'static [(&'static str, &'static str)],
+    /// Whether the dumps fill the space under big tiles with NULL tiles,
+    /// rather than with empty ones.
+    pub null_tiles: bool,
+    /// Site slot assignment quirks of the ISE dumps.
+    pub slot_quirks: SlotQuirks,
+    /// Tile kinds for rd2html; empty for families without tiles.
+    pub tiles: &'static [TileInfo],
+}
+
+pub const FAMILIES: &[FamilyInfo] = &[
+    FamilyInfo {
+        family: Family::Xc9500,
+        name: "xc9500",
+        ise_families: &[],
+        vivado_archs: &[],
+        part_patterns: &[("x[ca]95[0-9]+(?:xl|xv)?", "[a-z]{2}[0-9]+")],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::None,
+        tiles: &[],
+    },
+    FamilyInfo {
+        family: Family::Xpla3,
+        name: "xpla3",
+        ise_families: &[],
+        vivado_archs: &[],
+        part_patterns: &[("xcr3[0-9]+xl", "[a-z]{2}[0-9]+")],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::None,
+        tiles: &[],
+    },
+    FamilyInfo {
+        family: Family::Xbr,
+        name: "xbr",
+        ise_families: &[],
+        vivado_archs: &[],
+        part_patterns: &[("x[ca]2c[0-9]+a?", "[a-z]{2}g?[0-9]+")],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::None,
+        tiles: &[],
+    },
+    FamilyInfo {
+        family: Family::Xc4000e,
+        name: "xc4000e",
+        ise_families: &["xc4000e", "xc4000l", "spartan"],
+        vivado_archs: &[],
+        part_patterns: &[
+            ("xc40[0-9]+[el]", "[a-z]{2}[0-9]+"),
+            ("xcs[0-9]+", "[a-z]{2}[0-9]+"),
+        ],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::Xc4000,
+        tiles: XC4K_TILES,
+    },
+    FamilyInfo {
+        family: Family::Xc4000ex,
+        name: "xc4000ex",
+        ise_families: &["xc4000ex", "xc4000xl"],
+        vivado_archs: &[],
+        part_patterns: &[("xc40[0-9]+(?:xl|ex)", "[a-z]{2}[0-9]+")],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::Xc4000,
+        tiles: XC4K_TILES,
+    },
+    FamilyInfo {
+        family: Family::Xc4000xla,
+        name: "xc4000xla",
+        ise_families: &["xc4000xla"],
+        vivado_archs: &[],
+        part_patterns: &[("xc40[0-9]+xla", "[a-z]{2}[0-9]+")],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::Xc4000,
+        tiles: XC4K_TILES,
+    },
+    FamilyInfo {
+        family: Family::Xc4000xv,
+        name: "xc4000xv",
+        ise_families: &["xc4000xv"],
+        vivado_archs: &[],
+        part_patterns: &[("xc40[0-9]+xv", "[a-z]{2}[0-9]+")],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::Xc4000,
+        tiles: XC4K_TILES,
+    },
+    FamilyInfo {
+        family: Family::SpartanXl,
+        name: "spartanxl",
+        ise_families: &["spartanxl"],
+        vivado_archs: &[],
+        part_patterns: &[("xcs[0-9]+xl", "[a-z]{2}[0-9]+")],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::Xc4000,
+        tiles: XC4K_TILES,
+    },
+    FamilyInfo {
+        family: Family::Virtex,
+        name: "virtex",
+        ise_families: &["virtex", "qvirtex", "qrvirtex", "spartan2"],
+        vivado_archs: &[],
+        part_patterns: &[("x(?:cv|qv|qvr|c2s)[0-9]+", "[a-z]{2}[0-9]+")],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::Virtex,
+        tiles: VIRTEX_TILES,
+    },
+    FamilyInfo {
+        family: Family::VirtexE,
+        name: "virtexe",
+        ise_families: &["virtexe", "qvirtexe", "spartan2e", "aspartan2e"],
+        vivado_archs: &[],
+        part_patterns: &[("x(?:cv|qv|c2s|a2s)[0-9]+e", "[a-z]{2}[0-9]+")],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::Virtex,
+        tiles: VIRTEX_TILES,
+    },
+    FamilyInfo {
+        family: Family::Virtex2,
+        name: "virtex2",
+        ise_families: &["virtex2", "qvirtex2", "qrvirtex2"],
+        vivado_archs: &[],
+        part_patterns: &[("x(?:c|q|qr)2v[0-9]+", "[a-z]{2}[0-9]+")],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::Virtex2,
+        tiles: VIRTEX2_TILES,
+    },
+    FamilyInfo {
+        family: Family::Virtex2P,
+        name: "virtex2p",
+        ise_families: &["virtex2p", "qvirtex2p"],
+        vivado_archs: &[],
+        part_patterns: &[("x[cq]2vpx?[0-9]+", "[a-z]{2}[0-9]+")],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::Virtex2,
+        tiles: VIRTEX2_TILES,
+    },
+    FamilyInfo {
+        family: Family::Spartan3,
+        name: "spartan3",
+        ise_families: &["spartan3", "aspartan3"],
+        vivado_archs: &[],
+        part_patterns: &[
+            ("xc3s[0-9]+l?", "[a-z]{2}[0-9]+"),
+            ("xa3s[0-9]+l?", "[a-z]{2}g[0-9]+"),
+        ],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::Spartan3,
+        tiles: SPARTAN3_TILES,
+    },
+    FamilyInfo {
+        family: Family::Spartan3E,
+        name: "spartan3e",
+        ise_families: &["spartan3e", "aspartan3e"],
+        vivado_archs: &[],
+        part_patterns: &[
+            ("xc3s[0-9]+e", "[a-z]{2}[0-9]+"),
+            ("xa3s[0-9]+e", "[a-z]{2}g[0-9]+"),
+        ],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::Spartan3E,
+        tiles: SPARTAN3E_TILES,
+    },
+    FamilyInfo {
+        family: Family::Spartan3A,
+        name: "spartan3a",
+        ise_families: &["spartan3a", "aspartan3a"],
+        vivado_archs: &[],
+        part_patterns: &[
+            ("xc3s[0-9]+a", "[a-z]{2}[0-9]+"),
+            ("xc3s[0-9]+an", "[a-z]{2}g[0-9]+"),
+            ("xa3s[0-9]+a", "[a-z]{2}g[0-9]+"),
+        ],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::Spartan3E,
+        tiles: SPARTAN3E_TILES,
+    },
+    FamilyInfo {
+        family: Family::Spartan3ADsp,
+        name: "spartan3adsp",
+        ise_families: &["spartan3adsp", "aspartan3adsp"],
+        vivado_archs: &[],
+        part_patterns: &[
+            ("xc3sd[0-9]+a", "[a-z]{2}[0-9]+"),
+            ("xa3sd[0-9]+a", "[a-z]{2}g[0-9]+"),
+        ],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::Spartan3E,
+        tiles: SPARTAN3E_TILES,
+    },
+    FamilyInfo {
+        family: Family::Spartan6,
+        name: "spartan6",
+        ise_families: &["spartan6", "spartan6l", "aspartan6", "qspartan6", "qspartan6l"],
+        vivado_archs: &[],
+        part_patterns: &[("x[cqa]6slx[0-9](?:[0-9]+t?|)l?", "[a-z]{2}g?[0-9]+")],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::Spartan6,
+        tiles: SPARTAN6_TILES,
+    },
+    FamilyInfo {
+        family: Family::Virtex4,
+        name: "virtex4",
+        ise_families: &["virtex4", "qvirtex4", "qrvirtex4"],
+        vivado_archs: &[],
+        part_patterns: &[("x(?:c|q|qr)4v[lsf]x[0-9]+", "[a-z]{2}[0-9]+")],
+        null_tiles: false,
+        slot_quirks: SlotQuirks::None,
+        tiles: VIRTEX4_TILES,
+    },
+    FamilyInfo {
+        family: Family::Virtex5,
+        name: "virtex5",
+        ise_families: &["virtex5", "qvirtex5"],
+        vivado_archs: &[],
+        part_patterns: &[("x[cq]5v[lsft]x[0-9]+t?", "[a-z]{2}[0-9]+")],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::None,
+        tiles: VIRTEX5_TILES,
+    },
+    FamilyInfo {
+        family: Family::Virtex6,
+        name: "virtex6",
+        ise_families: &["virtex6", "virtex6l", "qvirtex6", "qvirtex6l"],
+        vivado_archs: &[],
+        part_patterns: &[("x[cq]6v[lshc]x[0-9]+t?l?", "[a-z]{2}g?[0-9]+")],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::None,
+        tiles: VIRTEX6_TILES,
+    },
+    // Both tools know 7 series; Vivado is needed for Spartan 7.
+    FamilyInfo {
+        family: Family::Series7,
+        name: "7series",
+        ise_families: &[
+            "artix7", "artix7l", "aartix7", "qartix7",
+            "kintex7", "kintex7l", "qkintex7", "qkintex7l",
+            "virtex7", "qvirtex7",
+            "zynq", "azynq", "qzynq",
+        ],
+        vivado_archs: &["spartan7", "artix7", "kintex7", "virtex7", "zynq"],
+        part_patterns: &[
+            ("x[ca]7(?:[aksvz]|v[xh])[0-9]+[st]?[li]?", "[a-z]{2}[gv][0-9]+"),
+            ("xq7(?:[akv]|v[xh])[0-9]+t?[li]?", "[a-z]{2}[0-9]+"),
+            ("xq7z[0-9]+", "[a-z]{2}g?[0-9]+"),
+        ],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::None,
+        tiles: SERIES7_TILES,
+    },
+    FamilyInfo {
+        family: Family::Ultrascale,
+        name: "ultrascale",
+        ise_families: &[],
+        vivado_archs: &["kintexu", "virtexu"],
+        part_patterns: &[("x(?:c|q|qr)[kv]u[0-9]+", "[a-z]{4}[0-9]+")],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::None,
+        tiles: ULTRASCALE_TILES,
+    },
+    // Spartan and Artix UltraScale+ use the same fabric and dumper as the
+    // rest; their new tile kinds show up as unknown in rd2html until the
+    // tables learn them.
+    FamilyInfo {
+        family: Family::UltrascalePlus,
+        name: "ultrascaleplus",
+        ise_families: &[],
+        vivado_archs: &[
+            "spartanuplus", "artixuplus", "kintexuplus",
+            "virtexuplus", "virtexuplusHBM", "virtexuplus58g",
+            "zynquplus", "zynquplusRFSOC",
+        ],
+        part_patterns: &[
+            ("x(?:c|a|q|qr)(?:[kv]u|au|su)[0-9]+p", "[a-z]{4}[0-9]+"),
+            ("x(?:c|a|q|qr)zu[0-9]+(?:cg|eg|ev|dr)", "[a-z]{4}[0-9]+"),
+        ],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::None,
+        tiles: ULTRASCALEPLUS_TILES,
+    },
+    // All Versal series share the PL fabric; their NoC, AI Engine and
+    // CPM/PS blocks are told apart by site type when dumping.  Tile kinds
+    // missing from the tables show up as unknown in rd2html.
+    FamilyInfo {
+        family: Family::Versal,
+        name: "versal",
+        ise_families: &[],
+        vivado_archs: &[
+            "versal", "versalaicore", "versalaiedge", "versalprime",
+            "versalpremium", "versalhbm", "versalnet",
+            "versalaiedge2", "versalprime2",
+        ],
+        part_patterns: &[
+            ("x(?:c|a|q)v[cmpehn][0-9]+", "[a-z]{4}[0-9]+"),
+            ("x(?:c|a|q)2v[em][0-9]+", "[a-z]{4}[0-9]+"),
+        ],
+        null_tiles: true,
+        slot_quirks: SlotQuirks::None,
+        tiles: VERSAL_TILES,
+    },
+];
+
+impl Family {
+    pub fn info(self) -> &'static FamilyInfo {
+        FAMILIES.iter().find(|fi| fi.family == self).unwrap()
+    }
+
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    pub fn from_name(name: &str) -> Option<Family> {
+        FAMILIES.iter().find(|fi| fi.name == name).map(|fi| fi.family)
+    }
+
+    /// Finds the family of a bare device name, such as `xc7a35t`.
+    pub fn from_device_name(name: &str) -> Option<Family> {
+        for fi in FAMILIES {
+            for (dpat, _) in fi.part_patterns {
+                if Regex::new(&format!("^(?:{})$", dpat)).unwrap().is_match(name) {
+                    return Some(fi.family);
+                }
+            }
+        }
+        None
+    }
+
+    /// Maps a Vivado part ARCHITECTURE to our family.
+    pub fn from_vivado_arch(arch: &str) -> Option<Family> {
+        FAMILIES.iter().find(|fi| fi.vivado_archs.contains(&arch)).map(|fi| fi.family)
+    }
+}
+
+impl fmt::Display for Family {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Family, FAMILIES};
+
+    #[test]
+    fn family_registry_test() {
+        for fi in FAMILIES {
+            assert_eq!(fi.family.info().name, fi.name);
+            assert_eq!(Family::from_name(fi.name), Some(fi.family));
+        }
+        assert_eq!(Family::from_vivado_arch("zynquplusRFSOC"), Some(Family::UltrascalePlus));
+        assert_eq!(Family::from_vivado_arch("versal"), Some(Family::Versal));
+        assert_eq!(Family::from_vivado_arch("versalpremium"), Some(Family::Versal));
+        assert_eq!(Family::from_vivado_arch("versalaiedge2"), Some(Family::Versal));
+        assert_eq!(Family::from_vivado_arch("versalfuture"), None);
+        assert_eq!(Family::from_vivado_arch("spartan7"), Some(Family::Series7));
+        assert_eq!(Family::from_vivado_arch("nosuch"), None);
+        assert_eq!(Family::from_device_name("xcv1000"), Some(Family::Virtex));
+        assert_eq!(Family::from_device_name("xc7s50"), Some(Family::Series7));
+        assert_eq!(Family::from_device_name("xcku040"), Some(Family::Ultrascale));
+        assert_eq!(Family::from_device_name("xcvu9p"), Some(Family::UltrascalePlus));
+        assert_eq!(Family::from_device_name("xczu7ev"), Some(Family::UltrascalePlus));
+        assert_eq!(Family::from_device_name("xcvc1902"), Some(Family::Versal));
+        assert_eq!(Family::from_device_name("xcvh1582"), Some(Family::Versal));
+        assert_eq!(Family::from_device_name("xc2ve3858"), Some(Family::Versal));
+        assert_eq!(Family::from_device_name("xc2v40"), Some(Family::Virtex2));
+        assert_eq!(Family::from_device_name("xc7a35tcsg324"), None);
+    }
+}
@@ -0,0 +1,579 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{File, read_dir};
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use serde::{Serialize, Deserialize};
+use capnp::message::ReaderOptions;
+use capnp::serialize;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use crate::error::Error;
+use crate::stringpool::StringPool;
+use crate::toolcache::ToolRunner;
+use crate::toolchain::{Toolchain, ToolchainSet};
+use super::family::Family;
+use super::rawdump::{Part, Source, Coord, TkSitePinDir, TkPipInversion, TkPipDirection, TkWire, PkgPin, WireIdx};
+use super::rdbuild::PartBuilder;
+use super::source::DeviceSource;
+
+#[allow(non_snake_case, clippy::all)]
+pub mod LogicalNetlist_capnp {
+    include!(concat!(env!("OUT_DIR"), "/LogicalNetlist_capnp.rs"));
+}
+
+#[allow(non_snake_case, clippy::all)]
+pub mod References_capnp {
+    include!(concat!(env!("OUT_DIR"), "/References_capnp.rs"));
+}
+
+#[allow(non_snake_case, clippy::all)]
+pub mod DeviceResources_capnp {
+    include!(concat!(env!("OUT_DIR"), "/DeviceResources_capnp.rs"));
+}
+
+use DeviceResources_capnp::device;
+use LogicalNetlist_capnp::netlist::Direction;
+
+/// Reads a gzipped DeviceResources message, as written by RapidWright.
+/// Pseudo pips are left out, as in Vivado dumps, and nothing is known of
+/// speed models, banks or pin functions.
+pub fn read_device_resources<P: AsRef<Path>>(path: P) -> Result<Part, Error> {
+    let f = GzDecoder::new(BufReader::new(File::open(path)?));
+    let mut opts = ReaderOptions::new();
+    // Big devices have far more than the default 64Mi words.
+    opts.traversal_limit_in_words(None);
+    let msg = serialize::read_message(BufReader::new(f), opts)?;
+    let dev = msg.get_root::<device::Reader>()?;
+    let strs = dev.get_str_list()?;
+    let s = |idx: u32| strs.get(idx).map_err(Error::from);
+
+    let name = dev.get_name()?;
+    // The interchange files do not say what family a device is, so go by name.
+    let family = Family::from_device_name(name).ok_or_else(|| Error::ParseError(format!("unknown family of device {}", name)))?;
+    let tiles = dev.get_tile_list()?;
+    let mut width: u16 = 0;
+    let mut height: u16 = 0;
+    for t in tiles.iter() {
+        width = std::cmp::max(width, t.get_col() + 1);
+        height = std::cmp::max(height, t.get_row() + 1);
+    }
+    let mut rd = PartBuilder::new(name.to_string(), family, Source::Interchange, width, height);
+
+    let site_types = dev.get_site_type_list()?;
+    let tile_types = dev.get_tile_type_list()?;
+    for t in tiles.iter() {
+        let tt = tile_types.get(t.get_type());
+        let tt_wires = tt.get_wires()?;
+        let mut wires = Vec::new();
+        for w in tt_wires.iter() {
+            wires.push((s(w)?, None));
+        }
+        let mut pips = Vec::new();
+        for pip in tt.get_pips()?.iter() {
+            if let device::p_i_p::PseudoCells(_) = pip.which()? {
+                continue;
+            }
+            let wf = s(tt_wires.get(pip.get_wire0()))?;
+            let wt = s(tt_wires.get(pip.get_wire1()))?;
+            let is_buf = pip.get_buffered21();
+            if pip.get_directional() {
+                pips.push((wf, wt, is_buf, false, false, TkPipInversion::Never, TkPipDirection::Uni, None));
+            } else {
+                pips.push((wf, wt, is_buf, false, false, TkPipInversion::Never, TkPipDirection::BiFwd, None));
+                pips.push((wt, wf, is_buf, false, false, TkPipInversion::Never, TkPipDirection::BiBwd, None));
+            }
+        }
+        let tt_sites = tt.get_site_types()?;
+        let mut sites = Vec::new();
+        for site in t.get_sites()?.iter() {
+            let stt = tt_sites.get(site.get_type());
+            let st = site_types.get(stt.get_primary_type());
+            let pin_wires = stt.get_primary_pins_to_tile_wires()?;
+            let mut pins = Vec::new();
+            for (i, pin) in st.get_pins()?.iter().enumerate() {
+                let dir = match pin.get_dir()? {
+                    Direction::Input => TkSitePinDir::Input,
+                    Direction::Output => TkSitePinDir::Output,
+                    Direction::Inout => TkSitePinDir::Bidir,
+                };
+                let wire = s(pin_wires.get(i as u32))?;
+                pins.push((s(pin.get_name())?, dir, if wire.is_empty() { None } else { Some(wire) }, None));
+            }
+            sites.push((s(site.get_name())?, s(st.get_name())?, pins));
+        }
+        let coord = Coord {
+            x: t.get_col(),
+            y: height - 1 - t.get_row(),
+        };
+        rd.add_tile(coord, s(t.get_name())?.to_string(), s(tt.get_name())?.to_string(), &sites, &wires, &pips);
+    }
+
+    let all_wires = dev.get_wires()?;
+    for node in dev.get_nodes()?.iter() {
+        let mut wires = Vec::new();
+        for wi in node.get_wires()?.iter() {
+            let w = all_wires.get(wi);
+            wires.push((s(w.get_tile())?, s(w.get_wire())?, None));
+        }
+        if !wires.is_empty() {
+            rd.add_node(&wires);
+        }
+    }
+
+    for pkg in dev.get_packages()?.iter() {
+        let pkg_name = s(pkg.get_name())?;
+        let mut pins = Vec::new();
+        for pin in pkg.get_package_pins()?.iter() {
+            let pad = match pin.get_site()?.which()? {
+                device::optional_string_idx::NoValue(()) => None,
+                device::optional_string_idx::Value(idx) => Some(s(idx)?.to_string()),
+            };
+            pins.push(PkgPin {
+                pad,
+                pin: s(pin.get_package_pin())?.to_string(),
+                vref_bank: None,
+                vcco_bank: None,
+                func: String::new(),
+                tracelen_um: None,
+                delay_min_fs: None,
+                delay_max_fs: None,
+            });
+        }
+        rd.add_package(pkg_name.to_string(), pins);
+        for grade in pkg.get_grades()?.iter() {
+            let grade_name = s(grade.get_name())?;
+            rd.add_combo(
+                format!("{}{}{}", name, pkg_name, grade_name),
+                name.to_string(),
+                pkg_name.to_string(),
+                s(grade.get_speed_grade())?.to_string(),
+                s(grade.get_temperature_grade())?.to_string(),
+            );
+        }
+    }
+
+    Ok(rd.finish())
+}
+
+/// Writes a gzipped DeviceResources message for the part.  Sites get only
+/// their pins, each with a site port BEL; cell libraries, constants and
+/// timing are left empty.  Internal wires become single-wire nodes.
+pub fn write_device_resources<P: AsRef<Path>>(part: &Part, path: P) -> Result<(), Error> {
+    let mut sp = StringPool::new();
+    let mut msg = capnp::message::Builder::new_default();
+    let mut dev = msg.init_root::<device::Builder>();
+    dev.set_name(&part.part);
+
+    // Site types are global in interchange, but a site kind may have
+    // different pins in different tile kinds: use the union of all of them.
+    // A pin cannot have different directions, though.
+    let mut site_pins: BTreeMap<&str, BTreeMap<&str, TkSitePinDir>> = BTreeMap::new();
+    for tk in part.tile_kinds.values() {
+        for site in tk.sites.iter() {
+            let pins = site_pins.entry(site.kind.as_str()).or_default();
+            for (name, pin) in site.pins.iter() {
+                match pins.insert(name, pin.dir) {
+                    Some(dir) if dir != pin.dir => return Err(Error::ParseError(format!("site kind {} pin {} is both {:?} and {:?}", site.kind, name, dir, pin.dir))),
+                    _ => (),
+                }
+            }
+        }
+    }
+    let mut site_type_idx: HashMap<&str, u32> = HashMap::new();
+    // Site pin order within each site type; inputs come first.
+    let mut site_pin_idx: HashMap<&str, Vec<&str>> = HashMap::new();
+    {
+        let mut sts = dev.reborrow().init_site_type_list(site_pins.len() as u32);
+        for (i, (&kind, pins)) in site_pins.iter().enumerate() {
+            let mut order: Vec<_> = pins.iter().filter(|(_, &d)| d == TkSitePinDir::Input).map(|(&n, &d)| (n, d)).collect();
+            let num_inputs = order.len() as u32;
+            order.extend(pins.iter().filter(|(_, &d)| d != TkSitePinDir::Input).map(|(&n, &d)| (n, d)));
+            let mut st = sts.reborrow().get(i as u32);
+            st.set_name(sp.put(kind));
+            // All ones when there are no inputs at all.
+            st.set_last_input(num_inputs.wrapping_sub(1));
+            {
+                let mut bels = st.reborrow().init_bels(order.len() as u32);
+                for (j, &(name, _)) in order.iter().enumerate() {
+                    let mut bel = bels.reborrow().get(j as u32);
+                    bel.set_name(sp.put(name));
+                    bel.set_type(sp.put(name));
+                    bel.set_category(device::BELCategory::SitePort);
+                    bel.init_pins(1).set(0, j as u32);
+                }
+            }
+            {
+                let mut bel_pins = st.reborrow().init_bel_pins(order.len() as u32);
+                for (j, &(name, dir)) in order.iter().enumerate() {
+                    let mut bp = bel_pins.reborrow().get(j as u32);
+                    bp.set_name(sp.put(name));
+                    bp.set_bel(sp.put(name));
+                    // A port BEL drives the site from its input pin and
+                    // the other way around.
+                    bp.set_dir(match dir {
+                        TkSitePinDir::Input => Direction::Output,
+                        TkSitePinDir::Output => Direction::Input,
+                        TkSitePinDir::Bidir => Direction::Inout,
+                    });
+                }
+            }
+            {
+                let mut site_wires = st.reborrow().init_site_wires(order.len() as u32);
+                for (j, &(name, _)) in order.iter().enumerate() {
+                    let mut sw = site_wires.reborrow().get(j as u32);
+                    sw.set_name(sp.put(name));
+                    sw.init_pins(1).set(0, j as u32);
+                }
+            }
+            {
+                let mut st_pins = st.reborrow().init_pins(order.len() as u32);
+                for (j, &(name, dir)) in order.iter().enumerate() {
+                    let mut pin = st_pins.reborrow().get(j as u32);
+                    pin.set_name(sp.put(name));
+                    pin.set_dir(match dir {
+                        TkSitePinDir::Input => Direction::Input,
+                        TkSitePinDir::Output => Direction::Output,
+                        TkSitePinDir::Bidir => Direction::Inout,
+                    });
+                    pin.set_belpin(j as u32);
+                }
+            }
+            site_type_idx.insert(kind, i as u32);
+            site_pin_idx.insert(kind, order.into_iter().map(|(n, _)| n).collect());
+        }
+    }
+
+    let mut tk_names: Vec<&str> = part.tile_kinds.keys().map(|k| k as &str).collect();
+    tk_names.sort_unstable();
+    let mut tk_idx: HashMap<&str, u32> = HashMap::new();
+    let mut tk_wires: HashMap<&str, Vec<WireIdx>> = HashMap::new();
+    {
+        let mut tts = dev.reborrow().init_tile_type_list(tk_names.len() as u32);
+        for (i, &name) in tk_names.iter().enumerate() {
+            let tk = &part.tile_kinds[name];
+            let mut tt = tts.reborrow().get(i as u32);
+            tt.set_name(sp.put(name));
+            let mut wires: Vec<WireIdx> = tk.wires.keys().copied().collect();
+            wires.sort_unstable();
+            let wire_idx: HashMap<WireIdx, u32> = wires.iter().enumerate().map(|(j, &w)| (w, j as u32)).collect();
+            {
+                let mut tt_wires = tt.reborrow().init_wires(wires.len() as u32);
+                for (j, &w) in wires.iter().enumerate() {
+                    tt_wires.set(j as u32, sp.put(part.print_wire(w)));
+                }
+            }
+            // Bidirectional pips are stored both ways; emit them once.
+            let mut pips: Vec<_> = tk.pips.iter().filter(|(_, pip)| pip.direction != TkPipDirection::BiBwd).collect();
+            pips.sort_unstable_by_key(|&(&k, _)| k);
+            {
+                let mut tt_pips = tt.reborrow().init_pips(pips.len() as u32);
+                for (j, (&(wf, wt), pip)) in pips.into_iter().enumerate() {
+                    let mut p = tt_pips.reborrow().get(j as u32);
+                    p.set_wire0(wire_idx[&wf]);
+                    p.set_wire1(wire_idx[&wt]);
+                    p.set_directional(pip.direction == TkPipDirection::Uni);
+                    p.set_buffered21(pip.is_buf);
+                    p.set_buffered20(false);
+                    p.set_conventional(());
+                }
+            }
+            {
+                let mut tt_sites = tt.reborrow().init_site_types(tk.sites.len() as u32);
+                for (j, site) in tk.sites.iter().enumerate() {
+                    let mut stt = tt_sites.reborrow().get(j as u32);
+                    stt.set_primary_type(site_type_idx[&site.kind as &str]);
+                    let order = &site_pin_idx[&site.kind as &str];
+                    let mut pin_wires = stt.init_primary_pins_to_tile_wires(order.len() as u32);
+                    for (k, &pin) in order.iter().enumerate() {
+                        let wire = match site.pins.get(pin) {
+                            Some(p) if p.wire != WireIdx::NONE => part.print_wire(p.wire),
+                            _ => "",
+                        };
+                        pin_wires.set(k as u32, sp.put(wire));
+                    }
+                }
+            }
+            tk_idx.insert(name, i as u32);
+            tk_wires.insert(name, wires);
+        }
+    }
+
+    // Interchange rows go top to bottom.
+    let mut coords: Vec<Coord> = part.tiles.keys().copied().collect();
+    coords.sort_unstable_by_key(|c| (part.height - 1 - c.y, c.x));
+    let mut wire_list: Vec<(u32, u32)> = Vec::new();
+    let mut wire_map: HashMap<(Coord, WireIdx), u32> = HashMap::new();
+    let mut single_nodes: Vec<u32> = Vec::new();
+    {
+        let mut tiles = dev.reborrow().init_tile_list(coords.len() as u32);
+        for (i, &coord) in coords.iter().enumerate() {
+            let tile = &part.tiles[&coord];
+            let tk = &part.tile_kinds[&tile.kind];
+            let mut t = tiles.reborrow().get(i as u32);
+            let tname = sp.put(&tile.name);
+            t.set_name(tname);
+            t.set_type(tk_idx[&tile.kind as &str]);
+            t.set_row(part.height - 1 - coord.y);
+            t.set_col(coord.x);
+            let sites: Vec<_> = tile.sites.iter().enumerate().filter_map(|(j, s)| s.as_ref().map(|s| (j, s))).collect();
+            let mut t_sites = t.init_sites(sites.len() as u32);
+            for (j, (stt, name)) in sites.into_iter().enumerate() {
+                let mut site = t_sites.reborrow().get(j as u32);
+                site.set_name(sp.put(name));
+                site.set_type(stt as u32);
+            }
+            for &w in tk_wires[&tile.kind as &str].iter() {
+                if !tile.has_wire(tk, w) {
+                    continue;
+                }
+                let idx = wire_list.len() as u32;
+                wire_list.push((tname, sp.put(part.print_wire(w))));
+                wire_map.insert((coord, w), idx);
+                if let TkWire::Internal(_, _) = tk.wires[&w] {
+                    single_nodes.push(idx);
+                }
+            }
+        }
+    }
+    {
+        let mut wires = dev.reborrow().init_wires(wire_list.len() as u32);
+        for (i, &(tile, wire)) in wire_list.iter().enumerate() {
+            let mut w = wires.reborrow().get(i as u32);
+            w.set_tile(tile);
+            w.set_wire(wire);
+        }
+    }
+    {
+        let mut nodes = dev.reborrow().init_nodes((part.nodes.len() + single_nodes.len()) as u32);
+        for (i, node) in part.nodes.iter().enumerate() {
+            let template = &part.templates[node.template as usize];
+            let mut n_wires = nodes.reborrow().get(i as u32).init_wires(template.wires.len() as u32);
+            for (j, w) in template.wires.iter().enumerate() {
+                let coord = Coord {x: node.base.x + w.delta.x, y: node.base.y + w.delta.y};
+                n_wires.set(j as u32, wire_map[&(coord, w.wire)]);
+            }
+        }
+        for (i, &w) in single_nodes.iter().enumerate() {
+            nodes.reborrow().get((part.nodes.len() + i) as u32).init_wires(1).set(0, w);
+        }
+    }
+
+    let mut pkg_names: Vec<&str> = part.packages.keys().map(|k| k as &str).collect();
+    pkg_names.sort_unstable();
+    {
+        let mut pkgs = dev.reborrow().init_packages(pkg_names.len() as u32);
+        for (i, &name) in pkg_names.iter().enumerate() {
+            let mut pkg = pkgs.reborrow().get(i as u32);
+            pkg.set_name(sp.put(name));
+            let pins = &part.packages[name];
+            {
+                let mut pkg_pins = pkg.reborrow().init_package_pins(pins.len() as u32);
+                for (j, pin) in pins.iter().enumerate() {
+                    let mut p = pkg_pins.reborrow().get(j as u32);
+                    p.set_package_pin(sp.put(&pin.pin));
+                    match pin.pad {
+                        None => p.reborrow().init_site().set_no_value(()),
+                        Some(ref pad) => p.reborrow().init_site().set_value(sp.put(pad)),
+                    }
+                    p.init_bel().set_no_value(());
+                }
+            }
+            let combos: Vec<_> = part.combos.iter().filter(|c| c.package == name).collect();
+            let mut grades = pkg.init_grades(combos.len() as u32);
+            for (j, combo) in combos.into_iter().enumerate() {
+                let mut g = grades.reborrow().get(j as u32);
+                // Whatever the combo name has past device and package, so
+                // that read_device_resources gets the same name back.
+                let grade = combo.name.strip_prefix(&combo.device).and_then(|n| n.strip_prefix(name)).unwrap_or(&combo.speed);
+                g.set_name(sp.put(grade));
+                g.set_speed_grade(sp.put(&combo.speed));
+                g.set_temperature_grade(sp.put(&combo.temp));
+            }
+        }
+    }
+
+    {
+        let mut strs = dev.init_str_list(sp.len() as u32);
+        for i in 0..sp.len() {
+            strs.set(i as u32, sp.get(i as u32));
+        }
+    }
+
+    let mut f = GzEncoder::new(BufWriter::new(File::create(path)?), Compression::default());
+    serialize::write_message(&mut f, &msg)?;
+    f.finish()?;
+    Ok(())
+}
+
+/// A device file in a directory of DeviceResources messages.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterchangePart {
+    pub device: String,
+    pub family: Family,
+    pub file: String,
+}
+
+/// Devices from a directory of `<device>.device` files, as written by
+/// RapidWright.  No tools are run.
+pub struct InterchangeSource {
+    pub dir: PathBuf,
+}
+
+impl DeviceSource for InterchangeSource {
+    type Part = InterchangePart;
+
+    const META_KEY: &'static str = "interchange-parts";
+
+    /// Besides our family names, device names are accepted.  Files of
+    /// devices we cannot place in a family are reported and left out.
+    fn list_parts(&self, _: &ToolchainSet, families: &HashSet<String>) -> Result<Vec<InterchangePart>, Error> {
+        let mut res = Vec::new();
+        for entry in read_dir(&self.dir)? {
+            let file = match entry?.file_name().into_string() {
+                Ok(f) => f,
+                Err(_) => continue,
+            };
+            let device = match file.strip_suffix(".device") {
+                Some(d) => d.to_string(),
+                None => continue,
+            };
+            let family = match Family::from_device_name(&device) {
+                Some(f) => f,
+                None => {
+                    println!("skipping {} of unknown family", file);
+                    continue;
+                },
+            };
+            if families.contains(family.name()) || families.contains(&device) {
+                res.push(InterchangePart {device, family, file});
+            }
+        }
+        res.sort_by(|a, b| a.device.cmp(&b.device));
+        Ok(res)
+    }
+
+    fn device(part: &InterchangePart) -> &str {
+        &part.device
+    }
+
+    fn family(part: &InterchangePart) -> Family {
+        part.family
+    }
+
+    fn variant(part: &InterchangePart) -> &str {
+        &part.file
+    }
+
+    fn toolchain<'a>(&self, tcs: &'a ToolchainSet, _: &[InterchangePart]) -> Result<&'a Toolchain, Error> {
+        tcs.default()
+    }
+
+    fn dump_device(&self, _: &ToolRunner, parts: &[InterchangePart], _: Option<&Path>) -> Result<Part, Error> {
+        let part = &parts[0];
+        let rd = read_device_resources(self.dir.join(&part.file))?;
+        if rd.part != part.device {
+            return Err(Error::ParseError(format!("{} contains device {}", part.file, rd.part)));
+        }
+        Ok(rd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use tempdir::TempDir;
+    use crate::xilinx::family::Family;
+    use crate::xilinx::rawdump::{Part, Source, Coord, TkSitePinDir, TkPipInversion, TkPipDirection, PkgPin};
+    use crate::xilinx::rdbuild::PartBuilder;
+
+    // Whatever the interchange format keeps of a part, by name.
+    fn summary(part: &Part) -> BTreeSet<String> {
+        let mut res = BTreeSet::new();
+        for (coord, tile) in part.tiles.iter() {
+            let tk = &part.tile_kinds[&tile.kind];
+            res.insert(format!("TILE {} {} {} {}", coord.x, coord.y, tile.name, tile.kind));
+            for (site, name) in tk.sites.iter().zip(tile.sites.iter()) {
+                for (pin, sp) in site.pins.iter() {
+                    res.insert(format!("PIN {:?} {} {} {:?} {}", name, site.kind, pin, sp.dir, part.print_wire(sp.wire)));
+                }
+            }
+            for &w in tk.wires.keys() {
+                res.insert(format!("WIRE {} {}", tile.kind, part.print_wire(w)));
+            }
+            for (&(wf, wt), pip) in tk.pips.iter() {
+                res.insert(format!("PIP {} {} {} {} {:?}", tile.kind, part.print_wire(wf), part.print_wire(wt), pip.is_buf, pip.direction));
+            }
+        }
+        for node in part.nodes.iter() {
+            let template = &part.templates[node.template as usize];
+            if template.wires.len() < 2 {
+                continue;
+            }
+            let mut wires: Vec<_> = template.wires.iter().map(|w| {
+                let coord = Coord {x: node.base.x + w.delta.x, y: node.base.y + w.delta.y};
+                format!("{}/{}", part.tiles[&coord].name, part.print_wire(w.wire))
+            }).collect();
+            wires.sort();
+            res.insert(format!("NODE {}", wires.join(" ")));
+        }
+        res
+    }
+
+    #[test]
+    fn device_resources_test() {
+        let mut rd = PartBuilder::new("xc2v40".to_string(), Family::Virtex2, Source::ISE, 1, 2);
+        for y in 0..2 {
+            rd.add_tile(
+                Coord {x: 0, y},
+                format!("R{}C1", 2 - y),
+                "CENTER".to_string(),
+                &[(&format!("SLICE_X0Y{}", y)[..], "SLICE", vec![
+                    ("BX", TkSitePinDir::Input, Some("BX_PINWIRE0"), None),
+                    ("X", TkSitePinDir::Output, Some("X_PINWIRE0"), None),
+                ])],
+                &[("BX_PINWIRE0", None), ("X_PINWIRE0", None), ("N2BEG0", None), ("N2END0", None), ("LH0", None)],
+                &[
+                    ("N2END0", "BX_PINWIRE0", true, false, false, TkPipInversion::Never, TkPipDirection::Uni, None),
+                    ("X_PINWIRE0", "N2BEG0", true, false, false, TkPipInversion::Never, TkPipDirection::Uni, None),
+                    ("N2BEG0", "LH0", false, false, false, TkPipInversion::Never, TkPipDirection::BiFwd, None),
+                    ("LH0", "N2BEG0", false, false, false, TkPipInversion::Never, TkPipDirection::BiBwd, None),
+                ],
+            );
+        }
+        rd.add_node(&[("R2C1", "N2BEG0", None), ("R1C1", "N2END0", None)]);
+        rd.add_package("cs144".to_string(), vec![PkgPin {
+            pad: Some("SLICE_X0Y0".to_string()),
+            pin: "A1".to_string(),
+            vref_bank: None,
+            vcco_bank: None,
+            func: String::new(),
+            tracelen_um: None,
+            delay_min_fs: None,
+            delay_max_fs: None,
+        }]);
+        rd.add_combo("xc2v40cs144-4".to_string(), "xc2v40".to_string(), "cs144".to_string(), "-4".to_string(), "C".to_string());
+        let part = rd.finish();
+
+        let dir = TempDir::new("interchange").unwrap();
+        let path = dir.path().join("xc2v40.device");
+        super::write_device_resources(&part, &path).unwrap();
+        let back = super::read_device_resources(&path).unwrap();
+        assert_eq!(back.part, part.part);
+        assert_eq!(back.family, Family::Virtex2);
+        assert_eq!((back.width, back.height), (part.width, part.height));
+        assert_eq!(summary(&back), summary(&part));
+        assert_eq!(back.packages, part.packages);
+        assert_eq!(back.combos, part.combos);
+    }
+
+    #[test]
+    fn site_pin_conflict_test() {
+        let mut rd = PartBuilder::new("xc7a0t".to_string(), Family::Series7, Source::Vivado, 2, 1);
+        rd.add_tile(Coord {x: 0, y: 0}, "A_X0Y0".to_string(), "A".to_string(), &[("SLICE_X0Y0", "SLICEL", vec![("A", TkSitePinDir::Input, None, None)])], &[], &[]);
+        rd.add_tile(Coord {x: 1, y: 0}, "B_X1Y0".to_string(), "B".to_string(), &[("SLICE_X1Y0", "SLICEL", vec![("A", TkSitePinDir::Output, None, None)])], &[], &[]);
+        let part = rd.finish();
+        let dir = TempDir::new("interchange").unwrap();
+        assert!(super::write_device_resources(&part, dir.path().join("xc7a0t.device")).is_err());
+    }
+}
@@ -1,3 +1,5 @@
 pub mod xdlrc;
 pub mod rawdump;
 pub mod partgen;
+pub mod nodes;
+pub mod source;
@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use crate::stringpool::StringPool;
+use super::xdlrc::{Tile, Wire};
+
+const FREE: u32 = u32::MAX;
+
+struct Slot {
+    // Interned tile and wire name.
+    key: (u32, u32),
+    parent: u32,
+    // Next member of the same node, in a circular list.
+    next: u32,
+    seen: bool,
+    speed: Option<u32>,
+    // Only meaningful for roots.
+    size: u32,
+    unseen: u32,
+}
+
+/// Gathers xdlrc wires into nodes as tiles stream by.  Wires mentioned in
+/// a connection list are unioned into one node; a node is complete once
+/// every one of its wires has been seen in its own tile, at which point it
+/// is handed out and forgotten, so that only nodes still open take memory.
+pub struct Nodes {
+    slots: Vec<Slot>,
+    freelist: Vec<u32>,
+    wire2slot: HashMap<(u32, u32), u32>,
+    wires: Vec<(u32, u32, Option<u32>)>,
+}
+
+impl Nodes {
+    pub fn new() -> Self {
+        Nodes {
+            slots: Vec::new(),
+            freelist: Vec::new(),
+            wire2slot: HashMap::new(),
+            wires: Vec::new(),
+        }
+    }
+
+    fn slot(&mut self, key: (u32, u32)) -> u32 {
+        if let Some(&i) = self.wire2slot.get(&key) {
+            return i;
+        }
+        let i = match self.freelist.pop() {
+            Some(i) => i,
+            None => {
+                self.slots.push(Slot { key, parent: FREE, next: FREE, seen: false, speed: None, size: 0, unseen: 0 });
+                (self.slots.len() - 1) as u32
+            }
+        };
+        self.slots[i as usize] = Slot { key, parent: i, next: i, seen: false, speed: None, size: 1, unseen: 1 };
+        self.wire2slot.insert(key, i);
+        i
+    }
+
+    fn find(&mut self, mut i: u32) -> u32 {
+        while self.slots[i as usize].parent != i {
+            let p = self.slots[i as usize].parent;
+            let gp = self.slots[p as usize].parent;
+            self.slots[i as usize].parent = gp;
+            i = gp;
+        }
+        i
+    }
+
+    fn union(&mut self, a: u32, b: u32) -> u32 {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+        if a == b {
+            return a;
+        }
+        if self.slots[a as usize].size < self.slots[b as usize].size {
+            std::mem::swap(&mut a, &mut b);
+        }
+        let sb = &mut self.slots[b as usize];
+        let (size, unseen, nb) = (sb.size, sb.unseen, sb.next);
+        sb.parent = a;
+        let sa = &mut self.slots[a as usize];
+        sa.size += size;
+        sa.unseen += unseen;
+        // Splice the member lists.
+        let na = std::mem::replace(&mut sa.next, nb);
+        self.slots[b as usize].next = na;
+        a
+    }
+
+    // Hands out the node with the given root, and frees its slots.
+    fn finish_node(&mut self, sp: &StringPool, root: u32, f: &mut impl FnMut(&StringPool, &[(u32, u32, Option<u32>)])) {
+        self.wires.clear();
+        let mut i = root;
+        loop {
+            let slot = &mut self.slots[i as usize];
+            self.wires.push((slot.key.0, slot.key.1, slot.speed));
+            self.wire2slot.remove(&slot.key);
+            let next = slot.next;
+            slot.parent = FREE;
+            self.freelist.push(i);
+            i = next;
+            if i == root {
+                break;
+            }
+        }
+        f(sp, &self.wires);
+    }
+
+    /// Adds a wire with connections.  `f` is called with the (tile, wire,
+    /// speed) ids of the node, if this completes it.
+    pub fn process_wire(&mut self, sp: &mut StringPool, t: &Tile, w: &Wire, mut f: impl FnMut(&StringPool, &[(u32, u32, Option<u32>)])) {
+        let i = self.slot((sp.put(&t.name), sp.put(&w.name)));
+        let slot = &mut self.slots[i as usize];
+        if !slot.seen {
+            slot.seen = true;
+            slot.speed = w.speed.as_ref().map(|s| sp.put(s));
+            let root = self.find(i);
+            self.slots[root as usize].unseen -= 1;
+        }
+        let mut root = self.find(i);
+        for (ct, cw) in &w.conns {
+            let j = self.slot((sp.put(ct), sp.put(cw)));
+            root = self.union(root, j);
+        }
+        if self.slots[root as usize].unseen == 0 {
+            self.finish_node(sp, root, &mut f);
+        }
+    }
+
+    /// Hands out all nodes still open, ie. ones with wires never seen.
+    pub fn finish_all(&mut self, sp: &StringPool, mut f: impl FnMut(&StringPool, &[(u32, u32, Option<u32>)])) {
+        for i in 0..self.slots.len() as u32 {
+            if self.slots[i as usize].parent == i {
+                self.finish_node(sp, i, &mut f);
+            }
+        }
+    }
+}
+
+impl Default for Nodes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stringpool::StringPool;
+    use super::Nodes;
+    use super::super::xdlrc::{Tile, Wire};
+
+    fn tile(name: &str, wires: &[(&str, &[(&str, &str)])]) -> Tile {
+        Tile {
+            x: 0,
+            y: 0,
+            name: name.to_string(),
+            kind: "INT".to_string(),
+            prims: Vec::new(),
+            wires: wires.iter().map(|(n, conns)| Wire {
+                name: n.to_string(),
+                speed: if *n == "A" { Some("FAST".to_string()) } else { None },
+                conns: conns.iter().map(|(t, w)| (t.to_string(), w.to_string())).collect(),
+            }).collect(),
+            pips: Vec::new(),
+        }
+    }
+
+    fn node(sp: &StringPool, wires: &[(u32, u32, Option<u32>)]) -> Vec<String> {
+        let mut res: Vec<_> = wires.iter().map(|&(t, w, s)| {
+            let mut r = format!("{}.{}", sp.get(t), sp.get(w));
+            if let Some(s) = s {
+                r += &format!(":{}", sp.get(s));
+            }
+            r
+        }).collect();
+        res.sort();
+        res
+    }
+
+    #[test]
+    fn nodes_test() {
+        let tiles = [
+            tile("T1", &[("A", &[("T3", "C")]), ("E", &[]), ("G", &[("T2", "K")])]),
+            // B reaches A's node only through T3.
+            tile("T2", &[("B", &[("T3", "D")]), ("K", &[("T1", "G")])]),
+            tile("T3", &[("C", &[("T1", "A"), ("T3", "D")]), ("D", &[("T2", "B"), ("T3", "C")]), ("M", &[("T9", "Z")])]),
+        ];
+        let mut sp = StringPool::new();
+        let mut nodes = Nodes::new();
+        let mut got = Vec::new();
+        for t in &tiles {
+            let mut done = Vec::new();
+            for w in &t.wires {
+                nodes.process_wire(&mut sp, t, w, |sp, wires| done.push(node(sp, wires)));
+            }
+            got.push(done);
+        }
+        let mut done = Vec::new();
+        nodes.finish_all(&sp, |sp, wires| done.push(node(sp, wires)));
+        got.push(done);
+        assert_eq!(got, vec![
+            vec![vec!["T1.E"]],
+            // Closes as soon as both its wires are seen, before T3.
+            vec![vec!["T1.G", "T2.K"]],
+            vec![vec!["T1.A:FAST", "T2.B", "T3.C", "T3.D"]],
+            // T9.Z never shows up.
+            vec![vec!["T3.M", "T9.Z"]],
+        ]);
+        // Slots of finished nodes are reused.
+        assert!(nodes.slots.len() <= 8);
+    }
+}
@@ -1,14 +1,19 @@
 use std::fs::File;
+use std::path::Path;
 use std::io::{BufRead, BufReader};
 use std::process::Stdio;
 use crate::error::Error;
 use crate::xilinx::rawdump::PkgPin;
+use crate::xilinx::family::{Family, FAMILIES};
 use crate::toolchain::Toolchain;
+use crate::toolreader::ToolProcess;
+use crate::linereader::LineReader;
 use tempdir::TempDir;
+use serde::{Serialize, Deserialize};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PartgenPkg {
-    pub family: String,
+    pub family: Family,
     pub device: String,
     pub package: String,
     pub speedgrades: Vec<String>,
@@ -30,107 +35,125 @@ fn parse_bank(d: &str) -> Result<Option<u32>, Error> {
     Ok(Some(d.parse()?))
 }
 
-pub fn parse_pkgfile(f: &mut impl BufRead) -> Result<Vec<PkgPin>, Error> {
+/// Parses a partgen .pkg file; `source` names it in error messages.
+pub fn parse_pkgfile(f: &mut impl BufRead, source: &str) -> Result<Vec<PkgPin>, Error> {
     let mut res: Vec<PkgPin> = Vec::new();
-    for l in f.lines() {
+    let mut lines = LineReader::new(f, source);
+    while let Some(l) = lines.next() {
         let l = l?;
-        if l.starts_with("#") { continue; }
-        let l: Vec<_> = l.split_whitespace().collect();
+        parse_pkgline(&l, &mut res).map_err(|e| lines.locate(e))?;
+    }
+    Ok(res)
+}
 
-        match l[..] {
-            [typ, pad, pin, bank, func, _, _] => {
-                if typ != "pin" && typ != "pkgpin" { continue; }
-                res.push(PkgPin{
-                    pad: if typ == "pin" { Some(pad.to_string()) } else { None },
-                    pin: pin.to_string(),
-                    vref_bank: parse_bank(bank)?,
-                    vcco_bank: parse_bank(bank)?,
-                    func: func.to_string(),
-                    tracelen_um: None,
-                    delay_min_fs: None,
-                    delay_max_fs: None,
-                });
-            }
-            [typ, pad, pin, vref_bank, vcco_bank, func, _, _, tracelen] => {
-                if typ != "pin" && typ != "pkgpin" { continue; }
-                let tracelen: Option<u32> = if tracelen == "N.A." { None } else { Some(tracelen.parse()?) };
-                res.push(PkgPin{
-                    pad: if typ == "pin" { Some(pad.to_string()) } else { None },
-                    pin: pin.to_string(),
-                    vref_bank: parse_bank(vref_bank)?,
-                    vcco_bank: parse_bank(vcco_bank)?,
-                    func: func.to_string(),
-                    tracelen_um: tracelen,
-                    delay_min_fs: None,
-                    delay_max_fs: None,
-                });
-            }
-            [typ, pad, pin, vref_bank, vcco_bank, func, _, _, delay_min, delay_max] => {
-                if typ != "pin" && typ != "pkgpin" { continue; }
-                res.push(PkgPin{
-                    pad: if typ == "pin" { Some(pad.to_string()) } else { None },
-                    pin: pin.to_string(),
-                    vref_bank: parse_bank(vref_bank)?,
-                    vcco_bank: parse_bank(vcco_bank)?,
-                    func: func.to_string(),
-                    tracelen_um: None,
-                    delay_min_fs: parse_delay(delay_min)?,
-                    delay_max_fs: parse_delay(delay_max)?,
-                });
+fn parse_pkgline(l: &str, res: &mut Vec<PkgPin>) -> Result<(), Error> {
+    if l.starts_with("#") { return Ok(()); }
+    let l: Vec<_> = l.split_whitespace().collect();
+
+    match l[..] {
+        [typ, pad, pin, bank, func, _, _] => {
+            if typ != "pin" && typ != "pkgpin" { return Ok(()); }
+            res.push(PkgPin{
+                pad: if typ == "pin" { Some(pad.to_string()) } else { None },
+                pin: pin.to_string(),
+                vref_bank: parse_bank(bank)?,
+                vcco_bank: parse_bank(bank)?,
+                func: func.to_string(),
+                tracelen_um: None,
+                delay_min_fs: None,
+                delay_max_fs: None,
+            });
+        }
+        [typ, pad, pin, vref_bank, vcco_bank, func, _, _, tracelen] => {
+            if typ != "pin" && typ != "pkgpin" { return Ok(()); }
+            let tracelen: Option<u32> = if tracelen == "N.A." { None } else { Some(tracelen.parse()?) };
+            res.push(PkgPin{
+                pad: if typ == "pin" { Some(pad.to_string()) } else { None },
+                pin: pin.to_string(),
+                vref_bank: parse_bank(vref_bank)?,
+                vcco_bank: parse_bank(vcco_bank)?,
+                func: func.to_string(),
+                tracelen_um: tracelen,
+                delay_min_fs: None,
+                delay_max_fs: None,
+            });
+        }
+        [typ, pad, pin, vref_bank, vcco_bank, func, _, _, delay_min, delay_max] => {
+            if typ != "pin" && typ != "pkgpin" { return Ok(()); }
+            res.push(PkgPin{
+                pad: if typ == "pin" { Some(pad.to_string()) } else { None },
+                pin: pin.to_string(),
+                vref_bank: parse_bank(vref_bank)?,
+                vcco_bank: parse_bank(vcco_bank)?,
+                func: func.to_string(),
+                tracelen_um: None,
+                delay_min_fs: parse_delay(delay_min)?,
+                delay_max_fs: parse_delay(delay_max)?,
+            });
+        }
+        _ => ()
+    }
+    Ok(())
+}
+
+// Parses one part entry of partlist.xct, and the .pkg file it refers to.
+fn parse_partlist_entry(lines: &mut LineReader<BufReader<File>>, dir: &Path) -> Result<Option<PartgenPkg>, Error> {
+    let l = match lines.next() {
+        None => return Ok(None),
+        Some(l) => l?,
+    };
+    let mut sl: &str = &l;
+    let mut cont = false;
+    if let Some(x) = l.strip_suffix("\\") {
+        cont = true;
+        sl = x;
+    }
+    let words = sl.split_whitespace().collect::<Vec<_>>();
+    if words.len() < 4 {
+        return Err(Error::ParseError("first line too short".to_string()));
+    }
+    if !words[0].starts_with("part") {
+        return Err(Error::ParseError("does not start with part".to_string()));
+    }
+    let mut part = words[1].to_lowercase();
+    if !part.starts_with("x") {
+        part = format!("xc{}", part);
+    }
+    let (device, package, family) = match split_partname(&part) {
+        None => return Err(Error::ParseError(format!("cannot parse part name: {}", part))),
+        Some((d, p, f)) => (d.to_string(), p.to_string(), f),
+    };
+    let pkgname = format!("{}.pkg", words[1].to_lowercase());
+    let mut speedgrades: Vec<String> = Vec::new();
+    while cont {
+        let l = match lines.next() {
+            None => return Err(Error::ParseError("part definition cut off".to_string())),
+            Some(l) => l?,
+        };
+        let mut sl: &str = &l;
+        cont = false;
+        if let Some(x) = l.strip_suffix("\\") {
+            cont = true;
+            sl = x;
+        }
+        if let Some(x) = sl.strip_prefix("\tSPEEDGRADE=") {
+            let x: Vec<_> = x.split_whitespace().collect();
+            if x.is_empty() {
+                return Err(Error::ParseError("empty speedgrade".to_string()));
             }
-            _ => ()
+            speedgrades.push(x[0].to_string());
         }
     }
-    Ok(res)
+    let pfile = File::open(dir.join(&pkgname))?;
+    let mut pbufread = BufReader::new(pfile);
+    let pins = parse_pkgfile(&mut pbufread, &pkgname)?;
+    Ok(Some(PartgenPkg {family, device, package, speedgrades, pins}))
 }
 
 use regex::Regex;
 
-const PATTERNS: &[(&str, &str, &str)] = &[
-    ("x[ca]95[0-9]+(?:xl|xv)?",         "[a-z]{2}[0-9]+",       "xc9500"),
-    ("xcr3[0-9]+xl",                    "[a-z]{2}[0-9]+",       "xpla3"),
-    ("x[ca]2c[0-9]+a?",                 "[a-z]{2}g?[0-9]+",     "xbr"),
-
-    ("xc40[0-9]+[el]",                  "[a-z]{2}[0-9]+",       "xc4000e"),
-    ("xcs[0-9]+",                       "[a-z]{2}[0-9]+",       "xc4000e"),
-    ("xc40[0-9]+(?:xl|ex)",             "[a-z]{2}[0-9]+",       "xc4000ex"),
-    ("xc40[0-9]+xla",                   "[a-z]{2}[0-9]+",       "xc4000xla"),
-    ("xc40[0-9]+xv",                    "[a-z]{2}[0-9]+",       "xc4000xv"),
-    ("xcs[0-9]+xl",                     "[a-z]{2}[0-9]+",       "spartanxl"),
-
-    ("x(?:cv|qv|qvr|c2s)[0-9]+",        "[a-z]{2}[0-9]+",       "virtex"),
-    ("x(?:cv|qv|c2s|a2s)[0-9]+e",       "[a-z]{2}[0-9]+",       "virtexe"),
-
-    ("x(?:c|q|qr)2v[0-9]+",             "[a-z]{2}[0-9]+",       "virtex2"),
-    ("x[cq]2vpx?[0-9]+",                "[a-z]{2}[0-9]+",       "virtex2p"),
-
-    ("xc3s[0-9]+l?",                    "[a-z]{2}[0-9]+",       "spartan3"),
-    ("xa3s[0-9]+l?",                    "[a-z]{2}g[0-9]+",      "spartan3"),
-
-    ("xc3s[0-9]+e",                     "[a-z]{2}[0-9]+",       "spartan3e"),
-    ("xa3s[0-9]+e",                     "[a-z]{2}g[0-9]+",      "spartan3e"),
-
-    ("xc3s[0-9]+a",                     "[a-z]{2}[0-9]+",       "spartan3a"),
-    ("xc3s[0-9]+an",                    "[a-z]{2}g[0-9]+",      "spartan3a"),
-    ("xa3s[0-9]+a",                     "[a-z]{2}g[0-9]+",      "spartan3a"),
-
-    ("xc3sd[0-9]+a",                    "[a-z]{2}[0-9]+",       "spartan3adsp"),
-    ("xa3sd[0-9]+a",                    "[a-z]{2}g[0-9]+",      "spartan3adsp"),
-
-    ("x[cqa]6slx[0-9](?:[0-9]+t?|)l?",  "[a-z]{2}g?[0-9]+",     "spartan6"),
-
-    ("x(?:c|q|qr)4v[lsf]x[0-9]+",       "[a-z]{2}[0-9]+",       "virtex4"),
-    ("x[cq]5v[lsft]x[0-9]+t?",          "[a-z]{2}[0-9]+",       "virtex5"),
-    ("x[cq]6v[lshc]x[0-9]+t?l?",        "[a-z]{2}g?[0-9]+",     "virtex6"),
-
-    ("x[ca]7(?:[akvz]|v[xh])[0-9]+[st]?[li]?","[a-z]{2}[gv][0-9]+",  "7series"),
-    ("xq7(?:[akv]|v[xh])[0-9]+t?[li]?", "[a-z]{2}[0-9]+",       "7series"),
-    ("xq7z[0-9]+",                      "[a-z]{2}g?[0-9]+",     "7series"),
-
-];
-
-pub fn split_partname(s: &str) -> Option<(&str, &str, &str)> {
-    for (dpat, ppat, fam) in PATTERNS {
+pub fn split_partname(s: &str) -> Option<(&str, &str, Family)> {
+    for (fi, (dpat, ppat)) in FAMILIES.iter().flat_map(|fi| fi.part_patterns.iter().map(move |pat| (fi, pat))) {
         let re = Regex::new(&("^(".to_string() + dpat + ")(" + ppat + ")$")).unwrap();
         if let Some(cap) = re.captures(s) {
             let dev = cap.get(1).unwrap();
@@ -139,21 +162,12 @@ pub fn split_partname(s: &str) -> Option<(&str, &str, &str)> {
             assert!(dev.end() == pkg.start());
             assert!(pkg.end() == s.len());
             let m = dev.end();
-            return Some((&s[..m], &s[m..], fam));
+            return Some((&s[..m], &s[m..], fi.family));
         }
     }
     None
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn split_partname_test() {
-        assert_eq!(super::split_partname("xc6slx9tqg144"), Some(("xc6slx9", "tqg144", "spartan6")));
-        assert_eq!(super::split_partname("xq6slx75tcs484"), Some(("xq6slx75t", "cs484", "spartan6")));
-    }
-}
-
 pub fn get_pkgs(tc: &Toolchain, query: &str) -> Result<Vec<PartgenPkg>, Error> {
     let dir = TempDir::new("partgen-pkg")?;
     let mut cmd = tc.command("partgen");
@@ -162,67 +176,46 @@ pub fn get_pkgs(tc: &Toolchain, query: &str) -> Result<Vec<PartgenPkg>, Error> {
     cmd.stdout(Stdio::null());
     cmd.stderr(Stdio::null());
     cmd.arg("-v");
-    if query != "" {
+    if !query.is_empty() {
         cmd.arg(query);
     }
-    let status = cmd.status()?;
+    let status = ToolProcess::spawn("partgen", cmd, tc.timeout("partgen"), None)?.wait()?;
     if !status.success() {
-        return Err(Error::ParseError(format!("non-zero partgen exit status")));
+        return Err(Error::ParseError("non-zero partgen exit status".to_string()));
     }
     let file = File::open(dir.path().join("partlist.xct"))?;
-    let bufread = BufReader::new(file);
-    let mut lines = bufread.lines();
+    let mut lines = LineReader::new(BufReader::new(file), "partlist.xct");
     let mut res: Vec<PartgenPkg> = Vec::new();
     loop {
-        let l = match lines.next() {
-            None => break,
-            Some(l) => l?,
-        };
-        let mut sl: &str = &l;
-        let mut cont = false;
-        if let Some(x) = l.strip_suffix("\\") {
-            cont = true;
-            sl = x;
-        }
-        let words = sl.split_whitespace().collect::<Vec<_>>();
-        if words.len() < 4 {
-            return Err(Error::ParseError(format!("first line too short: {}", l)));
+        match parse_partlist_entry(&mut lines, dir.path()) {
+            Ok(Some(pkg)) => res.push(pkg),
+            Ok(None) => break,
+            Err(e) => return Err(lines.locate(e)),
         }
-        if !words[0].starts_with("part") {
-            return Err(Error::ParseError(format!("does not start with part: {}", l)));
-        }
-        let mut part = words[1].to_lowercase();
-        if !part.starts_with("x") {
-            part = format!("xc{}", part);
-        }
-        let (device, package, family) = match split_partname(&part) {
-            None => return Err(Error::ParseError(format!("cannot parse part name: {}", part))),
-            Some((d, p, f)) => (d.to_string(), p.to_string(), f.to_string()),
-        };
-        let mut speedgrades: Vec<String> = Vec::new();
-        while cont {
-            let l = match lines.next() {
-                None => return Err(Error::ParseError(format!("part definition cut off"))),
-                Some(l) => l?,
-            };
-            let mut sl: &str = &l;
-            cont = false;
-            if let Some(x) = l.strip_suffix("\\") {
-                cont = true;
-                sl = x;
-            }
-            if let Some(x) = sl.strip_prefix("\tSPEEDGRADE=") {
-                let x: Vec<_> = x.split_whitespace().collect();
-                if x.len() == 0 {
-                    return Err(Error::ParseError(format!("empty speedgrade")));
-                }
-                speedgrades.push(x[0].to_string());
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xilinx::family::Family;
+
+    #[test]
+    fn split_partname_test() {
+        assert_eq!(super::split_partname("xc6slx9tqg144"), Some(("xc6slx9", "tqg144", Family::Spartan6)));
+        assert_eq!(super::split_partname("xq6slx75tcs484"), Some(("xq6slx75t", "cs484", Family::Spartan6)));
+    }
+
+    #[test]
+    fn parse_pkgfile_error_test() {
+        let data = b"# comment\npin P1 A1 0 IO_L1P_0 0 N.A.\npin P2 A2 zero IO_L1N_0 0 N.A.\n";
+        match super::parse_pkgfile(&mut &data[..], "test.pkg") {
+            Err(crate::error::Error::ParseErrorAt { source, line, text, .. }) => {
+                assert_eq!(source, "test.pkg");
+                assert_eq!(line, 3);
+                assert_eq!(text, "pin P2 A2 zero IO_L1N_0 0 N.A.");
             }
+            x => panic!("unexpected {:?}", x),
         }
-        let pfile = File::open(dir.path().join(format!("{}.pkg", words[1].to_lowercase())))?;
-        let mut pbufread = BufReader::new(pfile);
-        let pins = parse_pkgfile(&mut pbufread)?;
-        res.push(PartgenPkg {family, device, package, speedgrades, pins});
     }
-    Ok(res)
 }
@@ -1,25 +1,23 @@
 use std::collections::{HashSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
 use crate::xilinx::rawdump::{Part, Source, Coord, TkPipInversion, TkPipDirection};
 use crate::xilinx::rdbuild::PartBuilder;
-use crate::toolchain::Toolchain;
+use crate::toolcache::ToolRunner;
 use crate::error::Error;
 use crate::stringpool::StringPool;
-use super::xdlrc::{Parser, Options, PipKind, Tile, Wire};
+use super::xdlrc::{Parser, Options, PipKind};
 use super::partgen::PartgenPkg;
+use super::nodes::Nodes;
 
 fn is_buf_speed(speed: &Option<String>) -> bool {
     match speed {
         None => false,
-        Some(s) => if s.starts_with("B_") {
-            true
-        } else if s.starts_with("BSW_") {
+        Some(s) => if s.starts_with("B_") || s.starts_with("BSW_") {
             true
         } else if s.starts_with("R_") {
             false
-        } else if s.starts_with("D_") {
-            // umm.
-            false
-        } else if s.starts_with("CCMA1D8_") {
+        } else if s.starts_with("D_") || s.starts_with("CCMA1D8_") {
             // umm.
             false
         } else {
@@ -28,170 +26,66 @@ fn is_buf_speed(speed: &Option<String>) -> bool {
     }
 }
 
-struct NodeInfo {
-    unseen: HashSet<(u32, u32)>,
-    seen: HashMap<(u32, u32), Option<u32>>,
+fn add_node(rd: &mut PartBuilder, sp: &StringPool, wires: &[(u32, u32, Option<u32>)]) {
+    rd.add_node(&wires.iter().map(|&(t, w, s)| (sp.get(t), sp.get(w), s.map(|s| sp.get(s)))).collect::<Vec<_>>());
 }
 
-struct Nodes {
-    nodes: Vec<NodeInfo>,
-    freelist: Vec<usize>,
-    wire2node: HashMap<(u32, u32), usize>,
-}
+// (tile kind, wire from, wire to), interned.
+type PipKey = (u32, u32, u32);
 
-impl Nodes {
-    fn finish_node(&mut self, rd: &mut PartBuilder, sp: &mut StringPool, idx: usize) {
-        let node = &mut self.nodes[idx];
-        if node.seen.is_empty() && node.unseen.is_empty() {
-            return;
-        }
-        let mut wires: Vec<(&str, &str, Option<&str>)> = Vec::new();
-        for (t, w) in node.unseen.iter() {
-            wires.push((sp.get(*t), sp.get(*w), None));
-            self.wire2node.remove(&(*t, *w));
-        }
-        for ((t, w), s) in node.seen.iter() {
-            wires.push((sp.get(*t), sp.get(*w), match *s {
-                None => None,
-                Some(s) => Some(sp.get(s)),
-            }));
-            self.wire2node.remove(&(*t, *w));
-        }
-        rd.add_node(&wires);
-        self.free_node(idx);
-    }
-    fn finish_all(&mut self, rd: &mut PartBuilder, sp: &mut StringPool) {
-        for nidx in 0..self.nodes.len() {
-            self.finish_node(rd, sp, nidx);
-        }
-    }
-    fn free_node(&mut self, idx: usize) {
-        let node = &mut self.nodes[idx];
-        node.seen.clear();
-        node.unseen.clear();
-        self.freelist.push(idx);
-    }
-    fn process_wire(&mut self, rd: &mut PartBuilder, sp: &mut StringPool, t: &Tile, w: &Wire) {
-        let mut wnodes: HashSet<usize> = HashSet::new();
-        let mut wwires: HashSet<(u32, u32)> = HashSet::new();
-        wwires.insert((sp.put(&t.name), sp.put(&w.name)));
-        for (t, w) in &w.conns {
-            wwires.insert((sp.put(t), sp.put(w)));
-        }
-        for k in wwires.iter() {
-            if let Some(i) = self.wire2node.get(k) {
-                wnodes.insert(*i);
-            }
-        }
-        let mut wnodes : Vec<_> = wnodes.into_iter().collect();
-        let mut nseen: HashMap<(u32, u32), Option<u32>> = HashMap::new();
-        nseen.insert((sp.put(&t.name), sp.put(&w.name)), match &w.speed {
-            None => None,
-            Some(s) => Some(sp.put(s)),
-        });
-        let nidx = match wnodes.pop() {
-            None => match self.freelist.pop() {
-                Some(i) => i,
-                None => {
-                    let i = self.nodes.len();
-                    self.nodes.push(NodeInfo { seen: HashMap::new(), unseen: HashSet::new() });
-                    i
-                }
-            },
-            Some(i) => {
-                for oi in wnodes {
-                    let node = &mut self.nodes[oi];
-                    for w in node.unseen.iter() {
-                        wwires.insert(*w);
-                    }
-                    for (w, s) in node.seen.iter() {
-                        nseen.insert(*w, *s);
-                    }
-                    self.free_node(oi);
-                }
-                i
-            }
-        };
-        let node = &mut self.nodes[nidx];
-        for k in wwires.iter() {
-            if !node.seen.contains_key(k) && !nseen.contains_key(k) {
-                self.wire2node.insert(*k, nidx);
-                node.unseen.insert(*k);
-            }
-        }
-        for (k, v) in nseen.iter() {
-            if node.unseen.contains(k) {
-                node.unseen.remove(k);
-            }
-            self.wire2node.insert(*k, nidx);
-            node.seen.insert(*k, *v);
+// Lists the pips of every tile kind, as seen with the given test/excluded
+// pip filtering.  Gives None if stopped by `abort`.
+fn flag_pass(tr: &ToolRunner, key: &str, part: &str, dump_test: bool, dump_excluded: bool, abort: &AtomicBool) -> Result<Option<(StringPool, HashSet<PipKey>)>, Error> {
+    let mut sp = StringPool::new();
+    let mut pips = HashSet::new();
+    let mut parser = Parser::from_runner(tr, key, Options {
+        part: part.to_string(),
+        need_pips: true,
+        need_conns: false,
+        dump_test,
+        dump_excluded,
+    })?;
+    while let Some(t) = parser.get_tile()? {
+        if abort.load(Ordering::Relaxed) {
+            return Ok(None);
         }
-        if node.unseen.is_empty() {
-            self.finish_node(rd, sp, nidx);
+        for p in t.pips {
+            pips.insert((sp.put(&t.kind), sp.put(&p.wire_from), sp.put(&p.wire_to)));
         }
     }
+    Ok(Some((sp, pips)))
 }
 
-
-pub fn get_rawdump(tc: &Toolchain, pkgs: &[PartgenPkg]) -> Result<Part, Error> {
-    let part = &pkgs[0];
-    let device = &part.device;
-    let partname = part.device.clone() + &part.package;
+// Adds all tiles and nodes, with the excluded and test flags of pips left
+// unset.  Also gives the pips added, to set the flags on later.  Gives None
+// if stopped by `abort`.
+fn main_pass(tr: &ToolRunner, part: &PartgenPkg, partname: &str, abort: &AtomicBool) -> Result<Option<(PartBuilder, StringPool, HashSet<PipKey>)>, Error> {
     let pinmap: HashMap<String, String> = part.pins.iter()
-        .filter_map(|pin| match &pin.pad {
-            None => None,
-            Some(pad) => Some((pin.pin.to_string(), pad.to_string())),
-        })
+        .filter_map(|pin| pin.pad.as_ref().map(|pad| (pin.pin.to_string(), pad.to_string())))
         .collect();
 
     let mut sp = StringPool::new();
+    let mut tile_pips: HashSet<PipKey> = HashSet::new();
 
-    let mut pips_non_test: HashSet<(u32, u32, u32)> = HashSet::new();
-    {
-        let mut parser = Parser::from_toolchain(tc, Options {
-            part: partname.clone(),
-            need_pips: true,
-            need_conns: false,
-            dump_test: false,
-            dump_excluded: true,
-        })?;
-        while let Some(t) = parser.get_tile()? {
-            for pip in t.pips {
-                pips_non_test.insert((sp.put(&t.kind), sp.put(&pip.wire_from), sp.put(&pip.wire_to)));
-            }
-        }
-    }
-    let mut pips_non_excl: HashSet<(u32, u32, u32)> = HashSet::new();
-    {
-        let mut parser = Parser::from_toolchain(tc, Options {
-            part: partname.clone(),
-            need_pips: true,
-            need_conns: false,
-            dump_test: true,
-            dump_excluded: false,
-        })?;
-        while let Some(t) = parser.get_tile()? {
-            for pip in t.pips {
-                pips_non_excl.insert((sp.put(&t.kind), sp.put(&pip.wire_from), sp.put(&pip.wire_to)));
-            }
-        }
-    }
-    let mut parser = Parser::from_toolchain(tc, Options {
-        part: partname,
+    let mut parser = Parser::from_runner(tr, "xdlrc", Options {
+        part: partname.to_string(),
         need_pips: true,
         need_conns: true,
         dump_test: true,
         dump_excluded: true,
     })?;
-    let mut rd = PartBuilder::new(part.device.clone(), part.family.clone(), Source::ISE, parser.width() as u16, parser.height() as u16);
+    let mut rd = PartBuilder::new(part.device.clone(), part.family, Source::ISE, parser.width() as u16, parser.height() as u16);
 
-    let mut nodes = Nodes {
-        nodes: Vec::new(),
-        freelist: Vec::new(),
-        wire2node: HashMap::new(),
-    };
+    let mut nodes = Nodes::new();
 
     while let Some(t) = parser.get_tile()? {
+        if abort.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+        let pips: Vec<_> = t.pips.iter().filter(|p| p.route_through.is_none() && p.wire_from != "SWBOX_STUB" && p.wire_to != "SWBOX_STUB").collect();
+        for p in pips.iter() {
+            tile_pips.insert((sp.put(&t.kind), sp.put(&p.wire_from), sp.put(&p.wire_to)));
+        }
         rd.add_tile(
             Coord{x: t.x as u16, y: (parser.height()-t.y-1) as u16},
             t.name.clone(),
@@ -208,12 +102,9 @@ pub fn get_rawdump(tc: &Toolchain, pkgs: &[PartgenPkg]) -> Result<Part, Error> {
             )).collect::<Vec<_>>(),
             &t.wires.iter().filter(|w| w.name != "SWBOX_STUB").map(|w| (
                 &w.name[..],
-                match &w.speed {
-                    None => None,
-                    Some(s) => Some(&s[..]),
-                }
+                w.speed.as_deref()
             )).collect::<Vec<_>>(),
-            &t.pips.iter().filter(|p| p.route_through.is_none() && p.wire_from != "SWBOX_STUB" && p.wire_to != "SWBOX_STUB").map(|p| (
+            &pips.iter().map(|p| (
                 &p.wire_from[..],
                 &p.wire_to[..],
                 match p.kind {
@@ -222,8 +113,8 @@ pub fn get_rawdump(tc: &Toolchain, pkgs: &[PartgenPkg]) -> Result<Part, Error> {
                     PipKind::BiPass => false,
                     PipKind::Uni => is_buf_speed(&p.speed),
                 },
-                !pips_non_excl.contains(&(sp.put(&t.kind), sp.put(&p.wire_from), sp.put(&p.wire_to))),
-                !pips_non_test.contains(&(sp.put(&t.kind), sp.put(&p.wire_from), sp.put(&p.wire_to))),
+                false,
+                false,
                 TkPipInversion::Never,
                 match p.kind {
                     PipKind::BiBuf => TkPipDirection::BiFwd,
@@ -231,10 +122,7 @@ pub fn get_rawdump(tc: &Toolchain, pkgs: &[PartgenPkg]) -> Result<Part, Error> {
                     PipKind::BiPass => TkPipDirection::BiFwd,
                     PipKind::Uni => TkPipDirection::Uni,
                 },
-                match &p.speed {
-                    None => None,
-                    Some(s) => Some(&s[..]),
-                }
+                p.speed.as_deref()
             )).collect::<Vec<_>>(),
         );
         for w in t.wires.iter() {
@@ -245,17 +133,61 @@ pub fn get_rawdump(tc: &Toolchain, pkgs: &[PartgenPkg]) -> Result<Part, Error> {
                 rd.add_node(&[(
                     &t.name,
                     &w.name,
-                    match &w.speed {
-                        None => None,
-                        Some(s) => Some(&s),
-                    }
+                    w.speed.as_deref(),
                 )]);
             } else {
-                nodes.process_wire(&mut rd, &mut sp, &t, w);
+                nodes.process_wire(&mut sp, &t, w, |sp, wires| add_node(&mut rd, sp, wires));
             }
         }
     }
-    nodes.finish_all(&mut rd, &mut sp);
+    nodes.finish_all(&sp, |sp, wires| add_node(&mut rd, sp, wires));
+    Ok(Some((rd, sp, tile_pips)))
+}
+
+pub fn get_rawdump(tr: &ToolRunner, pkgs: &[PartgenPkg]) -> Result<Part, Error> {
+    let part = &pkgs[0];
+    let device = &part.device;
+    let partname = part.device.clone() + &part.package;
+
+    // All three passes run at once.  A pip's flags depend on all tiles of
+    // its kind, so they are only set once every pass is done.  Should one
+    // pass fail, the others are stopped.
+    let abort = AtomicBool::new(false);
+    let (main, non_test, non_excl) = thread::scope(|s| {
+        let spawn_flag_pass = |key: &'static str, dump_test: bool, dump_excluded: bool| {
+            let (abort, partname) = (&abort, &partname);
+            s.spawn(move || {
+                let res = flag_pass(tr, key, partname, dump_test, dump_excluded, abort);
+                if res.is_err() {
+                    abort.store(true, Ordering::Relaxed);
+                }
+                res
+            })
+        };
+        let non_test = spawn_flag_pass("xdlrc-nontest", false, true);
+        let non_excl = spawn_flag_pass("xdlrc-nonexcl", true, false);
+        let main = main_pass(tr, part, &partname, &abort);
+        if main.is_err() {
+            abort.store(true, Ordering::Relaxed);
+        }
+        let join = |h: thread::ScopedJoinHandle<'_, _>, key: &str| {
+            h.join().unwrap_or_else(|_| Err(Error::ToolError(format!("{} pass panicked", key))))
+        };
+        (main, join(non_test, "xdlrc-nontest"), join(non_excl, "xdlrc-nonexcl"))
+    });
+    let ((mut rd, mut sp, tile_pips), non_test, non_excl) = match (main?, non_test?, non_excl?) {
+        (Some(main), Some(non_test), Some(non_excl)) => (main, non_test, non_excl),
+        _ => return Err(Error::ToolError("xdlrc pass stopped".to_string())),
+    };
+    let mut intern = |(psp, pips): (StringPool, HashSet<PipKey>)| -> HashSet<PipKey> {
+        pips.into_iter().map(|(k, wf, wt)| (sp.put(psp.get(k)), sp.put(psp.get(wf)), sp.put(psp.get(wt)))).collect()
+    };
+    let pips_non_test = intern(non_test);
+    let pips_non_excl = intern(non_excl);
+    for &(k, wf, wt) in tile_pips.iter() {
+        rd.set_pip_flags(sp.get(k), sp.get(wf), sp.get(wt), !pips_non_excl.contains(&(k, wf, wt)), !pips_non_test.contains(&(k, wf, wt)));
+    }
+
     for pkg in pkgs {
         assert!(pkg.device == *device);
         rd.add_package(pkg.package.clone(), pkg.pins.clone());
@@ -265,3 +197,56 @@ pub fn get_rawdump(tc: &Toolchain, pkgs: &[PartgenPkg]) -> Result<Part, Error> {
     }
     Ok(rd.finish())
 }
+
+#[cfg(test)]
+mod tests {
+    use tempdir::TempDir;
+    use crate::toolcache::{ToolCache, ToolRunner};
+    use crate::xilinx::family::Family;
+    use crate::xilinx::ise::partgen::PartgenPkg;
+
+    fn xdlrc(pips: &[&str]) -> String {
+        let mut res = "# test\n(xdl_resource_report v0.2 xc2v40cs144 virtex2\n(tiles 1 2\n".to_string();
+        for x in 0..2 {
+            res += &format!("\t(tile 0 {} R1C{} CENTER 0\n", x, x + 1);
+            for w in ["A", "B", "C", "D"].iter() {
+                res += &format!("\t\t(wire {} 0)\n", w);
+            }
+            for p in pips {
+                res += &format!("\t\t(pip R1C{} {} -> B)\n", x + 1, p);
+            }
+            res += "\t)\n";
+        }
+        res + ")\n"
+    }
+
+    #[test]
+    fn pip_flags_test() {
+        let dir = TempDir::new("ise-rawdump").unwrap();
+        let cache = ToolCache::new(dir.path());
+        cache.store("xdlrc", xdlrc(&["A", "C", "D"]).as_bytes()).unwrap();
+        cache.store("xdlrc-nontest", xdlrc(&["A", "D"]).as_bytes()).unwrap();
+        let pkg = PartgenPkg {
+            family: Family::Virtex2,
+            device: "xc2v40".to_string(),
+            package: "cs144".to_string(),
+            speedgrades: vec!["-4".to_string()],
+            pins: Vec::new(),
+        };
+        let tr = ToolRunner::Replay(cache.clone());
+        // One of the passes is missing, and takes the others down with it.
+        assert!(super::get_rawdump(&tr, std::slice::from_ref(&pkg)).is_err());
+        cache.store("xdlrc-nonexcl", xdlrc(&["C", "D"]).as_bytes()).unwrap();
+        let rd = super::get_rawdump(&tr, &[pkg]).unwrap();
+        let tk = &rd.tile_kinds["CENTER"];
+        let wire = |n: &str| rd.all_wires().find(|&w| rd.print_wire(w) == n).unwrap();
+        let flags = |wf: &str| {
+            let pip = &tk.pips[&(wire(wf), wire("B"))];
+            (pip.is_excluded, pip.is_test)
+        };
+        assert_eq!(flags("A"), (true, false));
+        assert_eq!(flags("C"), (false, true));
+        assert_eq!(flags("D"), (false, false));
+        assert_eq!(tk.tiles.len(), 2);
+    }
+}
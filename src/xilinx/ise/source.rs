@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+use std::path::Path;
+use rayon::prelude::*;
+use crate::error::Error;
+use crate::toolcache::ToolRunner;
+use crate::toolchain::ToolchainSet;
+use crate::xilinx::family::Family;
+use crate::xilinx::rawdump::Part;
+use crate::xilinx::source::DeviceSource;
+use super::partgen::{get_pkgs, PartgenPkg};
+use super::rawdump::get_rawdump;
+
+/// Parts as known to ISE partgen, dumped through xdlrc.
+pub struct IseSource;
+
+impl DeviceSource for IseSource {
+    type Part = PartgenPkg;
+
+    const META_KEY: &'static str = "ise-pkgs";
+
+    fn list_parts(&self, tcs: &ToolchainSet, families: &HashSet<String>) -> Result<Vec<PartgenPkg>, Error> {
+        let mut queries: Vec<(Family, &'static str)> = Vec::new();
+        let mut families: Vec<_> = families.iter().collect();
+        families.sort();
+        for family in families {
+            let fi = match Family::from_name(family) {
+                Some(f) if !f.info().ise_families.is_empty() => f.info(),
+                _ => return Err(Error::ToolError(format!("unknown ISE family {}", family))),
+            };
+            queries.extend(fi.ise_families.iter().map(|ise_fam| (fi.family, *ise_fam)));
+        }
+        for (_, ise_fam) in queries.iter() {
+            println!("querying {}", ise_fam);
+        }
+        let pkg_list: Vec<_> = queries.into_par_iter().map(|(family, ise_fam)| get_pkgs(tcs.for_family(family.name())?, ise_fam)).collect();
+        let mut res = Vec::new();
+        for pkgs in pkg_list {
+            res.extend(pkgs?);
+        }
+        Ok(res)
+    }
+
+    fn device(part: &PartgenPkg) -> &str {
+        &part.device
+    }
+
+    fn family(part: &PartgenPkg) -> Family {
+        part.family
+    }
+
+    fn variant(part: &PartgenPkg) -> &str {
+        &part.package
+    }
+
+    fn dump_device(&self, tr: &ToolRunner, parts: &[PartgenPkg], _: Option<&Path>) -> Result<Part, Error> {
+        get_rawdump(tr, parts)
+    }
+}
@@ -1,9 +1,10 @@
-use std::io::{BufRead, Lines};
+use std::io::BufRead;
 use std::str::FromStr;
 use crate::xilinx::rawdump::TkSitePinDir;
 use crate::error::Error;
-use crate::toolreader::ToolchainReader;
 use crate::toolchain::Toolchain;
+use crate::toolcache::ToolRunner;
+use crate::linereader::LineReader;
 
 use Error::ParseError;
 
@@ -77,7 +78,7 @@ pub struct Parser {
     family: String,
     width: u32,
     height: u32,
-    lines: Lines<Box<dyn BufRead>>,
+    lines: LineReader<Box<dyn BufRead>>,
     tiles_done: bool,
 }
 
@@ -103,13 +104,29 @@ impl FromStr for PipKind {
 }
 
 impl Parser {
-    pub fn new(file: Box<dyn BufRead>) -> Result<Self, Error> {
-        let mut lines = file.lines();
+    /// Parses the header; `source` names the input in error messages.
+    pub fn new(file: Box<dyn BufRead>, source: &str) -> Result<Self, Error> {
+        let mut lines = LineReader::new(file, source);
+        match Parser::parse_header(&mut lines) {
+            Ok((version, part, family, width, height)) => Ok(Parser {
+                version,
+                part,
+                family,
+                width,
+                height,
+                lines,
+                tiles_done: false,
+            }),
+            Err(e) => Err(lines.locate(e)),
+        }
+    }
+
+    fn parse_header(lines: &mut LineReader<Box<dyn BufRead>>) -> Result<(String, String, String, u32, u32), Error> {
         // Comments.
         let l = loop {
             let l = lines
                 .next()
-                .ok_or(ParseError(format!("eof before xdl_resource_report")))??;
+                .ok_or(ParseError("eof before xdl_resource_report".to_string()))??;
             if !l.starts_with("#") {
                 break l;
             }
@@ -117,18 +134,18 @@ impl Parser {
         // xdl_resource_report.
         let l: Vec<_> = l
             .strip_prefix("(xdl_resource_report ")
-            .ok_or(ParseError(format!("expected xdl_resource_report")))?
+            .ok_or(ParseError("expected xdl_resource_report".to_string()))?
             .split(" ")
             .collect();
         let (version, part, family) = match l[..] {
             [v, p, f] => (v.to_string(), p.to_string(), f.to_string()),
-            _ => return Err(ParseError(format!("xdl_resource_report wrong arg count"))),
+            _ => return Err(ParseError("xdl_resource_report wrong arg count".to_string())),
         };
         // More comments.
         let l = loop {
             let l = lines
                 .next()
-                .ok_or(ParseError(format!("eof before xdl_resource_report")))??;
+                .ok_or(ParseError("eof before xdl_resource_report".to_string()))??;
             if !l.starts_with("#") {
                 break l;
             }
@@ -136,32 +153,27 @@ impl Parser {
         // tiles.
         let l: Vec<_> = l
             .strip_prefix("(tiles ")
-            .ok_or(ParseError(format!("expected tiles")))?
+            .ok_or(ParseError("expected tiles".to_string()))?
             .split(" ")
             .collect();
         let (width, height) = match l[..] {
             [h, w] => (w.parse::<u32>()?, h.parse::<u32>()?),
-            _ => return Err(ParseError(format!("tiles wrong arg count"))),
+            _ => return Err(ParseError("tiles wrong arg count".to_string())),
         };
-        // Make the actual parser.
-        Ok(Parser {
-            version,
-            part,
-            family,
-            width,
-            height,
-            lines,
-            tiles_done: false,
-        })
+        Ok((version, part, family, width, height))
     }
 
     pub fn from_toolchain(tc: &Toolchain, opt: Options) -> Result<Self, Error> {
+        Parser::from_runner(&ToolRunner::Live(tc), "xdlrc", opt)
+    }
+
+    pub fn from_runner(tr: &ToolRunner, key: &str, opt: Options) -> Result<Self, Error> {
         let mut args = vec!["-report"];
         let mut env: Vec<(&'static str, &'static str)> = Vec::new();
         if opt.need_pips {
             args.push("-pips");
         }
-        if opt.need_conns && !tc.use_wine {
+        if opt.need_conns {
             args.push("-all_conns");
             args.push("-speed");
         }
@@ -172,18 +184,22 @@ impl Parser {
             env.push(("XIL_DRM_EXCLUDE_ARCS", "1"));
         }
         args.push(&opt.part);
-        args.push("fifo.xdlrc");
-        Parser::new(Box::new(ToolchainReader::new(tc, "xdl", &args, &env, "fifo.xdlrc", &[])?))
+        args.push("out.xdlrc");
+        Parser::new(tr.run(key, "xdl", &args, &env, "out.xdlrc", &[])?, &format!("{} {}", key, opt.part))
     }
 
     pub fn get_tile(&mut self) -> Result<Option<Tile>, Error> {
+        self.parse_tile().map_err(|e| self.lines.locate(e))
+    }
+
+    fn parse_tile(&mut self) -> Result<Option<Tile>, Error> {
         if self.tiles_done {
             return Ok(None);
         }
         let l = self
             .lines
             .next()
-            .ok_or(ParseError(format!("eof in tiles")))??;
+            .ok_or(ParseError("eof in tiles".to_string()))??;
         if let Some(l) = l.strip_prefix("\t(tile ") {
             // Parse tile.
             let l: Vec<_> = l.split(" ").collect();
@@ -194,7 +210,7 @@ impl Parser {
                     name.to_string(),
                     kind.to_string(),
                 ),
-                _ => return Err(ParseError(format!("tile wrong arg count"))),
+                _ => return Err(ParseError("tile wrong arg count".to_string())),
             };
 
             let mut prims: Vec<Prim> = Vec::new();
@@ -205,7 +221,7 @@ impl Parser {
                 let l = self
                     .lines
                     .next()
-                    .ok_or(ParseError(format!("eof in tile")))??;
+                    .ok_or(ParseError("eof in tile".to_string()))??;
                 if l == "\t)" {
                     break;
                 } else if let Some(l) = l.strip_prefix("\t\t(primitive_site ") {
@@ -219,13 +235,13 @@ impl Parser {
                             let l = self
                                 .lines
                                 .next()
-                                .ok_or(ParseError(format!("eof in primitive_site")))??;
+                                .ok_or(ParseError("eof in primitive_site".to_string()))??;
                             if l == "\t\t)" {
                                 break;
                             } else if let Some(l) = l.strip_prefix("\t\t\t(pinwire ") {
                                 let l = l
                                     .strip_suffix(")")
-                                    .ok_or(ParseError(format!("missing ) on pinwire")))?;
+                                    .ok_or(ParseError("missing ) on pinwire".to_string()))?;
                                 let l: Vec<_> = l.split(" ").collect();
                                 match l[..] {
                                     [n, k, w] => pinwires.push(PinWire {
@@ -244,14 +260,11 @@ impl Parser {
                                         wire: w.to_string(),
                                     }),
                                     _ => {
-                                        return Err(ParseError(format!("pinwire wrong arg count")))
+                                        return Err(ParseError("pinwire wrong arg count".to_string()))
                                     }
                                 }
                             } else {
-                                return Err(ParseError(format!(
-                                    "expected primitive_site item: {}",
-                                    l
-                                )));
+                                return Err(ParseError("expected primitive_site item".to_string()));
                             }
                         }
                     }
@@ -281,7 +294,7 @@ impl Parser {
                                 }
                             },
                         ),
-                        _ => return Err(ParseError(format!("primitive_site wrong arg count"))),
+                        _ => return Err(ParseError("primitive_site wrong arg count".to_string())),
                     };
                     prims.push(Prim {
                         name,
@@ -300,22 +313,22 @@ impl Parser {
                             let l = self
                                 .lines
                                 .next()
-                                .ok_or(ParseError(format!("eof in wire")))??;
+                                .ok_or(ParseError("eof in wire".to_string()))??;
                             if l == "\t\t)" {
                                 break;
                             } else if let Some(l) = l.strip_prefix("\t\t\t(conn ") {
                                 let l = l
                                     .strip_suffix(")")
-                                    .ok_or(ParseError(format!("missing ) on conn")))?;
+                                    .ok_or(ParseError("missing ) on conn".to_string()))?;
                                 let l: Vec<_> = l.split(" ").collect();
                                 match l[..] {
                                     [tile, wire] => {
                                         conns.push((tile.to_string(), wire.to_string()))
                                     }
-                                    _ => return Err(ParseError(format!("conn wrong arg count"))),
+                                    _ => return Err(ParseError("conn wrong arg count".to_string())),
                                 }
                             } else {
-                                return Err(ParseError(format!("expected wire item: {}", l)));
+                                return Err(ParseError("expected wire item".to_string()));
                             }
                         }
                     }
@@ -323,26 +336,26 @@ impl Parser {
                     let (name, speed) = match l[..] {
                         [name, _] => (name.to_string(), None),
                         [name, _, speed] => (name.to_string(), Some(speed.to_string())),
-                        _ => return Err(ParseError(format!("wire wrong arg count"))),
+                        _ => return Err(ParseError("wire wrong arg count".to_string())),
                     };
                     wires.push(Wire { name, speed, conns });
                 } else if let Some(l) = l.strip_prefix("\t\t(pip ") {
                     let l = l
                         .strip_suffix(")")
-                        .ok_or(ParseError(format!("missing ) on pip")))?;
+                        .ok_or(ParseError("missing ) on pip".to_string()))?;
                     let (l, rt) = match l.strip_suffix(")") {
                         Some(l) => {
                             let sl: Vec<_> = l.split(" (_ROUTETHROUGH-").collect();
                             if sl.len() != 2 {
-                                return Err(ParseError(format!("not routethru pip: {:?}", l)));
+                                return Err(ParseError("not routethru pip".to_string()));
                             }
                             let sl1: Vec<_> = sl[1].split(" ").collect();
                             if sl1.len() != 2 {
-                                return Err(ParseError(format!("not routethru pip: {:?}", l)));
+                                return Err(ParseError("not routethru pip".to_string()));
                             }
                             let sl10: Vec<_> = sl1[0].split("-").collect();
                             if sl10.len() != 2 {
-                                return Err(ParseError(format!("not routethru pip: {:?}", l)));
+                                return Err(ParseError("not routethru pip".to_string()));
                             }
                             (
                                 sl[0],
@@ -371,12 +384,12 @@ impl Parser {
                             speed: None,
                             route_through: rt,
                         }),
-                        _ => return Err(ParseError(format!("pip wrong arg count: {:?}", l))),
+                        _ => return Err(ParseError("pip wrong arg count".to_string())),
                     }
                 } else if l.starts_with("\t\t(tile_summary") && l.ends_with(")") {
                     // eh.
                 } else {
-                    return Err(ParseError(format!("expected tile item: {}", l)));
+                    return Err(ParseError("expected tile item".to_string()));
                 }
             }
 
@@ -395,7 +408,7 @@ impl Parser {
             self.tiles_done = true;
             Ok(None)
         } else {
-            Err(ParseError(format!("expected tile: {}", l)))
+            Err(ParseError("expected tile".to_string()))
         }
     }
 
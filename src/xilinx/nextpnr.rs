@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use super::rawdump::{Part, Coord, SpeedIdx, TkPipInversion, TkPipDirection, TkSitePinDir, TkSiteSlot, WireIdx};
+
+// The chip database, in nextpnr's bba assembler format.  Every array is
+// a u32 count followed by a ref; strings are str refs.  Wires are indices
+// into the global wire name table, so that node templates do not depend on
+// the tile types they cross; tile types list their wires sorted, and pips
+// and site pins point into that list.
+//
+//   ChipInfo { str name, family; u32 width, height;
+//              [str] wire_names; [str] speeds;
+//              [TileType] tile_types; [Tile] tiles;
+//              [NodeTemplate] templates; [Node] nodes; [Package] packages; }
+//   TileType { str name; [u32] wires; [Pip] pips; [Bel] bels; }
+//   Pip { u32 wire_from, wire_to, speed; u16 flags; u16 pad; }
+//   Bel { str slot, kind; [BelPin] pins; }  -- one per site
+//   BelPin { str name; u32 wire; u32 dir; }
+//   Tile { u16 x, y; u32 tile_type; str name; [str] sites; }
+//   NodeTemplate { [TemplateWire] wires; }
+//   TemplateWire { u16 dx, dy; u32 wire; }  -- from the node base
+//   Node { u16 x, y; u32 template; }
+//   Package { str name; [PackagePin] pins; }
+//   PackagePin { str pin, pad, func; }
+//
+// Missing wires and speeds are all ones; unknown speeds are 0xfffffffe.
+// Pip flags: bit 0 buffered, 1 excluded, 2 test, 3-4 inversion (never,
+// always, programmable), 5-6 direction (uni, bidi forward, bidi backward).
+// Bel pin dir is 0 input, 1 output, 2 bidirectional.  Missing sites of
+// a tile have empty names.
+
+struct Bba<'a, W: Write> {
+    f: &'a mut W,
+}
+
+impl<'a, W: Write> Bba<'a, W> {
+    fn label(&mut self, l: &str) -> io::Result<()> {
+        writeln!(self.f, "align")?;
+        writeln!(self.f, "label {}", l)
+    }
+    fn arr(&mut self, n: usize, l: &str) -> io::Result<()> {
+        writeln!(self.f, "u32 {}", n)?;
+        if n == 0 {
+            writeln!(self.f, "u32 0")
+        } else {
+            writeln!(self.f, "ref {}", l)
+        }
+    }
+    // Strings are delimited by `|`, with no escapes.
+    fn s(&mut self, s: &str) -> io::Result<()> {
+        if s.contains('|') || s.contains('\n') {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("cannot write {:?} as a bba string", s)));
+        }
+        writeln!(self.f, "str |{}|", s)
+    }
+    fn u16(&mut self, v: u16) -> io::Result<()> {
+        writeln!(self.f, "u16 {}", v)
+    }
+    fn u32(&mut self, v: u32) -> io::Result<()> {
+        writeln!(self.f, "u32 {}", v)
+    }
+}
+
+fn pip_flags(is_buf: bool, is_excluded: bool, is_test: bool, inversion: TkPipInversion, direction: TkPipDirection) -> u16 {
+    let mut res = 0;
+    if is_buf {
+        res |= 1;
+    }
+    if is_excluded {
+        res |= 2;
+    }
+    if is_test {
+        res |= 4;
+    }
+    res |= match inversion {
+        TkPipInversion::Never => 0,
+        TkPipInversion::Always => 1,
+        TkPipInversion::Prog => 2,
+    } << 3;
+    res |= match direction {
+        TkPipDirection::Uni => 0,
+        TkPipDirection::BiFwd => 1,
+        TkPipDirection::BiBwd => 2,
+    } << 5;
+    res
+}
+
+fn wire_id(w: WireIdx) -> u32 {
+    match w.unpack() {
+        Some(idx) => idx as u32,
+        None => u32::MAX,
+    }
+}
+
+/// Writes the part as a nextpnr bba chip database, in the `chipdb_blob`
+/// section.
+pub fn write_bba(part: &Part, f: &mut impl Write) -> io::Result<()> {
+    let speed_ids: HashMap<&str, u32> = part.speeds.iter().enumerate().map(|(i, s)| (s as &str, i as u32)).collect();
+    let speed_id = |s: SpeedIdx| -> u32 {
+        if s == SpeedIdx::NONE {
+            u32::MAX
+        } else if s == SpeedIdx::UNKNOWN {
+            u32::MAX - 1
+        } else {
+            speed_ids[part.print_speed(s)]
+        }
+    };
+    let mut kinds: Vec<&str> = part.tile_kinds.keys().map(|k| k as &str).collect();
+    kinds.sort_unstable();
+    let kind_idx: HashMap<&str, u32> = kinds.iter().enumerate().map(|(i, &k)| (k, i as u32)).collect();
+    let mut coords: Vec<Coord> = part.tiles.keys().copied().collect();
+    coords.sort_unstable_by_key(|c| (c.y, c.x));
+    let mut pkgs: Vec<&str> = part.packages.keys().map(|k| k as &str).collect();
+    pkgs.sort_unstable();
+
+    let mut b = Bba { f };
+    writeln!(b.f, "push chipdb_blob")?;
+
+    b.label("wire_names")?;
+    for w in part.wires.iter() {
+        b.s(w)?;
+    }
+    b.label("speeds")?;
+    for s in part.speeds.iter() {
+        b.s(s)?;
+    }
+
+    for (i, &kind) in kinds.iter().enumerate() {
+        let tk = &part.tile_kinds[kind];
+        let mut wires: Vec<WireIdx> = tk.wires.keys().copied().collect();
+        wires.sort_unstable();
+        let wire_idx: HashMap<WireIdx, u32> = wires.iter().enumerate().map(|(j, &w)| (w, j as u32)).collect();
+        let local = |w: WireIdx| -> u32 {
+            match wire_idx.get(&w) {
+                Some(&idx) => idx,
+                None => u32::MAX,
+            }
+        };
+        b.label(&format!("tt{}_wires", i))?;
+        for &w in wires.iter() {
+            b.u32(wire_id(w))?;
+        }
+        let mut pips: Vec<_> = tk.pips.iter().collect();
+        pips.sort_unstable_by_key(|&(&k, _)| k);
+        b.label(&format!("tt{}_pips", i))?;
+        for (&(wf, wt), pip) in pips {
+            b.u32(local(wf))?;
+            b.u32(local(wt))?;
+            b.u32(speed_id(pip.speed))?;
+            b.u16(pip_flags(pip.is_buf, pip.is_excluded, pip.is_test, pip.inversion, pip.direction))?;
+            b.u16(0)?;
+        }
+        for (j, site) in tk.sites.iter().enumerate() {
+            let mut pins: Vec<_> = site.pins.iter().collect();
+            pins.sort_unstable_by_key(|&(n, _)| n);
+            b.label(&format!("tt{}_bel{}_pins", i, j))?;
+            for (name, pin) in pins {
+                b.s(name)?;
+                b.u32(local(pin.wire))?;
+                b.u32(match pin.dir {
+                    TkSitePinDir::Input => 0,
+                    TkSitePinDir::Output => 1,
+                    TkSitePinDir::Bidir => 2,
+                })?;
+            }
+        }
+        b.label(&format!("tt{}_bels", i))?;
+        for (j, site) in tk.sites.iter().enumerate() {
+            b.s(part.print_slot_kind(match site.slot {
+                TkSiteSlot::Single(sk) => sk,
+                TkSiteSlot::Indexed(sk, _) => sk,
+                TkSiteSlot::Xy(sk, _, _) => sk,
+            }))?;
+            b.s(&site.kind)?;
+            b.arr(site.pins.len(), &format!("tt{}_bel{}_pins", i, j))?;
+        }
+    }
+    b.label("tile_types")?;
+    for (i, &kind) in kinds.iter().enumerate() {
+        let tk = &part.tile_kinds[kind];
+        b.s(kind)?;
+        b.arr(tk.wires.len(), &format!("tt{}_wires", i))?;
+        b.arr(tk.pips.len(), &format!("tt{}_pips", i))?;
+        b.arr(tk.sites.len(), &format!("tt{}_bels", i))?;
+    }
+
+    for (i, c) in coords.iter().enumerate() {
+        let tile = &part.tiles[c];
+        if tile.sites.is_empty() {
+            continue;
+        }
+        b.label(&format!("tile{}_sites", i))?;
+        for s in tile.sites.iter() {
+            b.s(match s {
+                Some(s) => s,
+                None => "",
+            })?;
+        }
+    }
+    b.label("tiles")?;
+    for (i, c) in coords.iter().enumerate() {
+        let tile = &part.tiles[c];
+        b.u16(c.x)?;
+        b.u16(c.y)?;
+        b.u32(kind_idx[&tile.kind as &str])?;
+        b.s(&tile.name)?;
+        b.arr(tile.sites.len(), &format!("tile{}_sites", i))?;
+    }
+
+    for (i, t) in part.templates.iter().enumerate() {
+        b.label(&format!("tmpl{}_wires", i))?;
+        for w in t.wires.iter() {
+            b.u16(w.delta.x)?;
+            b.u16(w.delta.y)?;
+            b.u32(wire_id(w.wire))?;
+        }
+    }
+    b.label("templates")?;
+    for (i, t) in part.templates.iter().enumerate() {
+        b.arr(t.wires.len(), &format!("tmpl{}_wires", i))?;
+    }
+    b.label("nodes")?;
+    for n in part.nodes.iter() {
+        b.u16(n.base.x)?;
+        b.u16(n.base.y)?;
+        b.u32(n.template)?;
+    }
+
+    for (i, &pkg) in pkgs.iter().enumerate() {
+        b.label(&format!("pkg{}_pins", i))?;
+        for pin in part.packages[pkg].iter() {
+            b.s(&pin.pin)?;
+            b.s(match pin.pad {
+                Some(ref pad) => pad,
+                None => "",
+            })?;
+            b.s(&pin.func)?;
+        }
+    }
+    b.label("packages")?;
+    for (i, &pkg) in pkgs.iter().enumerate() {
+        b.s(pkg)?;
+        b.arr(part.packages[pkg].len(), &format!("pkg{}_pins", i))?;
+    }
+
+    b.label("chip_info")?;
+    b.s(&part.part)?;
+    b.s(part.family.name())?;
+    b.u32(part.width as u32)?;
+    b.u32(part.height as u32)?;
+    b.arr(part.wires.len(), "wire_names")?;
+    b.arr(part.speeds.len(), "speeds")?;
+    b.arr(kinds.len(), "tile_types")?;
+    b.arr(coords.len(), "tiles")?;
+    b.arr(part.templates.len(), "templates")?;
+    b.arr(part.nodes.len(), "nodes")?;
+    b.arr(pkgs.len(), "packages")?;
+    writeln!(b.f, "pop")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::xilinx::family::Family;
+    use crate::xilinx::rawdump::{Coord, Source, TkSitePinDir, TkPipInversion, TkPipDirection};
+    use crate::xilinx::rdbuild::PartBuilder;
+
+    #[test]
+    fn bba_test() {
+        let mut rd = PartBuilder::new("xc7a0t".to_string(), Family::Series7, Source::Vivado, 1, 3);
+        for y in 0..2 {
+            rd.add_tile(Coord {x: 0, y}, format!("CLB_X0Y{}", y), "CLB".to_string(),
+                &[(&format!("SLICE_X0Y{}", y)[..], "SLICEL", vec![("A", TkSitePinDir::Input, Some("IMUX"), None)])],
+                &[("IMUX", None), ("N", None), ("S", None)],
+                &[("S", "IMUX", true, false, false, TkPipInversion::Never, TkPipDirection::Uni, Some("fast"))]);
+        }
+        rd.add_tile(Coord {x: 0, y: 2}, "NULL_X0Y2".to_string(), "NULL".to_string(), &[], &[], &[]);
+        rd.add_node(&[("CLB_X0Y0", "N", None), ("CLB_X0Y1", "S", None)]);
+        let part = rd.finish();
+        let mut buf = Vec::new();
+        super::write_bba(&part, &mut buf).unwrap();
+        let bba = String::from_utf8(buf).unwrap();
+        let labels: HashSet<&str> = bba.lines().filter_map(|l| l.strip_prefix("label ")).collect();
+        for r in bba.lines().filter_map(|l| l.strip_prefix("ref ")) {
+            assert!(labels.contains(r), "no label {}", r);
+        }
+        assert!(labels.contains("chip_info"));
+
+        let mut rd = PartBuilder::new("xc7a0t".to_string(), Family::Series7, Source::Vivado, 1, 1);
+        rd.add_tile(Coord {x: 0, y: 0}, "CLB|X0Y0".to_string(), "CLB".to_string(), &[], &[], &[]);
+        assert!(super::write_bba(&rd.finish(), &mut Vec::new()).is_err());
+    }
+}
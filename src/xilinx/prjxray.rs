@@ -0,0 +1,122 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use serde::Serialize;
+use super::rawdump::{Part, Coord, WireIdx};
+
+// Project X-Ray counts grid rows from the top.
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+pub struct TileGridEntry {
+    pub grid_x: u16,
+    pub grid_y: u16,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub sites: BTreeMap<String, String>,
+    pub pin_functions: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize)]
+pub struct TileConn {
+    pub grid_deltas: [i32; 2],
+    pub tile_types: [String; 2],
+    pub wire_pairs: Vec<[String; 2]>,
+}
+
+fn grid_y(part: &Part, c: Coord) -> u16 {
+    part.height - 1 - c.y
+}
+
+/// Makes a tilegrid.json equivalent, without the bits.  Pin functions are
+/// taken from whichever package bonds the pad.
+pub fn tilegrid(part: &Part) -> BTreeMap<String, TileGridEntry> {
+    let mut pkg_names: Vec<&String> = part.packages.keys().collect();
+    pkg_names.sort();
+    let mut funcs: BTreeMap<&str, &str> = BTreeMap::new();
+    for pkg in pkg_names {
+        for pin in part.packages[pkg].iter() {
+            if let Some(ref pad) = pin.pad {
+                if !pin.func.is_empty() {
+                    funcs.entry(pad).or_insert(&pin.func);
+                }
+            }
+        }
+    }
+    let mut res = BTreeMap::new();
+    for (&coord, tile) in part.tiles.iter() {
+        let tk = &part.tile_kinds[&tile.kind];
+        let mut sites = BTreeMap::new();
+        let mut pin_functions = BTreeMap::new();
+        for (site, name) in tk.sites.iter().zip(tile.sites.iter()) {
+            if let Some(name) = name {
+                sites.insert(name.clone(), site.kind.clone());
+                if let Some(func) = funcs.get(name as &str) {
+                    pin_functions.insert(name.clone(), func.to_string());
+                }
+            }
+        }
+        res.insert(tile.name.clone(), TileGridEntry {
+            grid_x: coord.x,
+            grid_y: grid_y(part, coord),
+            kind: tile.kind.clone(),
+            sites,
+            pin_functions,
+        });
+    }
+    res
+}
+
+/// Makes tileconn.json equivalent rules: every pair of wires of a node that
+/// lie in different tiles gives a rule for that pair of tile kinds at that
+/// distance.  Pairs are ordered so that each rule appears once.
+pub fn tileconn(part: &Part) -> Vec<TileConn> {
+    let mut seen: HashSet<(u32, Vec<&str>)> = HashSet::new();
+    let mut rules = BTreeMap::new();
+    for node in part.nodes.iter() {
+        let template = &part.templates[node.template as usize];
+        let wires: Vec<_> = template.wires.iter().map(|w| {
+            let coord = Coord {x: node.base.x + w.delta.x, y: node.base.y + w.delta.y};
+            (coord, &part.tiles[&coord].kind as &str, w.wire)
+        }).collect();
+        // Nodes of one template over the same tile kinds give the same rules.
+        if !seen.insert((node.template, wires.iter().map(|&(_, k, _)| k).collect())) {
+            continue;
+        }
+        for (i, &a) in wires.iter().enumerate() {
+            for &b in wires[i+1..].iter() {
+                if a.0 == b.0 {
+                    continue;
+                }
+                let (a, b) = if (a.1, a.2) <= (b.1, b.2) { (a, b) } else { (b, a) };
+                let dx = b.0.x as i32 - a.0.x as i32;
+                let dy = grid_y(part, b.0) as i32 - grid_y(part, a.0) as i32;
+                rules.entry((a.1, b.1, dx, dy)).or_insert_with(BTreeSet::new).insert((a.2, b.2));
+            }
+        }
+    }
+    rules.into_iter().map(|((ka, kb, dx, dy), pairs)| TileConn {
+        grid_deltas: [dx, dy],
+        tile_types: [ka.to_string(), kb.to_string()],
+        wire_pairs: pairs.into_iter().map(|(wa, wb): (WireIdx, WireIdx)| [part.print_wire(wa).to_string(), part.print_wire(wb).to_string()]).collect(),
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xilinx::family::Family;
+    use crate::xilinx::rawdump::{Coord, Source};
+    use crate::xilinx::rdbuild::PartBuilder;
+
+    #[test]
+    fn tileconn_test() {
+        let mut rd = PartBuilder::new("xc7a0t".to_string(), Family::Series7, Source::Vivado, 1, 2);
+        rd.add_tile(Coord {x: 0, y: 0}, "INT_X0Y0".to_string(), "INT".to_string(), &[], &[("NN2BEG0", None), ("NN2END0", None)], &[]);
+        rd.add_tile(Coord {x: 0, y: 1}, "INT_X0Y1".to_string(), "INT".to_string(), &[], &[("NN2BEG0", None), ("NN2END0", None)], &[]);
+        rd.add_node(&[("INT_X0Y0", "NN2BEG0", None), ("INT_X0Y1", "NN2END0", None)]);
+        let part = rd.finish();
+        let tc = super::tileconn(&part);
+        assert_eq!(tc.len(), 1);
+        assert_eq!(tc[0].grid_deltas, [0, -1]);
+        assert_eq!(tc[0].tile_types, ["INT".to_string(), "INT".to_string()]);
+        assert_eq!(tc[0].wire_pairs, vec![["NN2BEG0".to_string(), "NN2END0".to_string()]]);
+        assert_eq!(super::tilegrid(&part)["INT_X0Y1"].grid_y, 0);
+    }
+}
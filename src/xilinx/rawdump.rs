@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs::File;
+use std::io::{Read, Write};
 use std::path::Path;
 use crate::error::Error;
+use super::family::Family;
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Serialize, Deserialize)]
@@ -81,6 +83,7 @@ impl NodeOrClass {
 pub enum Source {
     ISE,
     Vivado,
+    Interchange,
 }
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Serialize, Deserialize)]
@@ -201,10 +204,166 @@ pub struct PkgPin {
     pub delay_max_fs: Option<u32>,
 }
 
+/// The Versal blocks that are not PL fabric.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Serialize, Deserialize)]
+pub enum VersalSiteKind {
+    NocSwitch,
+    NocNmu,
+    NocNsu,
+    NocOther,
+    AieCore,
+    AieMemory,
+    AieShim,
+    Cpm,
+    Ps,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+pub struct VersalSite {
+    pub name: String,
+    pub kind: VersalSiteKind,
+    pub tile: Coord,
+    /// Column and row within the AI Engine array, for AIE sites.
+    pub aie_pos: Option<(u16, u16)>,
+}
+
+/// A NoC site output pin reaching another NoC site's input pin, either by
+/// sharing a node with it or through the pips of NoC tiles.  Sites are
+/// indices into `VersalInfo::sites`.
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+pub struct VersalNocLink {
+    pub from_site: usize,
+    pub from_pin: String,
+    pub to_site: usize,
+    pub to_pin: String,
+    /// The pips the link goes through, in order.
+    pub pips: Vec<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct VersalInfo {
+    pub sites: Vec<VersalSite>,
+    pub noc_links: Vec<VersalNocLink>,
+}
+
+/// The layout of the AI Engine array.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AieArray {
+    /// The lowest column and row of the array.
+    pub origin: (u16, u16),
+    pub columns: u16,
+    /// The kinds of AIE sites in each row, from the bottom (shim) row up.
+    pub rows: Vec<Vec<VersalSiteKind>>,
+    /// Positions within the array that have no AIE site at all.
+    pub holes: Vec<(u16, u16)>,
+}
+
+impl VersalInfo {
+    /// Works out the AI Engine array from the positions of the AIE sites,
+    /// if there are any.
+    pub fn aie_array(&self) -> Option<AieArray> {
+        let mut kinds: BTreeMap<(u16, u16), BTreeSet<VersalSiteKind>> = BTreeMap::new();
+        for site in self.sites.iter() {
+            if let Some(pos) = site.aie_pos {
+                kinds.entry(pos).or_default().insert(site.kind);
+            }
+        }
+        let c0 = kinds.keys().map(|&(c, _)| c).min()?;
+        let c1 = kinds.keys().map(|&(c, _)| c).max()?;
+        let r0 = kinds.keys().map(|&(_, r)| r).min()?;
+        let r1 = kinds.keys().map(|&(_, r)| r).max()?;
+        let mut rows = Vec::new();
+        let mut holes = Vec::new();
+        for r in r0..=r1 {
+            let mut row = BTreeSet::new();
+            for c in c0..=c1 {
+                match kinds.get(&(c, r)) {
+                    Some(k) => row.extend(k.iter().copied()),
+                    None => holes.push((c, r)),
+                }
+            }
+            rows.push(row.into_iter().collect());
+        }
+        Some(AieArray {
+            origin: (c0, r0),
+            columns: c1 - c0 + 1,
+            rows,
+            holes,
+        })
+    }
+}
+
+// Rawdump files start with the magic and format version, ahead of the
+// bincode data.  Bump the version whenever the layout of Part changes, and
+// teach from_file to read the old one.
+//
+// 0: no header
+// 1: Part::versal added
+// 2: Part::family is a Family rather than a name
+// 3: VersalNocLink::pips added
+const FILE_MAGIC: &[u8; 8] = b"PRJCRAWD";
+const FILE_VERSION: u32 = 3;
+
+// Part as written before format version 3, without `versal`, which follows
+// it in versions 1 and 2.  The family is a name before version 2.
+#[derive(Deserialize)]
+struct OldPart<F> {
+    part: String,
+    family: F,
+    source: Source,
+    width: u16,
+    height: u16,
+    tile_kinds: HashMap<String, TileKind>,
+    tiles: HashMap<Coord, Tile>,
+    speeds: Vec<String>,
+    node_classes: Vec<String>,
+    nodes: Vec<TkNode>,
+    templates: Vec<TkNodeTemplate>,
+    wires: Vec<String>,
+    slot_kinds: Vec<String>,
+    packages: HashMap<String, Vec<PkgPin>>,
+    combos: Vec<PartCombo>,
+}
+
+// VersalInfo as written before format version 3, with links as
+// (from_site, from_pin, to_site, to_pin).
+#[derive(Deserialize)]
+struct OldVersalInfo {
+    sites: Vec<VersalSite>,
+    noc_links: Vec<(usize, String, usize, String)>,
+}
+
+impl<F> OldPart<F> {
+    fn upgrade(self, family: Family, versal: Option<OldVersalInfo>) -> Part {
+        let versal = versal.map(|v| VersalInfo {
+            sites: v.sites,
+            noc_links: v.noc_links.into_iter().map(|(from_site, from_pin, to_site, to_pin)| VersalNocLink {from_site, from_pin, to_site, to_pin, pips: Vec::new()}).collect(),
+        });
+        Part {
+            part: self.part,
+            family,
+            source: self.source,
+            width: self.width,
+            height: self.height,
+            tile_kinds: self.tile_kinds,
+            tiles: self.tiles,
+            speeds: self.speeds,
+            node_classes: self.node_classes,
+            nodes: self.nodes,
+            templates: self.templates,
+            wires: self.wires,
+            slot_kinds: self.slot_kinds,
+            packages: self.packages,
+            combos: self.combos,
+            versal,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Part {
     pub part: String,
-    pub family: String,
+    pub family: Family,
     pub source: Source,
     pub width: u16,
     pub height: u16,
@@ -218,6 +377,7 @@ pub struct Part {
     pub slot_kinds: Vec<String>,
     pub packages: HashMap<String, Vec<PkgPin>>,
     pub combos: Vec<PartCombo>,
+    pub versal: Option<VersalInfo>,
 }
 
 impl Part {
@@ -268,10 +428,48 @@ impl Part {
         }
     }
 
+    /// Reads a rawdump file of the current format version, or of any older
+    /// one.
     pub fn from_file<P: AsRef<Path>> (path: P) -> Result<Self, Error> {
-        let f = File::open(path)?;
-        let cf = zstd::stream::Decoder::new(f)?;
-        let mut res: Part = bincode::deserialize_from(cf).unwrap();
+        let path = path.as_ref();
+        let mut cf = zstd::stream::Decoder::new(File::open(path)?)?;
+        let mut magic = [0; 8];
+        let version = if cf.read_exact(&mut magic).is_ok() && magic == *FILE_MAGIC {
+            let mut version = [0; 4];
+            cf.read_exact(&mut version)?;
+            u32::from_le_bytes(version)
+        } else {
+            // No header, so start over.
+            cf = zstd::stream::Decoder::new(File::open(path)?)?;
+            0
+        };
+        let err = |e: bincode::Error| match version {
+            0 => Error::ParseError(format!("{}: not a rawdump: {}", path.display(), e)),
+            _ => Error::ParseError(format!("{}: rawdump format version {}: {}", path.display(), version, e)),
+        };
+        let mut res: Part = match version {
+            FILE_VERSION => bincode::deserialize_from(cf).map_err(err)?,
+            0 | 1 => {
+                let old: OldPart<String> = bincode::deserialize_from(&mut cf).map_err(err)?;
+                let versal = if version == 1 {
+                    bincode::deserialize_from(&mut cf).map_err(err)?
+                } else {
+                    None
+                };
+                let family = match Family::from_name(&old.family) {
+                    Some(f) => f,
+                    None => return Err(Error::ParseError(format!("{}: unknown family {}", path.display(), old.family))),
+                };
+                old.upgrade(family, versal)
+            },
+            2 => {
+                let old: OldPart<Family> = bincode::deserialize_from(&mut cf).map_err(err)?;
+                let versal = bincode::deserialize_from(&mut cf).map_err(err)?;
+                let family = old.family;
+                old.upgrade(family, versal)
+            },
+            _ => return Err(Error::ParseError(format!("{} has rawdump format version {}, newer than the {} this build reads", path.display(), version, FILE_VERSION))),
+        };
         res.post_deserialize();
         Ok(res)
     }
@@ -279,7 +477,9 @@ impl Part {
     pub fn to_file<P: AsRef<Path>> (&self, path: P) -> Result<(), Error> {
         let f = File::create(path)?;
         let mut cf = zstd::stream::Encoder::new(f, 9)?;
-        bincode::serialize_into(&mut cf, self).unwrap();
+        cf.write_all(FILE_MAGIC)?;
+        cf.write_all(&FILE_VERSION.to_le_bytes())?;
+        bincode::serialize_into(&mut cf, self).map_err(|e| Error::ParseError(format!("rawdump: {}", e)))?;
         cf.finish()?;
         Ok(())
     }
@@ -321,3 +521,105 @@ impl Tile {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Write;
+    use std::path::Path;
+    use tempdir::TempDir;
+    use crate::xilinx::family::Family;
+    use crate::xilinx::rdbuild::PartBuilder;
+    use super::{Coord, Part, Source, VersalInfo, VersalSite, VersalSiteKind, FILE_MAGIC};
+
+    // Writes the part the way format versions 0 to 2 did.
+    fn write_old(rd: &Part, path: &Path, version: u32) {
+        let mut cf = zstd::stream::Encoder::new(File::create(path).unwrap(), 3).unwrap();
+        if version != 0 {
+            cf.write_all(FILE_MAGIC).unwrap();
+            cf.write_all(&version.to_le_bytes()).unwrap();
+        }
+        if version < 2 {
+            bincode::serialize_into(&mut cf, &rd.part).unwrap();
+            bincode::serialize_into(&mut cf, rd.family.name()).unwrap();
+        } else {
+            bincode::serialize_into(&mut cf, &rd.part).unwrap();
+            bincode::serialize_into(&mut cf, &rd.family).unwrap();
+        }
+        bincode::serialize_into(&mut cf, &(
+            &rd.source, rd.width, rd.height,
+            &rd.tile_kinds, &rd.tiles, &rd.speeds, &rd.node_classes, &rd.nodes,
+            &rd.templates, &rd.wires, &rd.slot_kinds, &rd.packages, &rd.combos,
+        )).unwrap();
+        if version != 0 {
+            let versal = rd.versal.as_ref().map(|v| (
+                &v.sites,
+                v.noc_links.iter().map(|l| (l.from_site, &l.from_pin, l.to_site, &l.to_pin)).collect::<Vec<_>>(),
+            ));
+            bincode::serialize_into(&mut cf, &versal).unwrap();
+        }
+        cf.finish().unwrap();
+    }
+
+    #[test]
+    fn aie_array_test() {
+        let site = |name: &str, kind, pos| VersalSite {name: name.to_string(), kind, tile: Coord {x: 0, y: 0}, aie_pos: pos};
+        let mut versal = VersalInfo::default();
+        assert_eq!(versal.aie_array(), None);
+        versal.sites.push(site("NOC_NMU512_X0Y0", VersalSiteKind::NocNmu, None));
+        for c in 2..5 {
+            if c != 3 {
+                versal.sites.push(site(&format!("AIE_PL_X{}Y0", c), VersalSiteKind::AieShim, Some((c, 0))));
+            }
+            for r in 1..3 {
+                versal.sites.push(site(&format!("AIE_CORE_X{}Y{}", c, r), VersalSiteKind::AieCore, Some((c, r))));
+                versal.sites.push(site(&format!("AIE_MEMORY_X{}Y{}", c, r), VersalSiteKind::AieMemory, Some((c, r))));
+            }
+        }
+        let array = versal.aie_array().unwrap();
+        assert_eq!(array.origin, (2, 0));
+        assert_eq!(array.columns, 3);
+        assert_eq!(array.rows, [
+            vec![VersalSiteKind::AieShim],
+            vec![VersalSiteKind::AieCore, VersalSiteKind::AieMemory],
+            vec![VersalSiteKind::AieCore, VersalSiteKind::AieMemory],
+        ]);
+        assert_eq!(array.holes, [(3, 0)]);
+    }
+
+    #[test]
+    fn file_version_test() {
+        let dir = TempDir::new("rawdump").unwrap();
+        let mut rd = PartBuilder::new("xcvc1902".to_string(), Family::Versal, Source::Vivado, 2, 1);
+        rd.add_tile(Coord {x: 0, y: 0}, "NOC_NMU512_X0Y0".to_string(), "NOC_NMU512".to_string(), &[], &[], &[]);
+        rd.add_tile(Coord {x: 1, y: 0}, "NOC_NSU512_X0Y0".to_string(), "NOC_NSU512".to_string(), &[], &[], &[]);
+        let nmu = rd.add_versal_site("NOC_NMU512_X0Y0".to_string(), VersalSiteKind::NocNmu, "NOC_NMU512_X0Y0").unwrap();
+        let nsu = rd.add_versal_site("NOC_NSU512_X0Y0".to_string(), VersalSiteKind::NocNsu, "NOC_NSU512_X0Y0").unwrap();
+        rd.add_versal_noc_link(nmu, "OUT".to_string(), nsu, "IN".to_string(), Vec::new());
+        rd.add_combo("xcvc1902-vsva2197-1LP-e-S".to_string(), "xcvc1902".to_string(), "vsva2197".to_string(), "-1LP".to_string(), "E".to_string());
+        let rd = rd.finish();
+        for version in 0..4 {
+            let path = dir.path().join(format!("v{}.zstd", version));
+            if version == 3 {
+                rd.to_file(&path).unwrap();
+            } else {
+                write_old(&rd, &path, version);
+            }
+            let nrd = Part::from_file(&path).unwrap();
+            assert_eq!(nrd.part, "xcvc1902");
+            assert_eq!(nrd.family, Family::Versal);
+            assert_eq!(nrd.combos[0].name, "xcvc1902-vsva2197-1LP-e-S");
+            if version == 0 {
+                assert_eq!(nrd.versal, None);
+            } else {
+                assert_eq!(nrd.versal, rd.versal);
+            }
+        }
+        let path = dir.path().join("v99.zstd");
+        write_old(&rd, &path, 99);
+        assert!(Part::from_file(&path).is_err());
+        let path = dir.path().join("junk.zstd");
+        std::fs::write(&path, zstd::encode_all(&b"junk"[..], 3).unwrap()).unwrap();
+        assert!(Part::from_file(&path).is_err());
+    }
+}
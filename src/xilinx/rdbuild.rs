@@ -1,5 +1,14 @@
 use std::collections::{HashSet, HashMap};
 use super::rawdump::*;
+use super::family::{Family, SlotQuirks};
+
+/// A site pin for `PartBuilder::add_tile`: name, direction, wire, speed.
+pub type SitePinDef<'a> = (&'a str, TkSitePinDir, Option<&'a str>, Option<&'a str>);
+/// A site for `PartBuilder::add_tile`: name, kind, pins.
+pub type SiteDef<'a> = (&'a str, &'a str, Vec<SitePinDef<'a>>);
+/// A pip for `PartBuilder::add_tile`: wire from, wire to, is_buf,
+/// is_excluded, is_test, inversion, direction, speed.
+pub type PipDef<'a> = (&'a str, &'a str, bool, bool, bool, TkPipInversion, TkPipDirection, Option<&'a str>);
 
 struct PartBuilderIndex {
     speeds: Vec<String>,
@@ -42,14 +51,6 @@ fn split_xy(s: &str) -> Option<(&str, u32, u32)> {
     Some((l, x, y))
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn split_xy_test() {
-        assert_eq!(super::split_xy("SLICE_X123Y456"), Some(("SLICE", 123, 456)));
-    }
-}
-
 fn get_lastnum(s: &str) -> u8 {
     let mut num : Option<u8> = None;
     for c in s.chars() {
@@ -67,7 +68,7 @@ fn get_lastnum(s: &str) -> u8 {
 }
 
 impl PartBuilder {
-    pub fn new(part: String, family: String, source: Source, width: u16, height: u16) -> Self {
+    pub fn new(part: String, family: Family, source: Source, width: u16, height: u16) -> Self {
         PartBuilder {
             part: Part {
                 part,
@@ -85,6 +86,7 @@ impl PartBuilder {
                 slot_kinds: Vec::new(),
                 packages: HashMap::new(),
                 combos: Vec::new(),
+                versal: None,
             },
             tiles_by_name: HashMap::new(),
             index: PartBuilderIndex {
@@ -103,8 +105,8 @@ impl PartBuilder {
         }
     }
 
-    fn slotify<'a>(&mut self, sites: &'a [(&'a str, &'a str, Vec<(&'a str, TkSitePinDir, Option<&'a str>, Option<&'a str>)>)]) -> HashMap<&'a str, TkSiteSlot> {
-        fn from_pinnum(pins: &[(&str, TkSitePinDir, Option<&str>, Option<&str>)], pin: &str) -> u8 {
+    fn slotify<'a>(&mut self, sites: &'a [SiteDef<'a>]) -> HashMap<&'a str, TkSiteSlot> {
+        fn from_pinnum(pins: &[SitePinDef], pin: &str) -> u8 {
             for (n, _, w, _) in pins {
                 if *n == pin {
                     return get_lastnum(w.unwrap());
@@ -127,9 +129,10 @@ impl PartBuilder {
                 }
             }
         }
+        let quirks = self.part.family.info().slot_quirks;
         let mut slots: HashSet<TkSiteSlot> = HashSet::new();
         for (n, k, p) in sites {
-            let slot = if self.part.family == "xc4000e" || self.part.family == "xc4000ex" || self.part.family == "xc4000xla" || self.part.family == "xc4000xv" || self.part.family == "spartanxl" {
+            let slot = if quirks == SlotQuirks::Xc4000 {
                 if let Some(urpos) = n.find("_R") {
                     if let Some(dpos) = n.find(".") {
                         TkSiteSlot::Indexed(self.index.slot_kind_to_idx(&n[..urpos]), n[dpos+1..].parse::<u8>().unwrap())
@@ -160,7 +163,7 @@ impl PartBuilder {
                 } else {
                     TkSiteSlot::Single(self.index.slot_kind_to_idx(n))
                 }
-            } else if self.part.family == "virtex" || self.part.family == "virtexe" {
+            } else if quirks == SlotQuirks::Virtex {
                 match *k {
                     "IOB" | "EMPTYIOB" | "PCIIOB" | "DLLIOB" => TkSiteSlot::Indexed(self.index.slot_kind_to_idx("IOB"), from_pinnum(p, "I")),
                     "TBUF" => TkSiteSlot::Indexed(self.index.slot_kind_to_idx(k), from_pinnum(p, "O")),
@@ -184,9 +187,9 @@ impl PartBuilder {
                     }),
                     _ => TkSiteSlot::Single(self.index.slot_kind_to_idx(k))
                 }
-            } else if *k == "TBUF" && self.part.family.starts_with("virtex2") {
+            } else if *k == "TBUF" && quirks == SlotQuirks::Virtex2 {
                 TkSiteSlot::Indexed(self.index.slot_kind_to_idx(k), from_pinnum(p, "O"))
-            } else if (*k == "GTIPAD" || *k == "GTOPAD") && self.part.family == "virtex2p" {
+            } else if (*k == "GTIPAD" || *k == "GTOPAD") && quirks == SlotQuirks::Virtex2 {
                 let idx : u8 = match n.as_bytes()[2] {
                     b'P' => 0,
                     b'N' => 1,
@@ -197,13 +200,13 @@ impl PartBuilder {
                 let base = self.index.slot_kind_to_idx(base);
                 let (bx, by) = *minxy.get(&base).unwrap();
                 TkSiteSlot::Xy(base, (x - bx) as u8, (y - by) as u8)
-            } else if (self.part.family.starts_with("virtex2") || self.part.family.starts_with("spartan3")) && (k.starts_with("IOB") || k.starts_with("IBUF") || k.starts_with("DIFF")) {
+            } else if matches!(quirks, SlotQuirks::Virtex2 | SlotQuirks::Spartan3 | SlotQuirks::Spartan3E) && (k.starts_with("IOB") || k.starts_with("IBUF") || k.starts_with("DIFF")) {
                 TkSiteSlot::Indexed(self.index.slot_kind_to_idx("IOB"), from_pinnum(p, "T"))
-            } else if ((self.part.family.starts_with("virtex2") || self.part.family == "spartan3") && k.starts_with("DCI")) || (self.part.family == "spartan3" && *k == "BUFGMUX") {
+            } else if (matches!(quirks, SlotQuirks::Virtex2 | SlotQuirks::Spartan3) && k.starts_with("DCI")) || (quirks == SlotQuirks::Spartan3 && *k == "BUFGMUX") {
                 TkSiteSlot::Indexed(self.index.slot_kind_to_idx(k), get_lastnum(n))
-            } else if self.part.family.starts_with("virtex2") && *k == "BUFGMUX" {
+            } else if quirks == SlotQuirks::Virtex2 && *k == "BUFGMUX" {
                 TkSiteSlot::Indexed(self.index.slot_kind_to_idx(k), n[7..8].parse::<u8>().unwrap())
-            } else if self.part.family == "spartan6" && k.starts_with("IOB") {
+            } else if quirks == SlotQuirks::Spartan6 && k.starts_with("IOB") {
                 TkSiteSlot::Indexed(self.index.slot_kind_to_idx("IOB"), from_pinnum(p, "PADOUT"))
             } else {
                 TkSiteSlot::Single(self.index.slot_kind_to_idx(n))
@@ -220,9 +223,9 @@ impl PartBuilder {
         coord: Coord,
         name: String,
         kind: String,
-        sites: &[(&str, &str, Vec<(&str, TkSitePinDir, Option<&str>, Option<&str>)>)],
+        sites: &[SiteDef],
         wires: &[(&str, Option<&str>)],
-        pips: &[(&str, &str, bool, bool, bool, TkPipInversion, TkPipDirection, Option<&str>)],
+        pips: &[PipDef],
     ) {
         assert!(coord.x < self.part.width);
         assert!(coord.y < self.part.height);
@@ -326,7 +329,7 @@ impl PartBuilder {
                         None => {
                             let i = tk.sites.len();
                             tk.sites.push(TkSite {
-                                slot: slot,
+                                slot,
                                 kind: kind.to_string(),
                                 pins: pins.iter().map(|(n, d, w, s)| (n.to_string(), TkSitePin {dir: *d, wire: *w, speed: *s})).collect(),
                             });
@@ -493,7 +496,7 @@ impl PartBuilder {
                     *w = TkWire::Connected(i);
                     tk.conn_wires.push(wire);
                     for crd in &tk.tiles {
-                        let t = self.part.tiles.get_mut(&crd).unwrap();
+                        let t = self.part.tiles.get_mut(crd).unwrap();
                         t.set_conn_wire(i, NodeOrClass::Pending(nc));
                     }
                     self.fixup_nodes_queue.push((kind, wire, s, nc));
@@ -508,6 +511,30 @@ impl PartBuilder {
     pub fn add_package(&mut self, name: String, pins: Vec<PkgPin>) {
         self.part.packages.insert(name, pins);
     }
+
+    /// Sets the excluded and test flags of a pip of a tile kind already
+    /// added, for sources that only learn them once all tiles are in.
+    pub fn set_pip_flags(&mut self, kind: &str, wire_from: &str, wire_to: &str, is_excluded: bool, is_test: bool) {
+        let k = (self.index.wires_by_name[wire_from], self.index.wires_by_name[wire_to]);
+        let pip = self.part.tile_kinds.get_mut(kind).unwrap().pips.get_mut(&k).unwrap();
+        pip.is_excluded = is_excluded;
+        pip.is_test = is_test;
+    }
+    /// Records a Versal hard block site, in a tile already added.
+    pub fn add_versal_site(&mut self, name: String, kind: VersalSiteKind, tile: &str) -> Option<usize> {
+        let tile = *self.tiles_by_name.get(tile)?;
+        let aie_pos = match kind {
+            VersalSiteKind::AieCore | VersalSiteKind::AieMemory | VersalSiteKind::AieShim => split_xy(&name).map(|(_, x, y)| (x as u16, y as u16)),
+            _ => None,
+        };
+        let versal = self.part.versal.get_or_insert_with(Default::default);
+        versal.sites.push(VersalSite {name, kind, tile, aie_pos});
+        Some(versal.sites.len() - 1)
+    }
+    pub fn add_versal_noc_link(&mut self, from_site: usize, from_pin: String, to_site: usize, to_pin: String, pips: Vec<String>) {
+        let versal = self.part.versal.get_or_insert_with(Default::default);
+        versal.noc_links.push(VersalNocLink {from_site, from_pin, to_site, to_pin, pips});
+    }
     pub fn add_combo(&mut self, name: String, device: String, package: String, speed: String, temp: String) {
         self.part.combos.push(PartCombo {name, device, package, speed, temp});
     }
@@ -521,7 +548,7 @@ impl PartBuilder {
             };
             let mut tidx : Option<u32> = None;
             for crd in &tk.tiles {
-                let t = self.part.tiles.get_mut(&crd).unwrap();
+                let t = self.part.tiles.get_mut(crd).unwrap();
                 if let NodeOrClass::Pending(_) = t.get_conn_wire(idx) {
                     let ctidx = match tidx {
                         Some(i) => i,
@@ -631,3 +658,11 @@ impl PartBuilderIndex {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn split_xy_test() {
+        assert_eq!(super::split_xy("SLICE_X123Y456"), Some(("SLICE", 123, 456)));
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{create_dir_all, remove_dir_all};
+use std::path::Path;
+use rayon::prelude::*;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use crate::error::Error;
+use crate::toolcache::{ToolCache, ToolRunner};
+use crate::toolchain::{Toolchain, ToolchainSet};
+use super::family::Family;
+use super::rawdump::Part;
+
+/// Somewhere device geometry comes from: a vendor tool, or anything else
+/// that can list parts and produce a rawdump for a device.
+pub trait DeviceSource: Sync {
+    /// One orderable part, as listed by the source.  Parts of one device
+    /// differ only in package, speed or temperature grade.
+    type Part: Clone + Serialize + DeserializeOwned + Send + Sync;
+
+    /// Key of the part list in a raw tool output cache.
+    const META_KEY: &'static str;
+
+    /// Whether `dump_device` keeps partial results in a work directory, so
+    /// that an interrupted dump can be resumed.
+    const RESUMABLE: bool = false;
+
+    /// Lists all parts of the given families.  Family names are ours, and
+    /// sources may also accept their own names for sub-families or devices.
+    fn list_parts(&self, tcs: &ToolchainSet, families: &HashSet<String>) -> Result<Vec<Self::Part>, Error>;
+
+    fn device(part: &Self::Part) -> &str;
+
+    fn family(part: &Self::Part) -> Family;
+
+    /// What tells the part apart from others of its device, for messages.
+    fn variant(part: &Self::Part) -> &str;
+
+    fn toolchain<'a>(&self, tcs: &'a ToolchainSet, parts: &[Self::Part]) -> Result<&'a Toolchain, Error> {
+        tcs.for_family(Self::family(&parts[0]).name())
+    }
+
+    /// Dumps a device, given all of its parts.  For `RESUMABLE` sources,
+    /// `work` is a directory to keep partial results in across runs; it is
+    /// removed once the dump is done.  Other sources always get `None`.
+    fn dump_device(&self, tr: &ToolRunner, parts: &[Self::Part], work: Option<&Path>) -> Result<Part, Error>;
+
+    fn group_devices(parts: Vec<Self::Part>) -> BTreeMap<String, Vec<Self::Part>> {
+        let mut res: BTreeMap<String, Vec<Self::Part>> = BTreeMap::new();
+        for part in parts {
+            res.entry(Self::device(&part).to_string()).or_default().push(part);
+        }
+        res
+    }
+}
+
+/// Dumps every device of the given families into `<dir>/<family>/<device>.zstd`,
+/// skipping devices already dumped.  With `cache`, raw tool output is kept
+/// in `<device>.raw` for `rebuild`.  A failed device does not stop the others.
+pub fn dump_parts<S: DeviceSource>(source: &S, tcs: &ToolchainSet, dir: &Path, families: &HashSet<String>, cache: bool) -> Result<(), Error> {
+    create_dir_all(dir)?;
+    let devices = S::group_devices(source.list_parts(tcs, families)?);
+    for (dev, parts) in devices.iter() {
+        println!("device {} [{}]: {}", dev, S::family(&parts[0]), parts.iter().fold(String::new(), |acc, p| acc + S::variant(p) + ", "));
+    }
+    let results: Vec<_> = devices.into_par_iter().map(|(dev, parts)| -> (String, Result<(), Error>) {
+        let fdir = dir.join(S::family(&parts[0]).name());
+        let path = fdir.join(dev.clone() + ".zstd");
+        if path.exists() {
+            println!("skipping {}", dev);
+            return (dev, Ok(()));
+        }
+        let work = if S::RESUMABLE {
+            Some(fdir.join(dev.clone() + ".work"))
+        } else {
+            None
+        };
+        if work.as_ref().is_some_and(|w| w.exists()) {
+            println!("resuming {}", dev);
+        } else {
+            println!("dumping {}", dev);
+        }
+        let res = (|| {
+            create_dir_all(&fdir)?;
+            let tc = source.toolchain(tcs, &parts)?;
+            let tr = if cache {
+                let cache = ToolCache::new(fdir.join(dev.clone() + ".raw"));
+                cache.save_meta(S::META_KEY, &parts)?;
+                ToolRunner::Record(tc, cache)
+            } else {
+                ToolRunner::Live(tc)
+            };
+            let rd = source.dump_device(&tr, &parts, work.as_deref())?;
+            rd.to_file(&path)?;
+            if let Some(work) = &work {
+                if work.exists() {
+                    remove_dir_all(work)?;
+                }
+            }
+            Ok(())
+        })();
+        match &res {
+            Ok(_) => println!("dumped {}", dev),
+            Err(e) => println!("failed {}: {:?}", dev, e),
+        }
+        (dev, res)
+    }).collect();
+    let failed: Vec<_> = results.into_iter().filter(|(_, res)| res.is_err()).map(|(dev, _)| dev).collect();
+    if !failed.is_empty() {
+        return Err(Error::ToolError(format!("failed to dump {}", failed.join(", "))));
+    }
+    Ok(())
+}
+
+/// Makes a rawdump again from raw tool output saved by `dump_parts`, if the
+/// cache was made by this source.
+pub fn rebuild<S: DeviceSource>(source: &S, cache: &ToolCache) -> Result<Option<Part>, Error> {
+    if !cache.has(S::META_KEY) {
+        return Ok(None);
+    }
+    let parts: Vec<S::Part> = cache.load_meta(S::META_KEY)?;
+    Ok(Some(source.dump_device(&ToolRunner::Replay(cache.clone()), &parts, None)?))
+}
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use std::fs::{remove_file, rename};
+use std::path::{Path, PathBuf};
+use rusqlite::{params, Connection};
+use crate::error::Error;
+use super::rawdump::{Part, Coord, SpeedIdx, NodeClassIdx, TkPipInversion, TkPipDirection, TkSitePinDir, TkSiteSlot, TkWire, WireIdx};
+
+const SCHEMA: &str = "
+CREATE TABLE part (name TEXT, family TEXT, source TEXT, width INTEGER, height INTEGER);
+CREATE TABLE wires (id INTEGER PRIMARY KEY, name TEXT NOT NULL UNIQUE);
+CREATE TABLE tile_kinds (id INTEGER PRIMARY KEY, name TEXT NOT NULL UNIQUE);
+CREATE TABLE tiles (id INTEGER PRIMARY KEY, name TEXT NOT NULL UNIQUE, kind_id INTEGER NOT NULL REFERENCES tile_kinds, x INTEGER NOT NULL, y INTEGER NOT NULL);
+CREATE TABLE sites (id INTEGER PRIMARY KEY, kind_id INTEGER NOT NULL REFERENCES tile_kinds, idx INTEGER NOT NULL, slot TEXT NOT NULL, kind TEXT NOT NULL);
+CREATE TABLE tile_sites (tile_id INTEGER NOT NULL REFERENCES tiles, site_id INTEGER NOT NULL REFERENCES sites, name TEXT NOT NULL);
+CREATE TABLE site_pins (site_id INTEGER NOT NULL REFERENCES sites, name TEXT NOT NULL, dir TEXT NOT NULL, wire_id INTEGER REFERENCES wires, speed TEXT);
+CREATE TABLE tile_kind_wires (kind_id INTEGER NOT NULL REFERENCES tile_kinds, wire_id INTEGER NOT NULL REFERENCES wires, internal INTEGER NOT NULL);
+CREATE TABLE pips (kind_id INTEGER NOT NULL REFERENCES tile_kinds, wire_from_id INTEGER NOT NULL REFERENCES wires, wire_to_id INTEGER NOT NULL REFERENCES wires, is_buf INTEGER NOT NULL, is_excluded INTEGER NOT NULL, is_test INTEGER NOT NULL, inversion TEXT NOT NULL, direction TEXT NOT NULL, speed TEXT);
+CREATE TABLE nodes (id INTEGER PRIMARY KEY, base_x INTEGER NOT NULL, base_y INTEGER NOT NULL, template INTEGER NOT NULL);
+CREATE TABLE node_wires (node_id INTEGER NOT NULL REFERENCES nodes, tile_id INTEGER NOT NULL REFERENCES tiles, wire_id INTEGER NOT NULL REFERENCES wires, speed TEXT, class TEXT);
+CREATE TABLE packages (id INTEGER PRIMARY KEY, name TEXT NOT NULL UNIQUE);
+CREATE TABLE pins (package_id INTEGER NOT NULL REFERENCES packages, pin TEXT NOT NULL, pad TEXT, vref_bank INTEGER, vcco_bank INTEGER, func TEXT NOT NULL, tracelen_um INTEGER, delay_min_fs INTEGER, delay_max_fs INTEGER);
+CREATE TABLE combos (name TEXT NOT NULL, device TEXT NOT NULL, package TEXT NOT NULL, speed TEXT NOT NULL, temp TEXT NOT NULL);
+";
+
+// Created after the data is in, which is much faster than keeping them
+// up to date on every insert.
+const INDEXES: &str = "
+CREATE INDEX tiles_kind ON tiles (kind_id);
+CREATE INDEX tiles_xy ON tiles (x, y);
+CREATE INDEX sites_kind ON sites (kind_id, idx);
+CREATE INDEX tile_sites_tile ON tile_sites (tile_id);
+CREATE INDEX tile_sites_name ON tile_sites (name);
+CREATE INDEX site_pins_site ON site_pins (site_id);
+CREATE INDEX site_pins_wire ON site_pins (wire_id);
+CREATE INDEX tile_kind_wires_kind ON tile_kind_wires (kind_id);
+CREATE INDEX tile_kind_wires_wire ON tile_kind_wires (wire_id);
+CREATE INDEX pips_kind ON pips (kind_id);
+CREATE INDEX pips_from ON pips (wire_from_id);
+CREATE INDEX pips_to ON pips (wire_to_id);
+CREATE INDEX node_wires_node ON node_wires (node_id);
+CREATE INDEX node_wires_tile_wire ON node_wires (tile_id, wire_id);
+CREATE INDEX pins_package ON pins (package_id);
+";
+
+fn wire_id(w: WireIdx) -> Option<i64> {
+    w.unpack().map(|idx| idx as i64)
+}
+
+fn speed(part: &Part, s: SpeedIdx) -> Option<&str> {
+    if s == SpeedIdx::NONE {
+        None
+    } else {
+        Some(part.print_speed(s))
+    }
+}
+
+fn node_class(part: &Part, c: NodeClassIdx) -> Option<&str> {
+    if c == NodeClassIdx::UNKNOWN {
+        None
+    } else {
+        Some(part.print_node_class(c))
+    }
+}
+
+fn slot_name(part: &Part, slot: TkSiteSlot) -> String {
+    match slot {
+        TkSiteSlot::Single(sk) => part.print_slot_kind(sk).to_string(),
+        TkSiteSlot::Indexed(sk, i) => format!("{}[{}]", part.print_slot_kind(sk), i),
+        TkSiteSlot::Xy(sk, x, y) => format!("{}_X{}Y{}", part.print_slot_kind(sk), x, y),
+    }
+}
+
+/// Writes the part into a new SQLite database, replacing `path` if it
+/// exists.  Wire ids are the rawdump wire indices; sites, site pins and
+/// pips are per tile kind, with `tile_sites` giving the site names of each
+/// tile.
+pub fn write_sqlite<P: AsRef<Path>>(part: &Part, path: P) -> Result<(), Error> {
+    // Built next to the destination and moved over it once complete, so
+    // that an interrupted run leaves no half-written database behind.
+    let path = path.as_ref();
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    if tmp.exists() {
+        remove_file(&tmp)?;
+    }
+    let mut conn = Connection::open(&tmp)?;
+    fill(&mut conn, part)?;
+    conn.close().map_err(|(_, e)| e)?;
+    rename(&tmp, path)?;
+    Ok(())
+}
+
+fn fill(conn: &mut Connection, part: &Part) -> Result<(), Error> {
+    conn.execute_batch(SCHEMA)?;
+    let tx = conn.transaction()?;
+    tx.execute("INSERT INTO part VALUES (?1, ?2, ?3, ?4, ?5)", params![part.part, part.family.name(), format!("{:?}", part.source), part.width, part.height])?;
+    {
+        let mut st = tx.prepare("INSERT INTO wires VALUES (?1, ?2)")?;
+        for (i, w) in part.wires.iter().enumerate() {
+            st.execute(params![i as i64, w])?;
+        }
+    }
+
+    let mut kinds: Vec<&str> = part.tile_kinds.keys().map(|k| k as &str).collect();
+    kinds.sort_unstable();
+    let mut kind_ids: HashMap<&str, i64> = HashMap::new();
+    let mut site_ids: HashMap<(&str, usize), i64> = HashMap::new();
+    {
+        let mut st_kind = tx.prepare("INSERT INTO tile_kinds VALUES (?1, ?2)")?;
+        let mut st_site = tx.prepare("INSERT INTO sites VALUES (?1, ?2, ?3, ?4, ?5)")?;
+        let mut st_pin = tx.prepare("INSERT INTO site_pins VALUES (?1, ?2, ?3, ?4, ?5)")?;
+        let mut st_wire = tx.prepare("INSERT INTO tile_kind_wires VALUES (?1, ?2, ?3)")?;
+        let mut st_pip = tx.prepare("INSERT INTO pips VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)")?;
+        for (i, &kind) in kinds.iter().enumerate() {
+            let tk = &part.tile_kinds[kind];
+            let kid = i as i64;
+            st_kind.execute(params![kid, kind])?;
+            kind_ids.insert(kind, kid);
+            for (j, site) in tk.sites.iter().enumerate() {
+                let sid = site_ids.len() as i64;
+                st_site.execute(params![sid, kid, j as i64, slot_name(part, site.slot), site.kind])?;
+                site_ids.insert((kind, j), sid);
+                for (name, pin) in site.pins.iter() {
+                    let dir = match pin.dir {
+                        TkSitePinDir::Input => "IN",
+                        TkSitePinDir::Output => "OUT",
+                        TkSitePinDir::Bidir => "BIDIR",
+                    };
+                    st_pin.execute(params![sid, name, dir, wire_id(pin.wire), speed(part, pin.speed)])?;
+                }
+            }
+            for (&w, tw) in tk.wires.iter() {
+                let internal = matches!(tw, TkWire::Internal(_, _));
+                st_wire.execute(params![kid, wire_id(w), internal])?;
+            }
+            for (&(wf, wt), pip) in tk.pips.iter() {
+                let inversion = match pip.inversion {
+                    TkPipInversion::Never => "NEVER",
+                    TkPipInversion::Always => "ALWAYS",
+                    TkPipInversion::Prog => "PROG",
+                };
+                let direction = match pip.direction {
+                    TkPipDirection::Uni => "UNI",
+                    TkPipDirection::BiFwd => "BIFWD",
+                    TkPipDirection::BiBwd => "BIBWD",
+                };
+                st_pip.execute(params![kid, wire_id(wf), wire_id(wt), pip.is_buf, pip.is_excluded, pip.is_test, inversion, direction, speed(part, pip.speed)])?;
+            }
+        }
+    }
+
+    let mut coords: Vec<Coord> = part.tiles.keys().copied().collect();
+    coords.sort_unstable();
+    let mut tile_ids: HashMap<Coord, i64> = HashMap::new();
+    {
+        let mut st_tile = tx.prepare("INSERT INTO tiles VALUES (?1, ?2, ?3, ?4, ?5)")?;
+        let mut st_site = tx.prepare("INSERT INTO tile_sites VALUES (?1, ?2, ?3)")?;
+        for (i, c) in coords.iter().enumerate() {
+            let tile = &part.tiles[c];
+            let tid = i as i64;
+            st_tile.execute(params![tid, tile.name, kind_ids[&tile.kind as &str], c.x, c.y])?;
+            tile_ids.insert(*c, tid);
+            for (j, name) in tile.sites.iter().enumerate() {
+                if let Some(name) = name {
+                    st_site.execute(params![tid, site_ids[&(&tile.kind as &str, j)], name])?;
+                }
+            }
+        }
+    }
+
+    {
+        let mut st_node = tx.prepare("INSERT INTO nodes VALUES (?1, ?2, ?3, ?4)")?;
+        let mut st_wire = tx.prepare("INSERT INTO node_wires VALUES (?1, ?2, ?3, ?4, ?5)")?;
+        for (i, node) in part.nodes.iter().enumerate() {
+            st_node.execute(params![i as i64, node.base.x, node.base.y, node.template])?;
+            for w in part.templates[node.template as usize].wires.iter() {
+                let c = Coord {x: node.base.x + w.delta.x, y: node.base.y + w.delta.y};
+                st_wire.execute(params![i as i64, tile_ids[&c], wire_id(w.wire), speed(part, w.speed), node_class(part, w.cls)])?;
+            }
+        }
+    }
+
+    let mut pkgs: Vec<&str> = part.packages.keys().map(|k| k as &str).collect();
+    pkgs.sort_unstable();
+    {
+        let mut st_pkg = tx.prepare("INSERT INTO packages VALUES (?1, ?2)")?;
+        let mut st_pin = tx.prepare("INSERT INTO pins VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)")?;
+        for (i, &pkg) in pkgs.iter().enumerate() {
+            st_pkg.execute(params![i as i64, pkg])?;
+            for pin in part.packages[pkg].iter() {
+                st_pin.execute(params![i as i64, pin.pin, pin.pad, pin.vref_bank, pin.vcco_bank, pin.func, pin.tracelen_um, pin.delay_min_fs, pin.delay_max_fs])?;
+            }
+        }
+        let mut st_combo = tx.prepare("INSERT INTO combos VALUES (?1, ?2, ?3, ?4, ?5)")?;
+        for combo in part.combos.iter() {
+            st_combo.execute(params![combo.name, combo.device, combo.package, combo.speed, combo.temp])?;
+        }
+    }
+    tx.commit()?;
+    conn.execute_batch(INDEXES)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::{params, Connection};
+    use tempdir::TempDir;
+    use crate::xilinx::family::Family;
+    use crate::xilinx::rawdump::{Coord, Source, TkPipInversion, TkPipDirection};
+    use crate::xilinx::rdbuild::PartBuilder;
+
+    #[test]
+    fn sqlite_test() {
+        let mut rd = PartBuilder::new("xc7a0t".to_string(), Family::Series7, Source::Vivado, 2, 1);
+        let pip = |wf, wt| (wf, wt, true, false, false, TkPipInversion::Never, TkPipDirection::Uni, None);
+        rd.add_tile(Coord {x: 0, y: 0}, "INT_L_X0Y0".to_string(), "INT_L".to_string(), &[],
+            &[("GFAN0", None), ("LOGIC_OUTS_L0", None), ("IMUX_L0", None)],
+            &[pip("GFAN0", "LOGIC_OUTS_L0"), pip("GFAN0", "IMUX_L0")]);
+        rd.add_tile(Coord {x: 1, y: 0}, "INT_R_X1Y0".to_string(), "INT_R".to_string(), &[],
+            &[("GFAN0", None), ("IMUX0", None)],
+            &[pip("GFAN0", "IMUX0")]);
+        let part = rd.finish();
+        let mut conn = Connection::open_in_memory().unwrap();
+        super::fill(&mut conn, &part).unwrap();
+        let mut st = conn.prepare("
+            SELECT DISTINCT tk.name FROM pips p
+            JOIN tile_kinds tk ON tk.id = p.kind_id
+            JOIN wires wf ON wf.id = p.wire_from_id
+            JOIN wires wt ON wt.id = p.wire_to_id
+            WHERE wf.name = ?1 AND wt.name LIKE 'LOGIC_OUTS%'").unwrap();
+        let kinds: Vec<String> = st.query_map(params!["GFAN0"], |r| r.get(0)).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(kinds, vec!["INT_L".to_string()]);
+
+        // Writing over an existing database replaces it.
+        let dir = TempDir::new("sqlite").unwrap();
+        let path = dir.path().join("xc7a0t.sqlite");
+        super::write_sqlite(&part, &path).unwrap();
+        super::write_sqlite(&part, &path).unwrap();
+        let conn = Connection::open(&path).unwrap();
+        let num: i64 = conn.query_row("SELECT COUNT(*) FROM pips", [], |r| r.get(0)).unwrap();
+        assert_eq!(num, 3);
+    }
+}
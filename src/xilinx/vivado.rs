@@ -1,2 +1,6 @@
 pub mod parts;
 pub mod rawdump;
+pub mod session;
+pub mod nodes;
+pub mod versal;
+pub mod source;
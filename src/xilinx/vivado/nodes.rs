@@ -0,0 +1,152 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::collections::BinaryHeap;
+use std::cmp::Reverse;
+use std::convert::TryInto;
+use tempdir::TempDir;
+use crate::error::Error;
+
+// node tile, node wire, tile, wire, speed index — all interned.
+type NodeWire = [u32; 5];
+
+const RECORD_SIZE: usize = 20;
+
+/// Collects the wires of every node in a device, tile by tile, and hands
+/// them back node by node.  Nodes are identified by the interned tile and
+/// wire halves of their name.  Once the buffer holds `limit` wires, it is
+/// sorted and spilled to a temporary file; the runs are merged at the end.
+pub struct NodeAccumulator {
+    buf: Vec<NodeWire>,
+    limit: usize,
+    dir: Option<TempDir>,
+    runs: Vec<File>,
+}
+
+struct Run {
+    reader: BufReader<File>,
+}
+
+impl Run {
+    fn next(&mut self) -> Result<Option<NodeWire>, Error> {
+        let mut data = [0u8; RECORD_SIZE];
+        match self.reader.read_exact(&mut data) {
+            Ok(()) => (),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+        let mut res = [0; 5];
+        for (i, x) in res.iter_mut().enumerate() {
+            *x = u32::from_le_bytes(data[i * 4..i * 4 + 4].try_into().unwrap());
+        }
+        Ok(Some(res))
+    }
+}
+
+impl NodeAccumulator {
+    pub fn new(limit: usize) -> Self {
+        NodeAccumulator {
+            buf: Vec::new(),
+            limit,
+            dir: None,
+            runs: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, node: (u32, u32), tile: u32, wire: u32, speed: u32) -> Result<(), Error> {
+        self.buf.push([node.0, node.1, tile, wire, speed]);
+        if self.buf.len() >= self.limit {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> Result<(), Error> {
+        if self.dir.is_none() {
+            self.dir = Some(TempDir::new("nodes")?);
+        }
+        let path = self.dir.as_ref().unwrap().path().join(format!("run{}", self.runs.len()));
+        self.buf.sort_unstable();
+        let mut w = BufWriter::new(File::create(&path)?);
+        for nw in self.buf.iter() {
+            for x in nw {
+                w.write_all(&x.to_le_bytes())?;
+            }
+        }
+        w.flush()?;
+        self.runs.push(File::open(&path)?);
+        self.buf.clear();
+        Ok(())
+    }
+
+    /// Calls `f` once per node with its (tile, wire, speed) triples, in node
+    /// order, stopping at the first error.
+    pub fn finish(mut self, mut f: impl FnMut(&[(u32, u32, u32)]) -> Result<(), Error>) -> Result<(), Error> {
+        let mut cur: Option<(u32, u32)> = None;
+        let mut wires: Vec<(u32, u32, u32)> = Vec::new();
+        let mut emit = |nw: NodeWire| -> Result<(), Error> {
+            if cur != Some((nw[0], nw[1])) {
+                if !wires.is_empty() {
+                    f(&wires)?;
+                    wires.clear();
+                }
+                cur = Some((nw[0], nw[1]));
+            }
+            wires.push((nw[2], nw[3], nw[4]));
+            Ok(())
+        };
+        if self.runs.is_empty() {
+            self.buf.sort_unstable();
+            for nw in self.buf.iter() {
+                emit(*nw)?;
+            }
+        } else {
+            if !self.buf.is_empty() {
+                self.spill()?;
+            }
+            let mut runs: Vec<_> = self.runs.drain(..).map(|f| Run { reader: BufReader::new(f) }).collect();
+            let mut heap = BinaryHeap::new();
+            for (i, run) in runs.iter_mut().enumerate() {
+                if let Some(nw) = run.next()? {
+                    heap.push(Reverse((nw, i)));
+                }
+            }
+            while let Some(Reverse((nw, i))) = heap.pop() {
+                emit(nw)?;
+                if let Some(nw) = runs[i].next()? {
+                    heap.push(Reverse((nw, i)));
+                }
+            }
+        }
+        if !wires.is_empty() {
+            f(&wires)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NodeAccumulator;
+
+    fn collect(limit: usize) -> Vec<Vec<(u32, u32, u32)>> {
+        let mut acc = NodeAccumulator::new(limit);
+        for i in 0..100 {
+            acc.add((i % 7, 0), i, i + 1, 3).unwrap();
+        }
+        let mut res = Vec::new();
+        acc.finish(|w| {
+            res.push(w.to_vec());
+            Ok(())
+        }).unwrap();
+        res
+    }
+
+    #[test]
+    fn spill_test() {
+        let res = collect(1000);
+        assert_eq!(res.len(), 7);
+        assert_eq!(res[0][..2], [(0, 1, 3), (7, 8, 3)]);
+        assert_eq!(collect(10), res);
+        assert_eq!(collect(1), res);
+    }
+}
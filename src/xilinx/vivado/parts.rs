@@ -1,12 +1,14 @@
-use crate::toolchain::Toolchain;
-use crate::toolreader::ToolchainReader;
 use crate::error::Error;
+use crate::xilinx::family::Family;
+use super::session::{Session, parse_lines};
 use std::io::BufRead;
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VivadoPart {
     pub name: String,
-    pub actual_family: String,
+    pub actual_family: Family,
     pub arch: String,
     pub family: String,
     pub device: String,
@@ -16,7 +18,6 @@ pub struct VivadoPart {
 }
 
 const GET_PARTS_TCL: &str = r#"
-set fd [open "parts.fifo" w]
 foreach x [get_parts] {
     set arch [get_property ARCHITECTURE $x]
     set fam [get_property FAMILY $x]
@@ -24,53 +25,43 @@ foreach x [get_parts] {
     set pkg [get_property PACKAGE $x]
     set speed [get_property SPEED $x]
     set temp [get_property TEMPERATURE_GRADE_LETTER $x]
-    puts $fd "PART $x $arch $fam $dev $pkg $speed $temp"
+    emit PART $x $arch $fam $dev $pkg $speed $temp
 }
-puts $fd "END"
+emit END
 "#;
 
-pub fn get_parts(tc: &Toolchain) -> Result<Vec<VivadoPart>, Error> {
-    let tr = ToolchainReader::new(tc, "vivado", &["-nolog", "-nojournal", "-mode", "batch", "-source", "script.tcl"], &[], "parts.fifo", &[("script.tcl", GET_PARTS_TCL.as_bytes())])?;
-    let lines = tr.lines();
+/// Lists all parts known to Vivado.  Parts of architectures we know nothing
+/// about are reported and left out.
+pub fn get_parts(session: &mut Session) -> Result<Vec<VivadoPart>, Error> {
     let mut res: Vec<VivadoPart> = Vec::new();
-    let mut got_end = false;
-    for l in lines {
-        let l = l?;
-        let sl: Vec<_> = l.split_whitespace().collect();
-        if sl[0] == "END" {
-            got_end = true;
-            break;
+    let mut unknown: BTreeMap<String, usize> = BTreeMap::new();
+    parse_lines("parts", &mut session.query("parts", GET_PARTS_TCL)?.lines(), |sl| {
+        match sl.first().map(|s| &s[..]) {
+            Some("END") => return Ok(true),
+            Some("PART") if sl.len() == 8 => (),
+            _ => return Err(Error::ParseError("unknown line".to_string())),
         }
-        assert!(sl[0] == "PART");
+        let actual_family = match Family::from_vivado_arch(&sl[2]) {
+            Some(f) => f,
+            None => {
+                *unknown.entry(sl[2].clone()).or_default() += 1;
+                return Ok(false);
+            },
+        };
         res.push(VivadoPart{
-            name: sl[1].to_string(),
-            actual_family: match sl[2] {
-                "spartan7" => "7series",
-                "artix7" => "7series",
-                "kintex7" => "7series",
-                "virtex7" => "7series",
-                "zynq" => "7series",
-                "kintexu" => "ultrascale",
-                "virtexu" => "ultrascale",
-                "kintexuplus" => "ultrascaleplus",
-                "virtexuplus" => "ultrascaleplus",
-                "virtexuplusHBM" => "ultrascaleplus",
-                "virtexuplus58g" => "ultrascaleplus",
-                "zynquplus" => "ultrascaleplus",
-                "zynquplusRFSOC" => "ultrascaleplus",
-                "versal" => "versal",
-                _ => panic!("unknown arch {}", sl[2]),
-            }.to_string(),
-            arch: sl[2].to_string(),
-            family: sl[3].to_string(),
-            device: sl[4].to_string(),
-            package: sl[5].to_string(),
-            speed: sl[6].to_string(),
-            temp: sl.get(7).unwrap_or(&"").to_string(),
+            name: sl[1].clone(),
+            actual_family,
+            arch: sl[2].clone(),
+            family: sl[3].clone(),
+            device: sl[4].clone(),
+            package: sl[5].clone(),
+            speed: sl[6].clone(),
+            temp: sl[7].clone(),
         });
-    }
-    if !got_end {
-        return Err(Error::ParseError(format!("missing END")));
+        Ok(false)
+    })?;
+    for (arch, num) in unknown {
+        println!("skipping {} parts of unknown architecture {}", num, arch);
     }
     Ok(res)
 }
@@ -1,53 +1,57 @@
-use std::io::{BufRead, Write};
+use std::io::{self, BufRead};
+use std::collections::BTreeMap;
+use std::sync::{Condvar, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::sync_channel;
+use std::thread;
 use std::collections::HashMap;
-use crate::toolchain::Toolchain;
-use crate::toolreader::ToolchainReader;
 use crate::error::Error;
 use crate::stringpool::StringPool;
 use crate::xilinx::rawdump::{Part, Source, Coord, TkSitePinDir, TkPipInversion, TkPipDirection, PkgPin};
 use crate::xilinx::rdbuild::PartBuilder;
+use crate::xilinx::family::Family;
 use super::parts::VivadoPart;
+use super::session::{Session, parse_lines};
+use super::nodes::NodeAccumulator;
+use super::versal::dump_versal;
 
 const TILE_BATCH_SIZE: usize = 4000;
 
+// Node wires kept in memory before spilling a sorted run to disk; 20 bytes
+// each.
+const NODE_BUFFER_WIRES: usize = 1 << 24;
+
 const LIST_TILES_TCL: &str = r#"
-link_design -part [lindex $argv 0]
-set fd [open "tiles.fifo" w]
 foreach x [get_tiles] {
     set gx [get_property GRID_POINT_X $x]
     set gy [get_property GRID_POINT_Y $x]
     set tt [get_property TYPE $x]
-    puts $fd "TILE $gx $gy $x $tt"
+    emit TILE $gx $gy $x $tt
 }
 foreach x [get_speed_models] {
     set idx [get_property SPEED_INDEX $x]
-    puts $fd "SPEED #$idx #$x"
+    emit SPEED $idx $x
 }
-puts $fd "END"
+emit END
 "#;
 
 const DUMP_PKGPINS_TCL: &str = r#"
-link_design -part [lindex $argv 0]
-set fd [open "pkgpins.fifo" w]
 foreach x [get_package_pins] {
     set bank [get_property BANK $x]
     set mind [get_property MIN_DELAY $x]
     set maxd [get_property MAX_DELAY $x]
     set func [get_property PIN_FUNC $x]
     set site [get_sites -of $x]
-    puts $fd "PKGPIN $x #$site #$bank #$func #$mind #$maxd"
+    emit PKGPIN $x $site $bank $func $mind $maxd
 }
-puts $fd "END"
+emit END
 "#;
 
 const DUMP_TTS_TCL: &str = r#"
-link_design -part [lindex $argv 0]
-set ifd [open "tts.list" r]
-set fd [open "tts.fifo" w]
-while { [gets $ifd tname] >= 0 } {
+foreach tname $tiles {
     set tile [get_tiles $tname]
     set tt [get_property TYPE $tile]
-    puts $fd "TILE $tt $tile"
+    emit TILE $tt $tile
     foreach x [get_pips -of $tile] {
         set wf [get_wires -uphill -of $x]
         set wt [get_wires -downhill -of $x]
@@ -59,57 +63,199 @@ while { [gets $ifd tname] >= 0 } {
         set pseudo [get_property IS_PSEUDO $x]
         set invfix [get_property IS_FIXED_INVERSION $x]
         set invcan [get_property CAN_INVERT $x]
-        puts $fd "PIP $x $wf $wt $dir $buf0 $buf1 $excl $test $pseudo $invfix $invcan"
+        emit PIP $x $wf $wt $dir $buf0 $buf1 $excl $test $pseudo $invfix $invcan
     }
 }
-puts $fd "END"
+emit END
 "#;
 
 const DUMP_TILES_TCL: &str = r#"
-link_design -part [lindex $argv 0]
-set ifd [open "tiles.list" r]
-set fd [open "tiles.fifo" w]
-while { [gets $ifd tname] >= 0 } {
+foreach tname $tiles {
     set tile [get_tiles $tname]
     set gx [get_property GRID_POINT_X $tile]
     set gy [get_property GRID_POINT_Y $tile]
     set tt [get_property TYPE $tile]
-    puts $fd "TILE $gx $gy $tile $tt"
+    emit TILE $gx $gy $tile $tt
     foreach x [get_wires -of $tile] {
         set node [get_nodes -of $x]
         set si [get_property SPEED_INDEX $x]
-        puts $fd "WIRE $x $si #$node"
+        emit WIRE $x $si $node
     }
     foreach x [get_pips -of $tile] {
         set si [get_property SPEED_INDEX $x]
-        puts $fd "PIP #$x #$si"
+        emit PIP $x $si
     }
     foreach x [get_sites -of $tile] {
         set type [get_property SITE_TYPE $x]
-        puts $fd "SITE $x $type"
+        emit SITE $x $type
         foreach y [get_site_pins -of $x] {
             set node [get_nodes -of $y]
             set dir [get_property DIRECTION $y]
             set si [get_property SPEED_INDEX $y]
-            puts $fd "SITEPIN #$y #$dir #$si #$node"
+            set pw {}
+            foreach w [get_wires -quiet -of $y] {
+                if {[get_property TILE_NAME $w] eq $tname} {
+                    lappend pw $w
+                }
+            }
+            emit SITEPIN $y $dir $si $node {*}$pw
         }
-        puts $fd "ENDSITE"
+        emit ENDSITE
     }
-    puts $fd "ENDTILE"
+    emit ENDTILE
 }
-puts $fd "END"
+emit END
 "#;
 
-fn parse_bool(s: &str) -> bool {
+// A pip or site pin of the tile being dumped, as passed to PartBuilder::add_tile.
+type TilePip<'a> = (&'a str, &'a str, bool, bool, bool, TkPipInversion, TkPipDirection, Option<&'a str>);
+type SitePin<'a> = (String, TkSitePinDir, Option<String>, Option<&'a str>);
+
+// Prepends the list of tiles for the DUMP_TTS and DUMP_TILES scripts.  Each
+// name is braced, so that it makes one list element whatever it contains.
+fn with_tiles<'a>(script: &str, tiles: impl Iterator<Item = &'a String>) -> String {
+    let mut res = "set tiles {".to_string();
+    for t in tiles {
+        res.push_str(" {");
+        res.push_str(t);
+        res.push('}');
+    }
+    res.push_str(" }\n");
+    res.push_str(script);
+    res
+}
+
+fn parse_bool(s: &str) -> Result<bool, Error> {
     match s {
-        "0" => false,
-        "1" => true,
-        _ => panic!("weird bool {}", s),
+        "0" => Ok(false),
+        "1" => Ok(true),
+        _ => Err(Error::ParseError(format!("weird bool {}", s))),
     }
 }
 
-pub fn get_rawdump(tc: &Toolchain, parts: &[VivadoPart]) -> Result<Part, Error> {
+pub(super) fn field(sl: &[String], idx: usize) -> Result<&str, Error> {
+    match sl.get(idx) {
+        Some(f) => Ok(f),
+        None => Err(Error::ParseError(format!("missing field {}", idx))),
+    }
+}
+
+pub(super) fn strip_pref<'a>(s: &'a str, pref: &str) -> Result<&'a str, Error> {
+    s.strip_prefix(pref).ok_or_else(|| Error::ParseError(format!("{} does not start with {}", s, pref)))
+}
+
+fn speed_model(speed_models: &HashMap<u32, String>, si: u32) -> Result<&str, Error> {
+    match speed_models.get(&si) {
+        Some(s) => Ok(s),
+        None => Err(Error::ParseError(format!("unknown speed index {}", si))),
+    }
+}
+
+fn opt_speed_model<'a>(speed_models: &'a HashMap<u32, String>, si: &str) -> Result<Option<&'a str>, Error> {
+    if si.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(speed_model(speed_models, si.parse()?)?))
+    }
+}
+
+// The tile wire a SITEPIN record attaches its pin to: the one Vivado lists,
+// if it does, or else the wire of the pin's node that lives in this tile.
+// Pins with no such wire are left unconnected.
+fn site_pin_wire(sl: &[String], wpref: &str, tile_n2w: &HashMap<String, Vec<u32>>, node_sp: &StringPool) -> Result<Option<String>, Error> {
+    let node = field(sl, 4)?;
+    match sl.len() {
+        5 => match tile_n2w.get(node).map(|v| &v[..]) {
+            None => Ok(None),
+            Some(&[w]) => Ok(Some(node_sp.get(w).to_string())),
+            Some(v) => Err(Error::ParseError(format!("site pin wire ambiguous: {:?}", v.iter().map(|n| node_sp.get(*n)).collect::<Vec<_>>()))),
+        },
+        6 => Ok(Some(strip_pref(&sl[5], wpref)?.to_string())),
+        _ => Err(Error::ParseError(format!("site pin on several wires: {:?}", &sl[5..]))),
+    }
+}
+
+// How far ahead of the oldest unconsumed batch the workers may run, per job.
+const BATCH_WINDOW_PER_JOB: usize = 2;
+
+#[derive(Default)]
+struct BatchWindow {
+    consumed: usize,
+    abort: bool,
+}
+
+// Runs `count` tile batches on `jobs` workers made by `fork`, each typically
+// a session of its own, handing the output of each batch to `consume` in
+// batch order.  Workers only start a batch once it is close enough to the
+// next one to be consumed, so that a slow batch cannot make the finished
+// ones after it pile up.
+fn run_parallel<W>(count: usize, jobs: usize, mut fork: impl FnMut() -> W, consume: &mut dyn FnMut(Vec<String>) -> Result<(), Error>) -> Result<(), Error>
+where W: FnMut(usize) -> Result<Vec<String>, Error> + Send {
+    let next = AtomicUsize::new(0);
+    let window = jobs * BATCH_WINDOW_PER_JOB;
+    let gate = (Mutex::new(BatchWindow::default()), Condvar::new());
+    thread::scope(|s| {
+        let (tx, rx) = sync_channel(jobs);
+        for _ in 0..jobs {
+            let tx = tx.clone();
+            let mut worker = fork();
+            let next = &next;
+            let gate = &gate;
+            s.spawn(move || {
+                loop {
+                    let bidx = next.fetch_add(1, Ordering::SeqCst);
+                    if bidx >= count {
+                        break;
+                    }
+                    {
+                        let mut st = gate.0.lock().unwrap();
+                        while !st.abort && bidx >= st.consumed + window {
+                            st = gate.1.wait(st).unwrap();
+                        }
+                        if st.abort {
+                            break;
+                        }
+                    }
+                    let res = worker(bidx);
+                    let failed = res.is_err();
+                    // The receiver is gone if another batch failed.
+                    if tx.send((bidx, res)).is_err() || failed {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+        let res = (|| {
+            // Batches finish in any order; hold on to the early ones until
+            // everything before them has been consumed.
+            let mut pending = BTreeMap::new();
+            for bidx in 0..count {
+                let lines = loop {
+                    if let Some(lines) = pending.remove(&bidx) {
+                        break lines;
+                    }
+                    match rx.recv() {
+                        Ok((idx, res)) => { pending.insert(idx, res?); },
+                        Err(_) => return Err(Error::ToolError(format!("lost tile batch {}", bidx))),
+                    }
+                };
+                consume(lines)?;
+                gate.0.lock().unwrap().consumed = bidx + 1;
+                gate.1.notify_all();
+            }
+            Ok(())
+        })();
+        // Let the workers waiting for their turn go, should we have failed.
+        gate.0.lock().unwrap().abort = true;
+        gate.1.notify_all();
+        res
+    })
+}
+
+pub fn get_rawdump(session: &mut Session, parts: &[VivadoPart], jobs: usize) -> Result<Part, Error> {
     let fpart = &parts[0];
+    session.open_part(&fpart.name);
 
     // STEP 1: list tiles, gather list of tile types, get dimensions; also list speed models
     let mut tts: HashMap<String, String> = HashMap::new();
@@ -117,54 +263,46 @@ pub fn get_rawdump(tc: &Toolchain, parts: &[VivadoPart]) -> Result<Part, Error>
     let mut width: u16 = 0;
     let mut height: u16 = 0;
     let mut speed_models: HashMap<u32, String> = HashMap::new();
-    {
-        let tr = ToolchainReader::new(tc, "vivado", &["-nolog", "-nojournal", "-mode", "batch", "-source", "script.tcl", "-tclargs", &fpart.name], &[], "tiles.fifo", &[("script.tcl", LIST_TILES_TCL.as_bytes())])?;
-        let lines = tr.lines();
-        let mut got_end = false;
-        for l in lines {
-            let l = l?;
-            let sl: Vec<_> = l.split_whitespace().collect();
-            match sl[0] {
-                "END" => {
-                    got_end = true;
-                    break;
-                },
-                "TILE" => {
-                    let gx: u16 = sl[1].parse()?;
-                    let gy: u16 = sl[2].parse()?;
-                    tile_names.push(sl[3].to_string());
-                    if !tts.contains_key(sl[4]) {
-                        tts.insert(sl[4].to_string(), sl[3].to_string());
-                    }
-                    if gy >= height {
-                        height = gy + 1;
-                    }
-                    if gx >= width {
-                        width = gx + 1;
-                    }
-                },
-                "SPEED" => {
-                    let idx: u32 = sl[1][1..].parse()?;
-                    let name = &sl[2][1..];
-                    if idx == 65535 {
-                        continue;
-                    }
-                    if speed_models.contains_key(&idx) {
-                        panic!("double speed model {}: {} {}", idx, name, speed_models.get(&idx).unwrap());
-                    }
-                    speed_models.insert(idx, name.to_string());
-                },
-                _ => panic!("unknown line {}", sl[0]),
-            }
-        }
-        if !got_end {
-            return Err(Error::ParseError(format!("missing END in tiles")));
+    parse_lines(&format!("{} tiles", fpart.name), &mut session.query("tiles", LIST_TILES_TCL)?.lines(), |sl| {
+        match sl.first().map(|s| &s[..]) {
+            Some("END") => return Ok(true),
+            Some("TILE") => {
+                let gx: u16 = field(sl, 1)?.parse()?;
+                let gy: u16 = field(sl, 2)?.parse()?;
+                let name = field(sl, 3)?;
+                let tt = field(sl, 4)?;
+                tile_names.push(name.to_string());
+                if !tts.contains_key(tt) {
+                    tts.insert(tt.to_string(), name.to_string());
+                }
+                if gy >= height {
+                    height = gy + 1;
+                }
+                if gx >= width {
+                    width = gx + 1;
+                }
+            },
+            Some("SPEED") => {
+                let idx: u32 = field(sl, 1)?.parse()?;
+                let name = field(sl, 2)?;
+                if idx == 65535 {
+                    return Ok(false);
+                }
+                if let Some(other) = speed_models.get(&idx) {
+                    return Err(Error::ParseError(format!("double speed model {}: {} {}", idx, name, other)));
+                }
+                speed_models.insert(idx, name.to_string());
+            },
+            _ => return Err(Error::ParseError("unknown line".to_string())),
         }
-        assert!((width as usize) * (height as usize) == tile_names.len());
+        Ok(false)
+    })?;
+    if (width as usize) * (height as usize) != tile_names.len() {
+        return Err(Error::ParseError(format!("{}: {}×{} grid has {} tiles", fpart.name, width, height, tile_names.len())));
     }
     println!("{}: {}×{} tiles, {} tts, {} SMs", fpart.device, width, height, tts.len(), speed_models.len());
 
-    let mut rd = PartBuilder::new(fpart.device.clone(), fpart.actual_family.clone(), Source::Vivado, width, height);
+    let mut rd = PartBuilder::new(fpart.device.clone(), fpart.actual_family, Source::Vivado, width, height);
 
     // STEP 2: dump TTs [pips]
     struct TtPip {
@@ -179,54 +317,52 @@ pub fn get_rawdump(tc: &Toolchain, parts: &[VivadoPart]) -> Result<Part, Error>
     }
     let mut tt_pips: HashMap<String, HashMap<String, TtPip>> = HashMap::new();
     {
-        let mut tlist: Vec<u8> = Vec::new();
-        for (_, tn) in tts {
-            tlist.write(tn.as_bytes())?;
-            tlist.write(b"\n")?;
-        }
-        let tr = ToolchainReader::new(tc, "vivado", &["-nolog", "-nojournal", "-mode", "batch", "-source", "script.tcl", "-tclargs", &fpart.name], &[], "tts.fifo", &[("script.tcl", DUMP_TTS_TCL.as_bytes()), ("tts.list", &tlist)])?;
-        let lines = tr.lines();
-        let mut got_end = false;
+        let script = with_tiles(DUMP_TTS_TCL, tts.values());
         let mut tile = "".to_string();
         let mut tt = "".to_string();
-        let mut pips: Option<&mut HashMap<String, TtPip>> = None;
-        for l in lines {
-            let l = l?;
-            let sl: Vec<_> = l.split_whitespace().collect();
-            match sl[0] {
-                "END" => {
-                    got_end = true;
-                    break;
-                },
-                "TILE" => {
-                    tile = sl[2].to_string();
-                    tt = sl[1].to_string();
-                    tt_pips.insert(sl[1].to_string(), HashMap::new());
-                    pips = Some(tt_pips.get_mut(sl[1]).unwrap());
+        parse_lines(&format!("{} tts", fpart.name), &mut session.query("tts", &script)?.lines(), |sl| {
+            match sl.first().map(|s| &s[..]) {
+                Some("END") => return Ok(true),
+                Some("TILE") => {
+                    tt = field(sl, 1)?.to_string();
+                    tile = field(sl, 2)?.to_string();
+                    tt_pips.insert(tt.clone(), HashMap::new());
                 },
-                "PIP" => {
+                Some("PIP") => {
                     let prefix = tile.clone() + "/";
                     let pprefix = tile.clone() + "/" + &tt + ".";
-                    let name = sl[1].strip_prefix(&pprefix).unwrap();
-                    let wf = sl[2].strip_prefix(&prefix).unwrap();
-                    let wt = sl[3].strip_prefix(&prefix).unwrap();
-                    let dir = parse_bool(sl[4]);
-                    let buf0 = parse_bool(sl[5]);
-                    let buf1 = parse_bool(sl[6]);
-                    let excl = parse_bool(sl[7]);
-                    let test = parse_bool(sl[8]);
-                    let pseudo = parse_bool(sl[9]);
-                    let invfix = parse_bool(sl[10]);
-                    let invcan = parse_bool(sl[11]);
+                    let name = strip_pref(field(sl, 1)?, &pprefix)?;
+                    let wf = strip_pref(field(sl, 2)?, &prefix)?;
+                    let wt = strip_pref(field(sl, 3)?, &prefix)?;
+                    let dir = parse_bool(field(sl, 4)?)?;
+                    let buf0 = parse_bool(field(sl, 5)?)?;
+                    let buf1 = parse_bool(field(sl, 6)?)?;
+                    let excl = parse_bool(field(sl, 7)?)?;
+                    let test = parse_bool(field(sl, 8)?)?;
+                    let pseudo = parse_bool(field(sl, 9)?)?;
+                    let invfix = parse_bool(field(sl, 10)?)?;
+                    let invcan = parse_bool(field(sl, 11)?)?;
                     let sep = match (dir, buf0, buf1) {
                         (true, false, false) => "->",
                         (false, false, false) => "<->",
                         (true, false, true) => "->>",
                         (false, true, true) => "<<->>",
-                        _ => panic!("unk pip dirbuf {} {} {} {}", name, dir, buf0, buf1),
+                        _ => return Err(Error::ParseError(format!("unk pip dirbuf {} {} {}", dir, buf0, buf1))),
+                    };
+                    if name != wf.to_string() + sep + wt {
+                        return Err(Error::ParseError(format!("pip name mismatch {}", name)));
+                    }
+                    let inv = match (invfix, invcan) {
+                        (false, false) => TkPipInversion::Never,
+                        (true, false) => TkPipInversion::Always,
+                        (false, true) => TkPipInversion::Prog,
+                        _ => return Err(Error::ParseError(format!("unk inversion {} {}", invfix, invcan))),
+                    };
+                    let pips = match tt_pips.get_mut(&tt) {
+                        Some(pips) => pips,
+                        None => return Err(Error::ParseError("PIP outside of TILE".to_string())),
                     };
-                    assert_eq!(name, wf.to_string() + sep + wt);
-                    pips.as_mut().unwrap().insert(name.to_string(), TtPip {
+                    pips.insert(name.to_string(), TtPip {
                         wire_from: wf.to_string(),
                         wire_to: wt.to_string(),
                         is_bidi: !dir,
@@ -234,141 +370,119 @@ pub fn get_rawdump(tc: &Toolchain, parts: &[VivadoPart]) -> Result<Part, Error>
                         is_excluded: excl,
                         is_test: test,
                         is_pseudo: pseudo,
-                        inv: match (invfix, invcan) {
-                            (false, false) => TkPipInversion::Never,
-                            (true, false) => TkPipInversion::Always,
-                            (false, true) => TkPipInversion::Prog,
-                            _ => panic!("unk inversion {} {}", invfix, invcan),
-                        }
+                        inv,
                     });
                 },
-                _ => panic!("unknown line {}", sl[0]),
+                _ => return Err(Error::ParseError("unknown line".to_string())),
             }
-        }
-        if !got_end {
-            return Err(Error::ParseError(format!("missing END in TTs")));
-        }
+            Ok(false)
+        })?;
     }
 
     // STEP 3: dump tiles [sites, pip speed, wires], STREAM THE MOTHERFUCKER, gather nodes
     let mut node_sp = StringPool::new();
-    let mut nodes: HashMap<String, Vec<(u32, u32, u32)>> = HashMap::new();
-    for batch in tile_names.chunks(TILE_BATCH_SIZE) {
-        let mut tlist: Vec<u8> = Vec::new();
-        for t in batch {
-            tlist.write(t.as_bytes())?;
-            tlist.write(b"\n")?;
-        }
-        let tr = ToolchainReader::new(tc, "vivado", &["-nolog", "-nojournal", "-mode", "batch", "-source", "script.tcl", "-tclargs", &fpart.name], &[], "tiles.fifo", &[("script.tcl", DUMP_TILES_TCL.as_bytes()), ("tiles.list", &tlist)])?;
-        let lines = tr.lines();
-        let mut got_end = false;
+    let mut nodes = NodeAccumulator::new(NODE_BUFFER_WIRES);
+    let mut dump_batch = |bidx: usize, lines: &mut dyn Iterator<Item = io::Result<String>>| -> Result<(), Error> {
         let mut tile: Option<String> = None;
         let mut wpref: String = String::new();
         let mut ppref: String = String::new();
         let mut tt: Option<String> = None;
         let mut coord: Option<Coord> = None;
         let mut wires: Vec<(String, u32)> = Vec::new();
-        let mut pips: Vec<(&str, &str, bool, bool, bool, TkPipInversion, TkPipDirection, Option<&str>)> = Vec::new();
+        let mut pips: Vec<TilePip> = Vec::new();
         let mut ttt_pips: Option<&HashMap<String, TtPip>> = None;
         let mut tile_n2w: HashMap<String, Vec<u32>> = HashMap::new();
-        let mut site_pins: Vec<(String, TkSitePinDir, Option<String>, Option<&str>)> = Vec::new();
+        let mut site_pins: Vec<SitePin> = Vec::new();
         let mut site: Option<(String, String)> = None;
         let mut spref: String = String::new();
-        let mut sites: Vec<(String, String, Vec<(String, TkSitePinDir, Option<String>, Option<&str>)>)> = Vec::new();
-        for l in lines {
-            let l = l?;
-            let sl: Vec<_> = l.split_whitespace().collect();
-            match sl[0] {
-                "TILE" => {
-                    assert!(tile.is_none());
+        let mut sites: Vec<(String, String, Vec<SitePin>)> = Vec::new();
+        parse_lines(&format!("{} tiles-{}", fpart.name, bidx), lines, |sl| {
+            match sl.first().map(|s| &s[..]) {
+                Some("TILE") => {
+                    if tile.is_some() {
+                        return Err(Error::ParseError("nested TILE".to_string()));
+                    }
+                    let name = field(sl, 3)?;
+                    let kind = field(sl, 4)?;
+                    let gy: u16 = field(sl, 2)?.parse()?;
+                    if gy >= height {
+                        return Err(Error::ParseError(format!("tile y {} out of range", gy)));
+                    }
                     coord = Some(Coord {
-                        x: sl[1].parse()?,
-                        y: height - 1 - sl[2].parse::<u16>()?,
+                        x: field(sl, 1)?.parse()?,
+                        y: height - 1 - gy,
                     });
-                    tile = Some(sl[3].to_string());
-                    tt = Some(sl[4].to_string());
-                    wpref = sl[3].to_string() + "/";
-                    ppref = sl[3].to_string() + "/" + sl[4] + ".";
-                    ttt_pips = Some(tt_pips.get(sl[4]).unwrap());
+                    tile = Some(name.to_string());
+                    tt = Some(kind.to_string());
+                    wpref = name.to_string() + "/";
+                    ppref = name.to_string() + "/" + kind + ".";
+                    ttt_pips = match tt_pips.get(kind) {
+                        Some(p) => Some(p),
+                        None => return Err(Error::ParseError(format!("unknown tile type {}", kind))),
+                    };
                 },
-                "SITE" => {
-                    assert!(site.is_none());
-                    site = Some((sl[1].to_string(), sl[2].to_string()));
-                    spref = sl[1].to_string() + "/";
+                Some("SITE") => {
+                    if site.is_some() {
+                        return Err(Error::ParseError("nested SITE".to_string()));
+                    }
+                    let name = field(sl, 1)?;
+                    site = Some((name.to_string(), field(sl, 2)?.to_string()));
+                    spref = name.to_string() + "/";
                 },
-                "SITEPIN" => {
-                    assert!(!site.is_none());
-                    let pin = sl[1][1..].strip_prefix(&spref).unwrap();
-                    let dir = match &sl[2][1..] {
+                Some("SITEPIN") => {
+                    if site.is_none() {
+                        return Err(Error::ParseError("SITEPIN outside of SITE".to_string()));
+                    }
+                    let pin = strip_pref(field(sl, 1)?, &spref)?;
+                    let dir = match field(sl, 2)? {
                         "IN" => TkSitePinDir::Input,
                         "OUT" => TkSitePinDir::Output,
                         "INOUT" => TkSitePinDir::Bidir,
-                        _ => panic!("weird pin dir {}", &sl[2][1..]),
-                    };
-                    let si = &sl[3][1..];
-                    let node = &sl[4][1..];
-                    let speed: Option<&str> = if si == "" {
-                        None
-                    } else {
-                        Some(speed_models.get(&si.parse::<u32>().unwrap()).unwrap())
-                    };
-                    let wire: Option<String> = match tile_n2w.get(node) {
-                        None => None,
-                        Some(v) => {
-                            let mut v = v.clone();
-                            if v.len() > 1 && match pin {
-                                "DOUT" => true,
-                                "SYSREF_OUT_NORTH_P" => true,
-                                "SYSREF_OUT_SOUTH_P" => true,
-                                "CLK_DISTR_OUT_NORTH" => true,
-                                "CLK_DISTR_OUT_SOUTH" => true,
-                                "T1_ALLOWED_SOUTH" => true,
-                                _ => false,
-                            } {
-                                let suffix = format!("_{}", pin);
-                                v.retain(|n| node_sp.get(*n).ends_with(&suffix));
-                            }
-                            if v.len() == 1 {
-                                Some(node_sp.get(v[0]).to_string())
-                            } else {
-                                panic!("SITE PIN WIRE AMBIGUOUS {:?} {:?}", sl, v.iter().map(|n| node_sp.get(*n)).collect::<Vec<_>>());
-                            }
-                        }
+                        d => return Err(Error::ParseError(format!("weird pin dir {}", d))),
                     };
+                    let speed = opt_speed_model(&speed_models, field(sl, 3)?)?;
+                    let wire = site_pin_wire(sl, &wpref, &tile_n2w, &node_sp)?;
                     site_pins.push((pin.to_string(), dir, wire, speed));
                 },
-                "ENDSITE" => {
-                    let (sname, skind) = site.unwrap();
-                    site = None;
-                    sites.push((sname, skind, site_pins));
-                    site_pins = Vec::new();
+                Some("ENDSITE") => {
+                    let (sname, skind) = match site.take() {
+                        Some(s) => s,
+                        None => return Err(Error::ParseError("ENDSITE outside of SITE".to_string())),
+                    };
+                    sites.push((sname, skind, std::mem::take(&mut site_pins)));
                 },
-                "WIRE" => {
-                    assert!(!tile.is_none());
-                    let name = sl[1].strip_prefix(&wpref).unwrap();
-                    let si = sl[2].parse::<u32>().unwrap();
-                    let node = &sl[3][1..];
+                Some("WIRE") => {
+                    let tname = match &tile {
+                        Some(t) => t,
+                        None => return Err(Error::ParseError("WIRE outside of TILE".to_string())),
+                    };
+                    let name = strip_pref(field(sl, 1)?, &wpref)?;
+                    let si: u32 = field(sl, 2)?.parse()?;
+                    speed_model(&speed_models, si)?;
+                    let node = field(sl, 3)?;
                     wires.push((name.to_string(), si));
-                    if node != "" {
-                        let nwires = nodes.entry(node.to_string()).or_insert(Vec::new());
-                        nwires.push((node_sp.put(tile.as_ref().unwrap()), node_sp.put(name), si));
-                        let n2w = tile_n2w.entry(node.to_string()).or_insert(Vec::new());
+                    if !node.is_empty() {
+                        let (nt, nw) = node.split_once('/').unwrap_or(("", node));
+                        let nkey = (node_sp.put(nt), node_sp.put(nw));
+                        nodes.add(nkey, node_sp.put(tname), node_sp.put(name), si)?;
+                        let n2w = tile_n2w.entry(node.to_string()).or_default();
                         n2w.push(node_sp.put(name));
                     }
                 },
-                "PIP" => {
-                    assert!(!tile.is_none());
-                    let name = sl[1][1..].strip_prefix(&ppref).unwrap();
-                    let si = &sl[2][1..];
-                    let pip = ttt_pips.unwrap().get(name).unwrap();
+                Some("PIP") => {
+                    let ttt_pips = match ttt_pips {
+                        Some(p) if tile.is_some() => p,
+                        _ => return Err(Error::ParseError("PIP outside of TILE".to_string())),
+                    };
+                    let name = strip_pref(field(sl, 1)?, &ppref)?;
+                    let pip = match ttt_pips.get(name) {
+                        Some(pip) => pip,
+                        None => return Err(Error::ParseError(format!("pip {} not in tile type", name))),
+                    };
                     if pip.is_pseudo {
-                        continue;
+                        return Ok(false);
                     }
-                    let speed: Option<&str> = if si == "" {
-                        None
-                    } else {
-                        Some(speed_models.get(&si.parse::<u32>().unwrap()).unwrap())
-                    };
+                    let speed = opt_speed_model(&speed_models, field(sl, 2)?)?;
                     if pip.is_bidi {
                         pips.push((
                             &pip.wire_from,
@@ -403,89 +517,112 @@ pub fn get_rawdump(tc: &Toolchain, parts: &[VivadoPart]) -> Result<Part, Error>
                         ));
                     }
                 },
-                "ENDTILE" => {
-                    assert!(site.is_none());
-                    assert!(!tile.is_none());
-                    rd.add_tile(coord.unwrap(), tile.unwrap(), tt.unwrap(),
+                Some("ENDTILE") => {
+                    if site.is_some() {
+                        return Err(Error::ParseError("ENDTILE inside SITE".to_string()));
+                    }
+                    let (coord, tile, tt) = match (coord.take(), tile.take(), tt.take()) {
+                        (Some(c), Some(t), Some(k)) => (c, t, k),
+                        _ => return Err(Error::ParseError("ENDTILE outside of TILE".to_string())),
+                    };
+                    let mut twires = Vec::new();
+                    for (w, s) in wires.iter() {
+                        twires.push((&w[..], Some(speed_model(&speed_models, *s)?)));
+                    }
+                    rd.add_tile(coord, tile, tt,
                         &sites.iter().map(|(n, t, p)| -> (&str, &str, _) {
-                            (&n, &t, p.iter().map(|(n, d, w, s)| -> (&str, TkSitePinDir, Option<&str>, Option<&str>) {
+                            (n, t, p.iter().map(|(n, d, w, s)| -> (&str, TkSitePinDir, Option<&str>, Option<&str>) {
                                 (n, *d, w.as_ref().map(|s| &s[..]), *s)
                             }).collect::<Vec<_>>())
                         }).collect::<Vec<_>>(),
-                        &wires.iter().map(|(w, s)| -> (&str, Option<&str>) {
-                            (w, Some(&speed_models.get(&s).unwrap()[..]))
-                        }).collect::<Vec<_>>(),
+                        &twires,
                         &pips,
                     );
-                    coord = None;
-                    tile = None;
-                    tt = None;
                     wires = Vec::new();
                     pips = Vec::new();
                     sites = Vec::new();
                     tile_n2w = HashMap::new();
                 },
-                "END" => {
-                    assert!(tile.is_none());
-                    got_end = true;
-                    break;
+                Some("END") => {
+                    if tile.is_some() {
+                        return Err(Error::ParseError("END inside TILE".to_string()));
+                    }
+                    return Ok(true);
                 },
-                _ => panic!("unknown line {}", sl[0]),
+                _ => return Err(Error::ParseError("unknown line".to_string())),
             }
+            Ok(false)
+        })
+    };
+    let batches: Vec<_> = tile_names.chunks(TILE_BATCH_SIZE).map(|batch| with_tiles(DUMP_TILES_TCL, batch.iter())).collect();
+    if jobs <= 1 {
+        for (bidx, script) in batches.iter().enumerate() {
+            dump_batch(bidx, &mut session.query(&format!("tiles-{}", bidx), script)?.lines())?;
         }
-        if !got_end {
-            return Err(Error::ParseError(format!("missing END in tiles")));
-        }
+    } else {
+        let mut bidx = 0;
+        let fork = || {
+            let mut session = session.fork();
+            let batches = &batches;
+            move |bidx: usize| -> Result<Vec<String>, Error> {
+                session.open_part(&fpart.name);
+                Ok(session.query(&format!("tiles-{}", bidx), &batches[bidx])?.lines().collect::<Result<Vec<_>, _>>()?)
+            }
+        };
+        run_parallel(batches.len(), jobs, fork, &mut |lines| {
+            bidx += 1;
+            dump_batch(bidx - 1, &mut lines.into_iter().map(Ok))
+        })?;
     }
 
     // STEP 4: stream nodes
-    for (_, v) in nodes {
-        rd.add_node(&v.into_iter().map(|(t, w, s)| -> (&str, &str, Option<&str>) {
-            (node_sp.get(t), node_sp.get(w), Some(speed_models.get(&s).unwrap()))
-        }).collect::<Vec<_>>());
+    nodes.finish(|v| {
+        let mut wires = Vec::new();
+        for &(t, w, s) in v {
+            wires.push((node_sp.get(t), node_sp.get(w), Some(speed_model(&speed_models, s)?)));
+        }
+        rd.add_node(&wires);
+        Ok(())
+    })?;
+
+    // STEP 5: Versal hard blocks
+    if fpart.actual_family == Family::Versal {
+        dump_versal(session, &fpart.name, &mut rd)?;
     }
 
-    // STEP 5: dump packages
+    // STEP 6: dump packages
     for part in parts.iter() {
         if rd.part.packages.contains_key(&part.package) {
             continue;
         }
         let mut pins: Vec<PkgPin> = Vec::new();
-        let tr = ToolchainReader::new(tc, "vivado", &["-nolog", "-nojournal", "-mode", "batch", "-source", "script.tcl", "-tclargs", &part.name], &[], "pkgpins.fifo", &[("script.tcl", DUMP_PKGPINS_TCL.as_bytes())])?;
-        let lines = tr.lines();
-        let mut got_end = false;
-        for l in lines {
-            let l = l?;
-            let sl: Vec<_> = l.split_whitespace().collect();
-            match sl[0] {
-                "END" => {
-                    got_end = true;
-                    break;
-                },
-                "PKGPIN" => {
-                    let pin = sl[1];
-                    let site = &sl[2][1..];
-                    let bank = &sl[3][1..];
-                    let func = &sl[4][1..];
-                    let mind = &sl[5][1..];
-                    let maxd = &sl[6][1..];
+        session.open_part(&part.name);
+        let key = format!("pkgpins-{}", part.package);
+        parse_lines(&format!("{} {}", part.name, key), &mut session.query(&key, DUMP_PKGPINS_TCL)?.lines(), |sl| {
+            match sl.first().map(|s| &s[..]) {
+                Some("END") => return Ok(true),
+                Some("PKGPIN") => {
+                    let pin = field(sl, 1)?;
+                    let site = field(sl, 2)?;
+                    let bank = field(sl, 3)?;
+                    let func = field(sl, 4)?;
+                    let mind = field(sl, 5)?;
+                    let maxd = field(sl, 6)?;
                     pins.push(PkgPin {
-                        pad: if site == "" { None } else { Some(site.to_string()) },
+                        pad: if site.is_empty() { None } else { Some(site.to_string()) },
                         pin: pin.to_string(),
-                        vref_bank: if bank == "" { None } else { Some(bank.parse()?) },
-                        vcco_bank: if bank == "" { None } else { Some(bank.parse()?) },
+                        vref_bank: if bank.is_empty() { None } else { Some(bank.parse()?) },
+                        vcco_bank: if bank.is_empty() { None } else { Some(bank.parse()?) },
                         func: func.to_string(),
                         tracelen_um: None,
-                        delay_min_fs: if mind == "" { None } else { Some(mind.parse()?) },
-                        delay_max_fs: if maxd == "" { None } else { Some(maxd.parse()?) },
+                        delay_min_fs: if mind.is_empty() { None } else { Some(mind.parse()?) },
+                        delay_max_fs: if maxd.is_empty() { None } else { Some(maxd.parse()?) },
                     });
                 },
-                _ => panic!("unknown line {}", sl[0]),
+                _ => return Err(Error::ParseError("unknown line".to_string())),
             }
-        }
-        if !got_end {
-            return Err(Error::ParseError(format!("missing END in tiles")));
-        }
+            Ok(false)
+        })?;
         rd.add_package(part.package.to_string(), pins);
     }
 
@@ -495,3 +632,61 @@ pub fn get_rawdump(tc: &Toolchain, parts: &[VivadoPart]) -> Result<Part, Error>
 
     Ok(rd.finish())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::thread;
+    use std::time::Duration;
+    use crate::error::Error;
+    use crate::stringpool::StringPool;
+    use super::super::session::decode_record;
+
+    #[test]
+    fn site_pin_wire_test() {
+        let mut sp = StringPool::new();
+        let mut n2w = HashMap::new();
+        n2w.insert("INT_X0Y0/IMUX0".to_string(), vec![sp.put("IMUX_L0")]);
+        n2w.insert("INT_X0Y0/IMUX1".to_string(), vec![sp.put("IMUX_L1"), sp.put("IMUX_R1")]);
+        let rec = |l: &str| decode_record(&format!("SITEPIN {}", l)).unwrap();
+        let wire = |l: &str| super::site_pin_wire(&rec(l), "CLBLL_L_X2Y0/", &n2w, &sp);
+        assert_eq!(wire("SLICE_X0Y0/A1 IN \\- INT_X0Y0/IMUX0").unwrap(), Some("IMUX_L0".to_string()));
+        assert_eq!(wire("SLICE_X0Y0/A2 IN \\- INT_X0Y0/IMUX2").unwrap(), None);
+        assert_eq!(wire("SLICE_X0Y0/A3 IN \\- \\-").unwrap(), None);
+        assert!(wire("SLICE_X0Y0/A4 IN \\- INT_X0Y0/IMUX1").is_err());
+        assert_eq!(wire("SLICE_X0Y0/A5 IN \\- INT_X0Y0/IMUX1 CLBLL_L_X2Y0/CLBLL_L_A5").unwrap(), Some("CLBLL_L_A5".to_string()));
+        assert!(wire("SLICE_X0Y0/A6 IN \\- INT_X0Y0/IMUX1 CLBLL_L_X2Y0/CLBLL_L_A6 CLBLL_L_X2Y0/CLBLL_L_A6_ALT").is_err());
+    }
+
+    #[test]
+    fn run_parallel_test() {
+        // Later batches finish first.
+        let fork = || |bidx: usize| -> Result<Vec<String>, Error> {
+            thread::sleep(Duration::from_millis(5 * (20 - bidx as u64)));
+            Ok(vec![bidx.to_string()])
+        };
+        let mut res = Vec::new();
+        super::run_parallel(20, 4, fork, &mut |lines| {
+            res.extend(lines);
+            Ok(())
+        }).unwrap();
+        assert_eq!(res, (0..20).map(|i| i.to_string()).collect::<Vec<_>>());
+
+        let fork = || |bidx: usize| -> Result<Vec<String>, Error> {
+            if bidx == 7 {
+                Err(Error::ToolError("batch failed".to_string()))
+            } else {
+                Ok(vec![bidx.to_string()])
+            }
+        };
+        let mut res = Vec::new();
+        assert!(super::run_parallel(20, 4, fork, &mut |lines| {
+            res.extend(lines);
+            Ok(())
+        }).is_err());
+        // A failure stops the run as soon as it comes in, but whatever was
+        // consumed before still came in order.
+        assert!(res.len() <= 7);
+        assert_eq!(res, (0..res.len()).map(|i| i.to_string()).collect::<Vec<_>>());
+    }
+}
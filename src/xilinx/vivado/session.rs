@@ -0,0 +1,312 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::{ChildStdin, ChildStdout, Stdio};
+use std::time::Duration;
+use tempdir::TempDir;
+use crate::toolcache::{ToolCache, ToolRunner};
+use crate::toolreader::ToolProcess;
+use crate::error::Error;
+
+// The driver loop.  Requests come in on stdin as a length line followed by
+// that many bytes of Tcl.  Replies go to stdout, with every line of ours
+// prefixed by @@ to tell it apart from Vivado's own messages: @@BEGIN, then
+// "@@ " + each record passed to emit, then @@END or @@ERROR <message>.
+//
+// A record is a list of fields separated by single spaces.  Within a field,
+// backslash, space, tab, CR and LF are escaped as \\, \s, \t, \r and \n,
+// and an empty field is written as \-, so that any string survives the trip
+// and a record always takes exactly one line.  See `decode_record`.
+const SESSION_TCL: &str = r#"
+fconfigure stdin -translation binary
+fconfigure stdout -buffering line
+proc emit {args} {
+    set fields {}
+    foreach f $args {
+        if {$f eq ""} {
+            lappend fields "\\-"
+        } else {
+            lappend fields [string map {"\\" "\\\\" " " "\\s" "\t" "\\t" "\r" "\\r" "\n" "\\n"} $f]
+        }
+    }
+    puts "@@ [join $fields " "]"
+}
+while {[gets stdin len] >= 0} {
+    set script [read stdin $len]
+    puts "@@BEGIN"
+    if {[catch {uplevel #0 $script} err]} {
+        puts "@@ERROR [string map {"\n" " "} $err]"
+    } else {
+        puts "@@END"
+    }
+}
+"#;
+
+// Field order matters: stdin is closed first, which ends the driver loop,
+// and only then does the ToolProcess drop reap Vivado, killing it if it is
+// still around.
+struct LiveSession {
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    process: ToolProcess,
+    timeout: Option<Duration>,
+    _dir: TempDir,
+}
+
+/// A long-lived Vivado process driven by Tcl requests, so that several dump
+/// steps share one startup and `link_design`.  When the runner replays
+/// a cache, no Vivado is started at all and replies come from the cache.
+pub struct Session<'a> {
+    tr: &'a ToolRunner<'a>,
+    live: Option<LiveSession>,
+    part: Option<String>,
+    linked: Option<String>,
+    checkpoints: Option<ToolCache>,
+}
+
+/// The data lines of one reply, as a byte stream.  Whatever is not read
+/// is skipped on drop, so that the session is ready for the next request.
+struct Reply<'b> {
+    session: &'b mut LiveSession,
+    line: Vec<u8>,
+    pos: usize,
+    done: bool,
+}
+
+impl<'b> Reply<'b> {
+    fn next_line(&mut self) -> io::Result<bool> {
+        loop {
+            self.line.clear();
+            self.pos = 0;
+            if self.session.stdout.read_until(b'\n', &mut self.line)? == 0 {
+                self.done = true;
+                self.line.clear();
+                self.session.process.check()?;
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "vivado session died"));
+            }
+            if let Some(l) = self.line.strip_prefix(b"@@ ") {
+                let l = l.to_vec();
+                self.line = l;
+                return Ok(true);
+            } else if self.line.starts_with(b"@@END") {
+                self.done = true;
+                self.line.clear();
+                return Ok(false);
+            } else if let Some(msg) = self.line.strip_prefix(b"@@ERROR ") {
+                self.done = true;
+                let msg = String::from_utf8_lossy(msg).trim_end().to_string();
+                self.line.clear();
+                return Err(Error::ToolError(format!("vivado error: {}", msg)).into());
+            }
+            // Vivado's own chatter.
+        }
+    }
+}
+
+impl<'b> Read for Reply<'b> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos == self.line.len() && (self.done || !self.next_line()?) {
+            return Ok(0);
+        }
+        let n = std::cmp::min(buf.len(), self.line.len() - self.pos);
+        buf[..n].copy_from_slice(&self.line[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+impl<'b> Drop for Reply<'b> {
+    fn drop(&mut self) {
+        while !self.done {
+            match self.next_line() {
+                Ok(_) => (),
+                Err(_) => break,
+            }
+        }
+        self.session.process.set_timeout(None);
+    }
+}
+
+impl LiveSession {
+    fn start(tr: &ToolRunner) -> Result<Self, Error> {
+        let tc = tr.toolchain().unwrap();
+        let dir = TempDir::new("vivado")?;
+        std::fs::write(dir.path().join("session.tcl"), SESSION_TCL)?;
+        let mut cmd = tc.command("vivado");
+        cmd.current_dir(dir.path().as_os_str());
+        cmd.args(["-nolog", "-nojournal", "-mode", "batch", "-source", "session.tcl"]);
+        cmd.stdin(Stdio::piped());
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::null());
+        let mut process = ToolProcess::spawn("vivado", cmd, None, None)?;
+        let (stdin, stdout) = process.take_pipes();
+        Ok(LiveSession {
+            stdin: stdin.unwrap(),
+            stdout: BufReader::new(stdout.unwrap()),
+            process,
+            timeout: tc.timeout("vivado"),
+            _dir: dir,
+        })
+    }
+
+    fn request(&mut self, script: &str) -> Result<Reply<'_>, Error> {
+        self.process.set_timeout(self.timeout);
+        writeln!(self.stdin, "{}", script.len())?;
+        self.stdin.write_all(script.as_bytes())?;
+        self.stdin.flush()?;
+        let mut reply = Reply {
+            session: self,
+            line: Vec::new(),
+            pos: 0,
+            done: false,
+        };
+        loop {
+            reply.line.clear();
+            if reply.session.stdout.read_until(b'\n', &mut reply.line)? == 0 {
+                reply.done = true;
+                reply.session.process.check()?;
+                return Err(Error::ToolError("vivado session died".to_string()));
+            }
+            if reply.line.starts_with(b"@@BEGIN") {
+                reply.line.clear();
+                return Ok(reply);
+            }
+        }
+    }
+}
+
+/// Splits a record written by the session's `emit` back into its fields.
+pub fn decode_record(l: &str) -> Result<Vec<String>, Error> {
+    let mut res = Vec::new();
+    for f in l.split(' ') {
+        if f == "\\-" {
+            res.push(String::new());
+            continue;
+        }
+        if f.is_empty() {
+            return Err(Error::ParseError("empty field".to_string()));
+        }
+        let mut field = String::with_capacity(f.len());
+        let mut chars = f.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                field.push(c);
+                continue;
+            }
+            field.push(match chars.next() {
+                Some('\\') => '\\',
+                Some('s') => ' ',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('n') => '\n',
+                Some(c) => return Err(Error::ParseError(format!("unknown escape \\{}", c))),
+                None => return Err(Error::ParseError("dangling backslash".to_string())),
+            });
+        }
+        res.push(field);
+    }
+    Ok(res)
+}
+
+impl<'a> Session<'a> {
+    pub fn new(tr: &'a ToolRunner<'a>) -> Self {
+        Session {
+            tr,
+            live: None,
+            part: None,
+            linked: None,
+            checkpoints: None,
+        }
+    }
+
+    /// Keeps every complete reply in the given cache, and answers queries
+    /// already found there without asking Vivado.  This makes a dump that
+    /// died halfway resumable.
+    pub fn with_checkpoints(mut self, cache: ToolCache) -> Self {
+        self.checkpoints = Some(cache);
+        self
+    }
+
+    /// Another session on the same runner and checkpoints, with nothing
+    /// loaded.
+    pub fn fork(&self) -> Self {
+        Session {
+            checkpoints: self.checkpoints.clone(),
+            ..Session::new(self.tr)
+        }
+    }
+
+    fn live(&mut self) -> Result<&mut LiveSession, Error> {
+        if self.live.is_none() {
+            self.live = Some(LiveSession::start(self.tr)?);
+        }
+        let live = self.live.as_mut().unwrap();
+        if self.linked != self.part {
+            self.linked = None;
+            if let Some(part) = &self.part {
+                let script = format!("catch close_design\nlink_design -part {{{}}}\n", part);
+                io::copy(&mut live.request(&script)?, &mut io::sink())?;
+            }
+            self.linked = self.part.clone();
+        }
+        Ok(live)
+    }
+
+    /// Selects the part that the following queries are about.  The design
+    /// is only linked once a query actually needs Vivado.
+    pub fn open_part(&mut self, part: &str) {
+        self.part = Some(part.to_string());
+    }
+
+    /// Runs a script, returning the lines it emits.  `key` names the reply
+    /// in the tool output cache and the checkpoints.
+    pub fn query(&mut self, key: &str, script: &str) -> Result<Box<dyn BufRead + '_>, Error> {
+        let tr = self.tr;
+        if tr.toolchain().is_none() {
+            return tr.cache().unwrap().replay(key);
+        }
+        if let Some(ckpt) = self.checkpoints.clone() {
+            if !ckpt.has(key) {
+                let mut data = Vec::new();
+                self.live()?.request(script)?.read_to_end(&mut data)?;
+                ckpt.store(key, &data)?;
+            }
+            let reply = ckpt.replay(key)?;
+            return match tr.cache() {
+                Some(cache) => cache.record(key, reply),
+                None => Ok(reply),
+            };
+        }
+        let reply = self.live()?.request(script)?;
+        match tr.cache() {
+            Some(cache) => cache.record(key, reply),
+            None => Ok(Box::new(BufReader::new(reply))),
+        }
+    }
+}
+
+// Feeds the lines of a script's output to `f` until it reports END (by
+// returning true), pinning any parse error to the line it happened on.
+pub fn parse_lines(source: &str, lines: &mut dyn Iterator<Item = io::Result<String>>, mut f: impl FnMut(&[String]) -> Result<bool, Error>) -> Result<(), Error> {
+    for (lno, l) in lines.enumerate() {
+        let l = l?;
+        match decode_record(&l).and_then(|sl| f(&sl)) {
+            Ok(true) => return Ok(()),
+            Ok(false) => (),
+            Err(e) => return Err(e.at(source, lno + 1, &l)),
+        }
+    }
+    Err(Error::ParseError(format!("{}: missing END", source)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode_record;
+
+    #[test]
+    fn decode_record_test() {
+        assert_eq!(decode_record("PIP a\\sb \\- c\\\\d\\n").unwrap(), ["PIP", "a b", "", "c\\d\n"]);
+        assert_eq!(decode_record("\\-").unwrap(), [""]);
+        assert!(decode_record("a  b").is_err());
+        assert!(decode_record("a\\x").is_err());
+        assert!(decode_record("a\\").is_err());
+    }
+}
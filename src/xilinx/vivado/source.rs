@@ -0,0 +1,119 @@
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+use crate::error::Error;
+use crate::toolcache::{ToolCache, ToolRunner};
+use crate::toolchain::{Toolchain, ToolchainSet};
+use crate::xilinx::family::Family;
+use crate::xilinx::rawdump::Part;
+use crate::xilinx::source::DeviceSource;
+use super::parts::{get_parts, VivadoPart};
+use super::rawdump::get_rawdump;
+use super::session::Session;
+
+// The toolchains parts of the given families may be dumped with: those
+// they map to directly, and those of architectures within them.
+fn toolchain_names<'a>(tcs: &'a ToolchainSet, families: &HashSet<String>) -> Result<BTreeSet<&'a str>, Error> {
+    let mut res = BTreeSet::new();
+    for f in families {
+        match tcs.families.get(f).or(tcs.default.as_ref()) {
+            Some(name) => res.insert(&name[..]),
+            None => return Err(Error::ParseError(format!("no toolchain for {}", f))),
+        };
+    }
+    for (arch, name) in tcs.families.iter() {
+        if Family::from_vivado_arch(arch).is_some_and(|f| families.contains(f.name())) {
+            res.insert(name);
+        }
+    }
+    Ok(res)
+}
+
+/// Parts as known to Vivado, dumped through a Tcl session.
+pub struct VivadoSource {
+    /// Number of Vivado processes dumping tiles of a single device.
+    pub jobs: usize,
+}
+
+impl DeviceSource for VivadoSource {
+    type Part = VivadoPart;
+
+    const META_KEY: &'static str = "vivado-parts";
+
+    const RESUMABLE: bool = true;
+
+    /// Besides our family names, Vivado architectures and device names
+    /// are accepted.  Every toolchain the selected families may be dumped
+    /// with is asked, since a newer Vivado knows parts an older one does not.
+    fn list_parts(&self, tcs: &ToolchainSet, families: &HashSet<String>) -> Result<Vec<VivadoPart>, Error> {
+        let mut parts: Vec<VivadoPart> = Vec::new();
+        let mut seen: HashSet<String> = HashSet::new();
+        for name in toolchain_names(tcs, families)? {
+            let tr = ToolRunner::Live(tcs.by_name(name)?);
+            for part in get_parts(&mut Session::new(&tr))? {
+                if seen.insert(part.name.clone()) {
+                    parts.push(part);
+                }
+            }
+        }
+        parts.retain(|part| families.contains(part.actual_family.name()) || families.contains(&part.arch) || families.contains(&part.device));
+        Ok(parts)
+    }
+
+    fn device(part: &VivadoPart) -> &str {
+        &part.device
+    }
+
+    fn family(part: &VivadoPart) -> Family {
+        part.actual_family
+    }
+
+    fn variant(part: &VivadoPart) -> &str {
+        &part.name
+    }
+
+    // Newer architectures may need a newer Vivado than the rest of their
+    // family, so the toolchain can be picked per architecture.
+    fn toolchain<'a>(&self, tcs: &'a ToolchainSet, parts: &[VivadoPart]) -> Result<&'a Toolchain, Error> {
+        if tcs.families.contains_key(&parts[0].arch) {
+            tcs.for_family(&parts[0].arch)
+        } else {
+            tcs.for_family(parts[0].actual_family.name())
+        }
+    }
+
+    /// Finished queries are checkpointed in `work`, so that an interrupted
+    /// dump picks up where it stopped.
+    fn dump_device(&self, tr: &ToolRunner, parts: &[VivadoPart], work: Option<&Path>) -> Result<Part, Error> {
+        let mut session = Session::new(tr);
+        if let Some(work) = work {
+            session = session.with_checkpoints(ToolCache::new(work));
+        }
+        get_rawdump(&mut session, parts, self.jobs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::toolchain::{Toolchain, ToolchainSet};
+
+    #[test]
+    fn toolchain_names_test() {
+        let tcs = ToolchainSet {
+            toolchains: ["v2020", "v2024", "v2025"].iter().map(|n| (n.to_string(), Toolchain::default())).collect(),
+            default: Some("v2020".to_string()),
+            families: vec![
+                ("versal".to_string(), "v2024".to_string()),
+                ("spartanuplus".to_string(), "v2025".to_string()),
+            ].into_iter().collect(),
+        };
+        let names = |fams: &[&str]| {
+            let fams: HashSet<String> = fams.iter().map(|s| s.to_string()).collect();
+            super::toolchain_names(&tcs, &fams).unwrap().into_iter().collect::<Vec<_>>()
+        };
+        assert_eq!(names(&["7series"]), ["v2020"]);
+        assert_eq!(names(&["versal"]), ["v2024"]);
+        assert_eq!(names(&["ultrascaleplus"]), ["v2020", "v2025"]);
+        assert_eq!(names(&["spartanuplus", "xcvc1902"]), ["v2020", "v2025"]);
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+use crate::error::Error;
+use crate::xilinx::rawdump::VersalSiteKind;
+use crate::xilinx::rdbuild::PartBuilder;
+use super::session::{Session, parse_lines};
+use super::rawdump::field;
+
+// NoC links start at the output pins of NoC sites and are followed through
+// the pips of NoC tiles (the NPS switch crossbars, among others), but not
+// through general routing, until they reach the pins of other sites.
+const DUMP_VERSAL_TCL: &str = r#"
+set noc {}
+set noctiles {}
+foreach x [get_sites -quiet] {
+    set type [get_property SITE_TYPE $x]
+    if {[regexp {^(NOC_|AIE|CPM|PS9|PSX)} $type]} {
+        set tile [get_tiles -of $x]
+        emit SITE $x $type $tile
+        if {[string match NOC_* $type]} {
+            lappend noc $x
+            dict set noctiles $tile 1
+        }
+    }
+}
+foreach x $noc {
+    foreach pin [get_site_pins -quiet -of $x -filter {DIRECTION == OUT}] {
+        set seen {}
+        set todo {}
+        foreach node [get_nodes -quiet -of $pin] {
+            lappend todo [list $node {}]
+        }
+        while {[llength $todo]} {
+            lassign [lindex $todo 0] node path
+            set todo [lrange $todo 1 end]
+            if {[dict exists $seen $node]} {
+                continue
+            }
+            dict set seen $node 1
+            set reached 0
+            foreach other [get_site_pins -quiet -of $node] {
+                if {$other ne $pin} {
+                    emit NOCLINK $pin $other {*}$path
+                    set reached 1
+                }
+            }
+            if {$reached} {
+                continue
+            }
+            foreach pip [get_pips -quiet -downhill -of $node] {
+                if {[dict exists $noctiles [get_tiles -of $pip]]} {
+                    foreach next [get_nodes -quiet -downhill -of $pip] {
+                        lappend todo [list $next [concat $path [list $pip]]]
+                    }
+                }
+            }
+        }
+    }
+}
+emit END
+"#;
+
+fn site_kind(kind: &str) -> Option<VersalSiteKind> {
+    Some(if kind.starts_with("NOC_NPS") {
+        VersalSiteKind::NocSwitch
+    } else if kind.starts_with("NOC_NMU") {
+        VersalSiteKind::NocNmu
+    } else if kind.starts_with("NOC_NSU") {
+        VersalSiteKind::NocNsu
+    } else if kind.starts_with("NOC_") {
+        VersalSiteKind::NocOther
+    } else if kind.starts_with("AIE") && kind.contains("CORE") {
+        VersalSiteKind::AieCore
+    } else if kind.starts_with("AIE") && kind.contains("MEM") {
+        VersalSiteKind::AieMemory
+    } else if kind.starts_with("AIE") && (kind.contains("PL") || kind.contains("NOC") || kind.contains("SHIM")) {
+        VersalSiteKind::AieShim
+    } else if kind.starts_with("CPM") {
+        VersalSiteKind::Cpm
+    } else if kind.starts_with("PS9") || kind.starts_with("PSX") {
+        VersalSiteKind::Ps
+    } else {
+        return None
+    })
+}
+
+/// Records the NoC, AI Engine and CPM/PS sites of a Versal part, and the
+/// links between NoC sites.  All tiles must already be in the builder.
+pub fn dump_versal(session: &mut Session, part: &str, rd: &mut PartBuilder) -> Result<(), Error> {
+    parse_versal(&format!("{} versal", part), &mut session.query("versal", DUMP_VERSAL_TCL)?.lines(), rd)
+}
+
+fn parse_versal(source: &str, lines: &mut dyn Iterator<Item = io::Result<String>>, rd: &mut PartBuilder) -> Result<(), Error> {
+    let mut sites: HashMap<String, usize> = HashMap::new();
+    parse_lines(source, lines, |sl| {
+        match sl.first().map(|s| &s[..]) {
+            Some("END") => return Ok(true),
+            Some("SITE") => {
+                let name = field(sl, 1)?;
+                let kind = field(sl, 2)?;
+                let tile = field(sl, 3)?;
+                // Odd AIE site kinds, if any, are skipped.
+                if let Some(kind) = site_kind(kind) {
+                    match rd.add_versal_site(name.to_string(), kind, tile) {
+                        Some(idx) => { sites.insert(name.to_string(), idx); },
+                        None => return Err(Error::ParseError(format!("site {} in unknown tile {}", name, tile))),
+                    }
+                }
+            },
+            Some("NOCLINK") => {
+                let (fs, fp) = field(sl, 1)?.split_once('/').ok_or_else(|| Error::ParseError("weird site pin".to_string()))?;
+                let (ts, tp) = field(sl, 2)?.split_once('/').ok_or_else(|| Error::ParseError("weird site pin".to_string()))?;
+                // Links to non-NoC sites, eg. PL interface, are left to the
+                // usual nodes.
+                if let (Some(&f), Some(&t)) = (sites.get(fs), sites.get(ts)) {
+                    rd.add_versal_noc_link(f, fp.to_string(), t, tp.to_string(), sl[3..].to_vec());
+                }
+            },
+            _ => return Err(Error::ParseError("unknown line".to_string())),
+        }
+        Ok(false)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use crate::xilinx::family::Family;
+    use crate::xilinx::rawdump::{Coord, Source, VersalSiteKind};
+    use crate::xilinx::rdbuild::PartBuilder;
+
+    #[test]
+    fn site_kind_test() {
+        assert_eq!(super::site_kind("NOC_NPS_VNOC"), Some(VersalSiteKind::NocSwitch));
+        assert_eq!(super::site_kind("NOC_NPS5555"), Some(VersalSiteKind::NocSwitch));
+        assert_eq!(super::site_kind("NOC_NMU512"), Some(VersalSiteKind::NocNmu));
+        assert_eq!(super::site_kind("NOC_NSU128"), Some(VersalSiteKind::NocNsu));
+        assert_eq!(super::site_kind("NOC_NCRB"), Some(VersalSiteKind::NocOther));
+        assert_eq!(super::site_kind("AIE_CORE"), Some(VersalSiteKind::AieCore));
+        assert_eq!(super::site_kind("AIE_MEMORY"), Some(VersalSiteKind::AieMemory));
+        assert_eq!(super::site_kind("AIE_PL"), Some(VersalSiteKind::AieShim));
+        assert_eq!(super::site_kind("AIE_NOC"), Some(VersalSiteKind::AieShim));
+        assert_eq!(super::site_kind("AIE_FOO"), None);
+        assert_eq!(super::site_kind("CPM_MAIN"), Some(VersalSiteKind::Cpm));
+        assert_eq!(super::site_kind("PS9"), Some(VersalSiteKind::Ps));
+        assert_eq!(super::site_kind("SLICEL"), None);
+    }
+
+    #[test]
+    fn parse_versal_test() {
+        let mut rd = PartBuilder::new("xcvc1902".to_string(), Family::Versal, Source::Vivado, 4, 2);
+        for (x, tile) in ["NOC_NMU512_X0Y0", "NOC_NPS_VNOC_X0Y0", "NOC_NSU512_X0Y0", "AIE_TILE_X0Y1"].iter().enumerate() {
+            let kind = tile.rsplit_once("_X").unwrap().0;
+            rd.add_tile(Coord {x: x as u16, y: 0}, tile.to_string(), kind.to_string(), &[], &[], &[]);
+        }
+        for x in 0..4 {
+            rd.add_tile(Coord {x, y: 1}, format!("NULL_X{}Y1", x), "NULL".to_string(), &[], &[], &[]);
+        }
+        // As captured from the DUMP_VERSAL_TCL reply.
+        let reply = [
+            r"SITE NOC_NMU512_X0Y0 NOC_NMU512 NOC_NMU512_X0Y0",
+            r"SITE NOC_NPS_VNOC_X0Y0 NOC_NPS_VNOC NOC_NPS_VNOC_X0Y0",
+            r"SITE NOC_NSU512_X0Y0 NOC_NSU512 NOC_NSU512_X0Y0",
+            r"SITE AIE_CORE_X3Y1 AIE_CORE AIE_TILE_X0Y1",
+            r"SITE AIE_MEMORY_X3Y1 AIE_MEMORY AIE_TILE_X0Y1",
+            r"SITE AIE_WEIRD_X3Y1 AIE_WEIRD AIE_TILE_X0Y1",
+            r"NOCLINK NOC_NMU512_X0Y0/TO_NOC NOC_NPS_VNOC_X0Y0/IN_0",
+            r"NOCLINK NOC_NPS_VNOC_X0Y0/OUT_1 NOC_NSU512_X0Y0/FROM_NOC NOC_NPS_VNOC_X0Y0/NOC_NPS_VNOC.OUT_1->>M_OUT1 NOC_NSU512_X0Y0/NOC_NSU512.S_IN->>FROM_NOC",
+            r"NOCLINK NOC_NSU512_X0Y0/TO_PL SLICE_X0Y0/A1",
+            r"END",
+        ];
+        let mut lines = reply.iter().map(|l| Ok(l.to_string())).collect::<Vec<io::Result<String>>>().into_iter();
+        super::parse_versal("versal", &mut lines, &mut rd).unwrap();
+        let part = rd.finish();
+        let versal = part.versal.unwrap();
+        let names: Vec<_> = versal.sites.iter().map(|s| &s.name[..]).collect();
+        assert_eq!(names, ["NOC_NMU512_X0Y0", "NOC_NPS_VNOC_X0Y0", "NOC_NSU512_X0Y0", "AIE_CORE_X3Y1", "AIE_MEMORY_X3Y1"]);
+        assert_eq!(versal.sites[1].kind, VersalSiteKind::NocSwitch);
+        assert_eq!(versal.sites[1].tile, Coord {x: 1, y: 0});
+        assert_eq!(versal.sites[1].aie_pos, None);
+        assert_eq!(versal.sites[3].aie_pos, Some((3, 1)));
+        assert_eq!(versal.sites[4].kind, VersalSiteKind::AieMemory);
+        assert_eq!(versal.noc_links.len(), 2);
+        let link = &versal.noc_links[0];
+        assert_eq!((link.from_site, &link.from_pin[..], link.to_site, &link.to_pin[..]), (0, "TO_NOC", 1, "IN_0"));
+        assert!(link.pips.is_empty());
+        let link = &versal.noc_links[1];
+        assert_eq!((link.from_site, &link.from_pin[..], link.to_site, &link.to_pin[..]), (1, "OUT_1", 2, "FROM_NOC"));
+        assert_eq!(link.pips, ["NOC_NPS_VNOC_X0Y0/NOC_NPS_VNOC.OUT_1->>M_OUT1", "NOC_NSU512_X0Y0/NOC_NSU512.S_IN->>FROM_NOC"]);
+
+        let mut rd = PartBuilder::new("xcvc1902".to_string(), Family::Versal, Source::Vivado, 1, 1);
+        let mut lines = vec![Ok(r"SITE NOC_NMU512_X0Y0 NOC_NMU512 NOC_NMU512_X0Y0".to_string())].into_iter();
+        assert!(super::parse_versal("versal", &mut lines, &mut rd).is_err());
+    }
+}
@@ -0,0 +1,407 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::{self, Write};
+use super::rawdump::{Part, Coord, NodeOrClass, SpeedIdx, TkWire, TkSitePinDir, WireIdx};
+
+// VPR wants every routing node in a channel.  Nodes that stay in one row
+// become CHANX, nodes that stay in one column become CHANY, and anything
+// else is put in the CHANX of its lowest row, over its full width.  Site
+// pins become IPIN/OPIN nodes with their own SINK/SOURCE, connected to the
+// node of their wire by the delayless switch.  Pips become edges, with one
+// switch per speed and buffering.
+
+fn esc(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+struct BlockPin<'a> {
+    site: usize,
+    name: &'a str,
+    wire: WireIdx,
+    is_input: bool,
+}
+
+// Pins of a tile kind, in ptc order: by site, then by name.  Bidirectional
+// pins appear twice.
+fn block_pins<'a>(part: &'a Part, kind: &str) -> Vec<BlockPin<'a>> {
+    let tk = &part.tile_kinds[kind];
+    let mut res = Vec::new();
+    for (i, site) in tk.sites.iter().enumerate() {
+        let mut pins: Vec<_> = site.pins.iter().collect();
+        pins.sort_unstable_by_key(|&(n, _)| n);
+        for (name, pin) in pins {
+            if pin.dir != TkSitePinDir::Output {
+                res.push(BlockPin { site: i, name, wire: pin.wire, is_input: true });
+            }
+            if pin.dir != TkSitePinDir::Input {
+                res.push(BlockPin { site: i, name, wire: pin.wire, is_input: false });
+            }
+        }
+    }
+    res
+}
+
+fn port_name(pin: &BlockPin) -> String {
+    esc(&format!("S{}_{}{}", pin.site, pin.name, if pin.is_input { "" } else { "_O" }))
+}
+
+fn sorted_kinds(part: &Part) -> Vec<&str> {
+    let mut res: Vec<&str> = part.tile_kinds.keys().map(|k| k as &str).collect();
+    res.sort_unstable();
+    res
+}
+
+fn switches(part: &Part) -> BTreeMap<(SpeedIdx, bool), u32> {
+    let mut res = BTreeMap::new();
+    for tk in part.tile_kinds.values() {
+        for pip in tk.pips.values() {
+            res.insert((pip.speed, pip.is_buf), 0);
+        }
+    }
+    // Switch 0 is the delayless one.
+    for (i, v) in res.values_mut().enumerate() {
+        *v = i as u32 + 1;
+    }
+    res
+}
+
+fn switch_name(part: &Part, speed: SpeedIdx, is_buf: bool) -> String {
+    let speed = part.print_speed(speed).trim_matches(|c| c == '[' || c == ']');
+    esc(&format!("{}_{}", speed, if is_buf { "buf" } else { "pass" }))
+}
+
+fn port_decl(pin: &BlockPin) -> String {
+    format!("<{} name=\"{}\" num_pins=\"1\"/>", if pin.is_input { "input" } else { "output" }, port_name(pin))
+}
+
+/// Writes a skeleton VPR architecture: one tile and pb_type per tile kind,
+/// with site pins as ports, and a fixed layout of the part's tile grid.
+/// Each pb_type wraps a single primitive with the same ports, modelled as
+/// a `.subckt` of the tile kind's name.
+pub fn write_arch(part: &Part, f: &mut impl Write) -> io::Result<()> {
+    writeln!(f, "<architecture>")?;
+    writeln!(f, "  <models>")?;
+    for kind in sorted_kinds(part) {
+        let pins = block_pins(part, kind);
+        writeln!(f, "    <model name=\"{}\">", esc(kind))?;
+        writeln!(f, "      <input_ports>")?;
+        for pin in pins.iter().filter(|p| p.is_input) {
+            writeln!(f, "        <port name=\"{}\"/>", port_name(pin))?;
+        }
+        writeln!(f, "      </input_ports>")?;
+        writeln!(f, "      <output_ports>")?;
+        for pin in pins.iter().filter(|p| !p.is_input) {
+            writeln!(f, "        <port name=\"{}\"/>", port_name(pin))?;
+        }
+        writeln!(f, "      </output_ports>")?;
+        writeln!(f, "    </model>")?;
+    }
+    writeln!(f, "  </models>")?;
+    writeln!(f, "  <tiles>")?;
+    for kind in sorted_kinds(part) {
+        let kind_e = esc(kind);
+        writeln!(f, "    <tile name=\"{}\">", kind_e)?;
+        writeln!(f, "      <sub_tile name=\"{}\">", kind_e)?;
+        writeln!(f, "        <equivalent_sites><site pb_type=\"{}\"/></equivalent_sites>", kind_e)?;
+        for pin in block_pins(part, kind) {
+            writeln!(f, "        {}", port_decl(&pin))?;
+        }
+        writeln!(f, "        <fc in_type=\"frac\" in_val=\"1.0\" out_type=\"frac\" out_val=\"1.0\"/>")?;
+        writeln!(f, "        <pinlocations pattern=\"spread\"/>")?;
+        writeln!(f, "      </sub_tile>")?;
+        writeln!(f, "    </tile>")?;
+    }
+    writeln!(f, "  </tiles>")?;
+    writeln!(f, "  <layout>")?;
+    writeln!(f, "    <fixed_layout name=\"{}\" width=\"{}\" height=\"{}\">", esc(&part.part), part.width, part.height)?;
+    let mut coords: Vec<Coord> = part.tiles.keys().copied().collect();
+    coords.sort_unstable();
+    for c in coords {
+        writeln!(f, "      <single type=\"{}\" x=\"{}\" y=\"{}\" priority=\"1\"/>", esc(&part.tiles[&c].kind), c.x, c.y)?;
+    }
+    writeln!(f, "    </fixed_layout>")?;
+    writeln!(f, "  </layout>")?;
+    writeln!(f, "  <device>")?;
+    writeln!(f, "    <sizing R_minW_nmos=\"1\" R_minW_pmos=\"1\"/>")?;
+    writeln!(f, "    <area grid_logic_tile_area=\"1\"/>")?;
+    writeln!(f, "    <chan_width_distr><x distr=\"uniform\" peak=\"1.0\"/><y distr=\"uniform\" peak=\"1.0\"/></chan_width_distr>")?;
+    writeln!(f, "    <switch_block type=\"wilton\" fs=\"3\"/>")?;
+    writeln!(f, "    <connection_block input_switch_name=\"__vpr_delayless_switch__\"/>")?;
+    writeln!(f, "  </device>")?;
+    writeln!(f, "  <switchlist>")?;
+    writeln!(f, "    <switch type=\"mux\" name=\"__vpr_delayless_switch__\" R=\"0\" Cin=\"0\" Cout=\"0\" Tdel=\"0\" mux_trans_size=\"0\" buf_size=\"0\"/>")?;
+    for &(speed, is_buf) in switches(part).keys() {
+        writeln!(f, "    <switch type=\"{}\" name=\"{}\" R=\"0\" Cin=\"0\" Cout=\"0\" Tdel=\"0\" mux_trans_size=\"0\" buf_size=\"0\"/>", if is_buf { "mux" } else { "pass_gate" }, switch_name(part, speed, is_buf))?;
+    }
+    writeln!(f, "  </switchlist>")?;
+    writeln!(f, "  <segmentlist>")?;
+    writeln!(f, "    <segment name=\"generic\" length=\"1\" type=\"bidir\" freq=\"1\" Rmetal=\"0\" Cmetal=\"0\">")?;
+    writeln!(f, "      <wire_switch name=\"__vpr_delayless_switch__\"/><opin_switch name=\"__vpr_delayless_switch__\"/>")?;
+    writeln!(f, "      <sb type=\"pattern\">1 1</sb><cb type=\"pattern\">1</cb>")?;
+    writeln!(f, "    </segment>")?;
+    writeln!(f, "  </segmentlist>")?;
+    writeln!(f, "  <complexblocklist>")?;
+    for kind in sorted_kinds(part) {
+        let kind_e = esc(kind);
+        let prim = esc(&format!("{}_PRIM", kind));
+        let pins = block_pins(part, kind);
+        writeln!(f, "    <pb_type name=\"{}\">", kind_e)?;
+        for pin in pins.iter() {
+            writeln!(f, "      {}", port_decl(pin))?;
+        }
+        writeln!(f, "      <pb_type name=\"{}\" blif_model=\".subckt {}\" num_pb=\"1\">", prim, kind_e)?;
+        for pin in pins.iter() {
+            writeln!(f, "        {}", port_decl(pin))?;
+        }
+        writeln!(f, "      </pb_type>")?;
+        writeln!(f, "      <interconnect>")?;
+        for pin in pins.iter() {
+            let port = port_name(pin);
+            if pin.is_input {
+                writeln!(f, "        <direct name=\"{}\" input=\"{}.{}\" output=\"{}.{}\"/>", port, kind_e, port, prim, port)?;
+            } else {
+                writeln!(f, "        <direct name=\"{}\" input=\"{}.{}\" output=\"{}.{}\"/>", port, prim, port, kind_e, port)?;
+            }
+        }
+        writeln!(f, "      </interconnect>")?;
+        writeln!(f, "    </pb_type>")?;
+    }
+    writeln!(f, "  </complexblocklist>")?;
+    writeln!(f, "</architecture>")?;
+    Ok(())
+}
+
+enum ChanKind {
+    X,
+    Y,
+}
+
+struct Chan {
+    kind: ChanKind,
+    lo: Coord,
+    hi: Coord,
+    ptc: u32,
+}
+
+/// Writes a VPR routing resource graph matching `write_arch`.
+pub fn write_rr_graph(part: &Part, f: &mut impl Write) -> io::Result<()> {
+    let mut chanx_width = vec![0u32; part.height as usize];
+    let mut chany_width = vec![0u32; part.width as usize];
+    let mut chans: Vec<Chan> = Vec::new();
+    let mut add_chan = |lo: Coord, hi: Coord| {
+        let (kind, ptc) = if lo.y == hi.y || lo.x != hi.x {
+            let w = &mut chanx_width[lo.y as usize];
+            *w += 1;
+            (ChanKind::X, *w - 1)
+        } else {
+            let w = &mut chany_width[lo.x as usize];
+            *w += 1;
+            (ChanKind::Y, *w - 1)
+        };
+        chans.push(Chan { kind, lo, hi, ptc });
+    };
+    for node in part.nodes.iter() {
+        let template = &part.templates[node.template as usize];
+        let mut lo = Coord { x: u16::MAX, y: u16::MAX };
+        let mut hi = Coord { x: 0, y: 0 };
+        for w in template.wires.iter() {
+            let c = Coord {x: node.base.x + w.delta.x, y: node.base.y + w.delta.y};
+            lo = Coord { x: lo.x.min(c.x), y: lo.y.min(c.y) };
+            hi = Coord { x: hi.x.max(c.x), y: hi.y.max(c.y) };
+        }
+        add_chan(lo, hi);
+    }
+    // Internal wires are single-tile nodes of their own.
+    let mut coords: Vec<Coord> = part.tiles.keys().copied().collect();
+    coords.sort_unstable();
+    let mut internal: HashMap<(Coord, WireIdx), u32> = HashMap::new();
+    for &c in coords.iter() {
+        let tk = &part.tile_kinds[&part.tiles[&c].kind];
+        let mut wires: Vec<_> = tk.wires.iter().filter(|(_, w)| matches!(w, TkWire::Internal(_, _))).map(|(&w, _)| w).collect();
+        wires.sort_unstable();
+        for w in wires {
+            internal.insert((c, w), (part.nodes.len() + internal.len()) as u32);
+            add_chan(c, c);
+        }
+    }
+    let wire_node = |c: Coord, w: WireIdx| -> Option<u32> {
+        let tile = &part.tiles[&c];
+        match part.tile_kinds[&tile.kind].wires.get(&w)? {
+            TkWire::Internal(_, _) => Some(internal[&(c, w)]),
+            TkWire::Connected(idx) => match tile.get_conn_wire(*idx) {
+                NodeOrClass::Node(n) => Some(n),
+                _ => None,
+            },
+        }
+    };
+
+    let kinds = sorted_kinds(part);
+    let kind_pins: HashMap<&str, Vec<BlockPin>> = kinds.iter().map(|&k| (k, block_pins(part, k))).collect();
+    let switches = switches(part);
+
+    writeln!(f, "<rr_graph tool_name=\"prjcombine\" tool_version=\"{}\" tool_comment=\"{}\">", env!("CARGO_PKG_VERSION"), esc(&part.part))?;
+    writeln!(f, "  <channels>")?;
+    let max_width = chanx_width.iter().chain(chany_width.iter()).copied().max().unwrap_or(0);
+    writeln!(f, "    <channel chan_width_max=\"{}\" x_min=\"{}\" y_min=\"{}\" x_max=\"{}\" y_max=\"{}\"/>",
+        max_width,
+        chanx_width.iter().copied().min().unwrap_or(0),
+        chany_width.iter().copied().min().unwrap_or(0),
+        chanx_width.iter().copied().max().unwrap_or(0),
+        chany_width.iter().copied().max().unwrap_or(0))?;
+    for (i, w) in chanx_width.iter().enumerate() {
+        writeln!(f, "    <x_list index=\"{}\" info=\"{}\"/>", i, w)?;
+    }
+    for (i, w) in chany_width.iter().enumerate() {
+        writeln!(f, "    <y_list index=\"{}\" info=\"{}\"/>", i, w)?;
+    }
+    writeln!(f, "  </channels>")?;
+    writeln!(f, "  <switches>")?;
+    writeln!(f, "    <switch id=\"0\" type=\"mux\" name=\"__vpr_delayless_switch__\"><timing R=\"0\" Cin=\"0\" Cout=\"0\" Tdel=\"0\"/><sizing mux_trans_size=\"0\" buf_size=\"0\"/></switch>")?;
+    for (&(speed, is_buf), &id) in switches.iter() {
+        writeln!(f, "    <switch id=\"{}\" type=\"{}\" name=\"{}\"><timing R=\"0\" Cin=\"0\" Cout=\"0\" Tdel=\"0\"/><sizing mux_trans_size=\"0\" buf_size=\"0\"/></switch>", id, if is_buf { "mux" } else { "pass_gate" }, switch_name(part, speed, is_buf))?;
+    }
+    writeln!(f, "  </switches>")?;
+    writeln!(f, "  <segments>")?;
+    writeln!(f, "    <segment id=\"0\" name=\"generic\"><timing R_per_meter=\"0\" C_per_meter=\"0\"/></segment>")?;
+    writeln!(f, "  </segments>")?;
+    writeln!(f, "  <block_types>")?;
+    writeln!(f, "    <block_type id=\"0\" name=\"EMPTY\" width=\"1\" height=\"1\"/>")?;
+    for (i, &kind) in kinds.iter().enumerate() {
+        writeln!(f, "    <block_type id=\"{}\" name=\"{}\" width=\"1\" height=\"1\">", i + 1, esc(kind))?;
+        for (ptc, pin) in kind_pins[kind].iter().enumerate() {
+            writeln!(f, "      <pin_class type=\"{}\"><pin ptc=\"{}\">{}.{}[0]</pin></pin_class>", if pin.is_input { "INPUT" } else { "OUTPUT" }, ptc, esc(kind), port_name(pin))?;
+        }
+        writeln!(f, "    </block_type>")?;
+    }
+    writeln!(f, "  </block_types>")?;
+    writeln!(f, "  <grid>")?;
+    let kind_idx: HashMap<&str, usize> = kinds.iter().enumerate().map(|(i, &k)| (k, i + 1)).collect();
+    for x in 0..part.width {
+        for y in 0..part.height {
+            let id = match part.tiles.get(&Coord { x, y }) {
+                Some(t) => kind_idx[&t.kind as &str],
+                None => 0,
+            };
+            writeln!(f, "    <grid_loc x=\"{}\" y=\"{}\" block_type_id=\"{}\" width_offset=\"0\" height_offset=\"0\"/>", x, y, id)?;
+        }
+    }
+    writeln!(f, "  </grid>")?;
+
+    writeln!(f, "  <rr_nodes>")?;
+    for (i, chan) in chans.iter().enumerate() {
+        writeln!(f, "    <node id=\"{}\" type=\"{}\" direction=\"BI_DIR\" capacity=\"1\"><loc xlow=\"{}\" ylow=\"{}\" xhigh=\"{}\" yhigh=\"{}\" ptc=\"{}\"/><segment segment_id=\"0\"/></node>",
+            i, match chan.kind { ChanKind::X => "CHANX", ChanKind::Y => "CHANY" },
+            chan.lo.x, chan.lo.y, if let ChanKind::X = chan.kind { chan.hi.x } else { chan.lo.x }, if let ChanKind::X = chan.kind { chan.lo.y } else { chan.hi.y }, chan.ptc)?;
+    }
+    // Each site pin gets a pin node and a source or sink node, in that order.
+    let mut next = chans.len() as u32;
+    let mut edges: Vec<(u32, u32, u32)> = Vec::new();
+    for &c in coords.iter() {
+        let tile = &part.tiles[&c];
+        for (ptc, pin) in kind_pins[&tile.kind as &str].iter().enumerate() {
+            if tile.sites[pin.site].is_none() {
+                continue;
+            }
+            let (pin_kind, class_kind) = if pin.is_input { ("IPIN", "SINK") } else { ("OPIN", "SOURCE") };
+            writeln!(f, "    <node id=\"{}\" type=\"{}\" capacity=\"1\"><loc xlow=\"{}\" ylow=\"{}\" xhigh=\"{}\" yhigh=\"{}\" side=\"TOP\" ptc=\"{}\"/></node>", next, pin_kind, c.x, c.y, c.x, c.y, ptc)?;
+            writeln!(f, "    <node id=\"{}\" type=\"{}\" capacity=\"1\"><loc xlow=\"{}\" ylow=\"{}\" xhigh=\"{}\" yhigh=\"{}\" ptc=\"{}\"/></node>", next + 1, class_kind, c.x, c.y, c.x, c.y, ptc)?;
+            if pin.is_input {
+                edges.push((next, next + 1, 0));
+            } else {
+                edges.push((next + 1, next, 0));
+            }
+            if pin.wire != WireIdx::NONE {
+                if let Some(n) = wire_node(c, pin.wire) {
+                    if pin.is_input {
+                        edges.push((n, next, 0));
+                    } else {
+                        edges.push((next, n, 0));
+                    }
+                }
+            }
+            next += 2;
+        }
+    }
+    writeln!(f, "  </rr_nodes>")?;
+
+    writeln!(f, "  <rr_edges>")?;
+    for (src, dst, sw) in edges {
+        writeln!(f, "    <edge src_node=\"{}\" sink_node=\"{}\" switch_id=\"{}\"/>", src, dst, sw)?;
+    }
+    for &c in coords.iter() {
+        let tk = &part.tile_kinds[&part.tiles[&c].kind];
+        let mut pips: Vec<_> = tk.pips.iter().collect();
+        pips.sort_unstable_by_key(|&(&k, _)| k);
+        for (&(wf, wt), pip) in pips {
+            if let (Some(nf), Some(nt)) = (wire_node(c, wf), wire_node(c, wt)) {
+                writeln!(f, "    <edge src_node=\"{}\" sink_node=\"{}\" switch_id=\"{}\"/>", nf, nt, switches[&(pip.speed, pip.is_buf)])?;
+            }
+        }
+    }
+    writeln!(f, "  </rr_edges>")?;
+    writeln!(f, "</rr_graph>")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xilinx::family::Family;
+    use crate::xilinx::rawdump::{Coord, Source, TkSitePinDir, TkPipInversion, TkPipDirection};
+    use crate::xilinx::rdbuild::PartBuilder;
+
+    #[test]
+    fn rr_graph_test() {
+        let mut rd = PartBuilder::new("xc7a0t".to_string(), Family::Series7, Source::Vivado, 2, 2);
+        rd.add_tile(Coord {x: 0, y: 0}, "CLB_X0Y0".to_string(), "CLB".to_string(),
+            &[("SLICE_X0Y0", "SLICEL", vec![
+                ("I", TkSitePinDir::Input, Some("IMUX"), None),
+                ("O", TkSitePinDir::Output, Some("OUT"), None),
+            ])],
+            &[("IMUX", None), ("OUT", None), ("E", None), ("N", None)],
+            &[
+                ("OUT", "E", true, false, false, TkPipInversion::Never, TkPipDirection::Uni, None),
+                ("OUT", "N", true, false, false, TkPipInversion::Never, TkPipDirection::Uni, None),
+                ("E", "IMUX", true, false, false, TkPipInversion::Never, TkPipDirection::Uni, None),
+            ]);
+        rd.add_tile(Coord {x: 1, y: 0}, "INT_X1Y0".to_string(), "INTE".to_string(), &[], &[("W", None)], &[]);
+        rd.add_tile(Coord {x: 0, y: 1}, "INT_X0Y1".to_string(), "INTN".to_string(), &[], &[("S", None)], &[]);
+        rd.add_node(&[("CLB_X0Y0", "E", None), ("INT_X1Y0", "W", None)]);
+        rd.add_node(&[("CLB_X0Y0", "N", None), ("INT_X0Y1", "S", None)]);
+        let part = rd.finish();
+        let mut buf = Vec::new();
+        super::write_rr_graph(&part, &mut buf).unwrap();
+        let rr = String::from_utf8(buf).unwrap();
+
+        for (kind, num) in [("CHANX", 3), ("CHANY", 1), ("IPIN", 1), ("OPIN", 1), ("SINK", 1), ("SOURCE", 1)] {
+            assert_eq!(rr.matches(&format!("type=\"{}\"", kind)).count(), num, "{}", kind);
+        }
+        // The two nodes come first, then the internal wires of CLB_X0Y0,
+        // which go after the first node in the CHANX of row 0.
+        assert!(rr.contains("<node id=\"0\" type=\"CHANX\" direction=\"BI_DIR\" capacity=\"1\"><loc xlow=\"0\" ylow=\"0\" xhigh=\"1\" yhigh=\"0\" ptc=\"0\"/>"));
+        assert!(rr.contains("<node id=\"1\" type=\"CHANY\" direction=\"BI_DIR\" capacity=\"1\"><loc xlow=\"0\" ylow=\"0\" xhigh=\"0\" yhigh=\"1\" ptc=\"0\"/>"));
+        assert!(rr.contains("<node id=\"2\" type=\"CHANX\" direction=\"BI_DIR\" capacity=\"1\"><loc xlow=\"0\" ylow=\"0\" xhigh=\"0\" yhigh=\"0\" ptc=\"1\"/>"));
+        assert!(rr.contains("<node id=\"3\" type=\"CHANX\" direction=\"BI_DIR\" capacity=\"1\"><loc xlow=\"0\" ylow=\"0\" xhigh=\"0\" yhigh=\"0\" ptc=\"2\"/>"));
+        assert!(rr.contains("<node id=\"4\" type=\"IPIN\""));
+        assert!(rr.contains("<node id=\"5\" type=\"SINK\""));
+        assert!(rr.contains("<node id=\"6\" type=\"OPIN\""));
+        assert!(rr.contains("<node id=\"7\" type=\"SOURCE\""));
+
+        let wire = |name: &str| part.wires.iter().position(|w| w == name).unwrap();
+        let (imux, out) = if wire("IMUX") < wire("OUT") { (2, 3) } else { (3, 2) };
+        let edge = |src: u32, dst: u32| format!("<edge src_node=\"{}\" sink_node=\"{}\"", src, dst);
+        assert_eq!(rr.matches("<edge ").count(), 7);
+        assert!(rr.contains(&edge(4, 5)));
+        assert!(rr.contains(&edge(imux, 4)));
+        assert!(rr.contains(&edge(7, 6)));
+        assert!(rr.contains(&edge(6, out)));
+        assert!(rr.contains(&edge(out, 0)));
+        assert!(rr.contains(&edge(out, 1)));
+        assert!(rr.contains(&edge(0, imux)));
+
+        let mut buf = Vec::new();
+        super::write_arch(&part, &mut buf).unwrap();
+        let arch = String::from_utf8(buf).unwrap();
+        assert!(arch.contains("<model name=\"CLB\">"));
+        assert!(arch.contains("<pb_type name=\"CLB_PRIM\" blif_model=\".subckt CLB\" num_pb=\"1\">"));
+        assert!(arch.contains("<direct name=\"S0_O_O\" input=\"CLB_PRIM.S0_O_O\" output=\"CLB.S0_O_O\"/>"));
+    }
+}